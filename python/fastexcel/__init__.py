@@ -1,7 +1,9 @@
 from __future__ import annotations
 
+import re
 import sys
 import typing
+from enum import Enum
 from typing import TYPE_CHECKING, Callable, Literal
 
 if sys.version_info < (3, 10):
@@ -23,12 +25,17 @@ from ._fastexcel import (
     CalamineCellError,
     CalamineError,
     CannotRetrieveCellDataError,
+    CellStyle,
     ColumnInfo,
     ColumnNotFoundError,
+    DefinedName,
+    EncryptedWorkbookError,
     FastExcelError,
     InvalidParametersError,
     SheetNotFoundError,
+    TableInfo,
     UnsupportedColumnTypeCombinationError,
+    WorkbookMetadata,
     __version__,
     _ExcelReader,
     _ExcelSheet,
@@ -36,11 +43,30 @@ from ._fastexcel import (
 )
 from ._fastexcel import read_excel as _read_excel
 
-DType = Literal["null", "int", "float", "string", "boolean", "datetime", "date", "duration"]
+DType = Literal["null", "int", "float", "string", "boolean", "datetime", "date", "duration", "time"]
 DTypeMap: TypeAlias = "dict[str | int, DType]"
+DTypeCoercion: TypeAlias = Literal["coerce", "strict", "strict_no_bool_numeric", "raw_string"]
 ColumnNameFrom: TypeAlias = Literal["provided", "looked_up", "generated"]
-DTypeFrom: TypeAlias = Literal["provided_by_index", "provided_by_name", "guessed"]
+DTypeFrom: TypeAlias = Literal[
+    "provided_for_all", "provided_by_index", "provided_by_name", "guessed", "int_as_float"
+]
 SheetVisible: TypeAlias = Literal["visible", "hidden", "veryhidden"]
+WorkbookFormat: TypeAlias = Literal["xlsx", "xls", "xlsb", "ods"]
+OutputFormat: TypeAlias = Literal["arrow_ipc", "csv", "parquet"]
+SchemaSampleStrategy: TypeAlias = Literal["head", "head_tail"]
+DuplicateColumnStrategy: TypeAlias = Literal["suffix", "pandas", "error"]
+UseColumns: TypeAlias = (
+    "list[str] | list[int] | str | re.Pattern[str] | Callable[[ColumnInfo], bool]"
+)
+
+
+class _Unset(Enum):
+    """Marks an option which was not passed, and falls back on the reader's default"""
+
+    UNSET = "UNSET"
+
+
+_UNSET = _Unset.UNSET
 
 
 def _recordbatch_to_polars(rb: pa.RecordBatch) -> pl.DataFrame:
@@ -77,6 +103,58 @@ class ExcelSheet:
         """The sheet's total height"""
         return self._sheet.total_height
 
+    @property
+    def skipped_row_count(self) -> int:
+        """The number of data rows skipped because of `skip_rows`.
+
+        Rows before the header row, and empty rows skipped at the beginning of the sheet when
+        `header_row` and `skip_rows` are `None`, are not part of the data and are not counted.
+        Unless `n_rows` or `stop_at_empty_row` cut the data short,
+        `total_height - skipped_row_count == height`.
+        """
+        return self._sheet.skipped_row_count
+
+    def is_empty(self) -> bool:
+        """Whether the sheet has no data rows, with `skip_rows` and `n_rows` applied"""
+        return self._sheet.is_empty()
+
+    def has_only_null_columns(self) -> bool:
+        """Whether all selected columns have the `"null"` dtype, i.e. no sampled cell has a value.
+
+        Unlike `is_empty`, this is true for sheets with data rows in which every cell is empty.
+        Also true if no column is selected.
+        """
+        return self._sheet.has_only_null_columns()
+
+    @property
+    def header_row(self) -> int | None:
+        """The index (starting at 0) of the sheet's row the column labels were read from.
+
+        For labels spanning several rows, this is the index of the first one.
+
+        `None` if the sheet does not have any column labels, or if they were provided via
+        `column_names`.
+        """
+        return self._sheet.header_row
+
+    @property
+    def data_bounds(self) -> tuple[int, int] | None:
+        """The indices (starting at 0) of the first and last rows of the sheet containing at least
+        one non-empty cell, e.g. to detect blank rows above or below the data.
+
+        Like `header_row`, indices are the sheet's row indices, and are not affected by
+        `skip_rows` or `n_rows`. `None` if the sheet is empty.
+        """
+        return self._sheet.data_bounds
+
+    @property
+    def hidden_columns(self) -> list[int]:
+        """The indices of the sheet's hidden columns, as in `ColumnInfo.index`.
+
+        Always empty for workbooks which are not XLSX files.
+        """
+        return self._sheet.hidden_columns
+
     @property
     def selected_columns(self) -> list[ColumnInfo]:
         """The sheet's selected columns"""
@@ -94,13 +172,83 @@ class ExcelSheet:
 
     @property
     def visible(self) -> SheetVisible:
-        """The visibility of the sheet"""
+        """The visibility of the sheet.
+
+        OpenDocument spreadsheets have no "very hidden" state: their sheets are either
+        `visible` or `hidden`.
+        """
         return self._sheet.visible
 
+    @property
+    def tab_color(self) -> str | None:
+        """The ARGB hex color of the sheet's tab (e.g. `"FFFF0000"` for red).
+
+        `None` if the tab has no color, or if its color refers to the workbook's theme rather than
+        an RGB value. Only read from XLSX workbooks opened from a path or bytes.
+        """
+        return self._sheet.tab_color
+
     def to_arrow(self) -> pa.RecordBatch:
         """Converts the sheet to a pyarrow `RecordBatch`"""
         return self._sheet.to_arrow()
 
+    def to_arrow_table(self) -> pa.Table:
+        """Converts the sheet to a pyarrow `Table`, made of a single `RecordBatch`"""
+        return pa.Table.from_batches([self.to_arrow()])
+
+    def to_arrow_with_schema(self, schema: pa.Schema) -> pa.RecordBatch:
+        """Converts the sheet to a pyarrow `RecordBatch` with the given schema.
+
+        Columns are looked up by the names of the schema's fields, and loaded with the fields'
+        types instead of the guessed ones: other columns of the sheet are ignored, and a
+        `ColumnNotFoundError` is raised if a field has no matching column. Supported types are
+        the ones `to_arrow` can produce (`int64`, `float64`, `string`, `bool`, `timestamp[ms]`,
+        `date32`, `duration[ms]`, `time32[ms]` and `null`).
+        """
+        return self._sheet.to_arrow_with_schema(schema)
+
+    def column_by_name(self, name: str) -> pa.Array:
+        """Loads a single selected column, by name, as a pyarrow `Array`.
+
+        The column has the same dtype and rows as in `to_arrow`, but the other columns are not
+        loaded, which saves memory when only one column of a wide sheet is needed. Raises a
+        `ColumnNotFoundError` if no selected column has this name.
+        """
+        return self._sheet.column_by_name(name)
+
+    def column_by_index(self, index: int) -> pa.Array:
+        """Loads a single selected column, by its index in the sheet, as a pyarrow `Array`.
+
+        See `column_by_name`.
+        """
+        return self._sheet.column_by_index(index)
+
+    def raw_cell_string(self, row: int, col: int) -> str | None:
+        """The raw value of a single cell as a string, without loading any column.
+
+        `row` is relative to the first data row, i.e. row `0` is the first row `to_arrow` would
+        return, and `col` is the index of the column in the sheet. Values are shown as read from
+        the file, before any dtype is applied, e.g. `"2019"` for a number or `"1.2E5"` for a
+        string. `None` if the cell is empty, is outside of the data, or is not a string nor a
+        number (e.g. booleans, dates and errors). Useful to understand why a column got its dtype.
+        """
+        return self._sheet.raw_cell_string(row, col)
+
+    def arrow_schema(self) -> pa.Schema:
+        """The pyarrow schema of the `RecordBatch` `to_arrow` would produce, without loading any
+        data. Its fields are the selected columns.
+        """
+        return self._sheet.arrow_schema()
+
+    def schema_hint(self) -> list[tuple[str, str]]:
+        """The name and the pyarrow type of every selected column, without loading any data.
+
+        Types are the ones `to_arrow` would produce, as strings (e.g. `"double"`, `"string"` or
+        `"timestamp[ms]"`). Useful to build an explicit dtype mapping before converting the
+        sheet to pandas, for example to keep integer columns containing nulls as integers.
+        """
+        return self._sheet.schema_hint()
+
     def to_pandas(self) -> "pd.DataFrame":
         """Converts the sheet to a Pandas `DataFrame`.
 
@@ -116,6 +264,49 @@ class ExcelSheet:
         """
         return _recordbatch_to_polars(self.to_arrow())
 
+    def stream_to(
+        self,
+        path: str | Path,
+        *,
+        format: OutputFormat,
+        batch_size: int = 10_000,
+    ) -> None:
+        """Writes the sheet to a file, building at most `batch_size` rows at a time.
+
+        Unlike `to_arrow`, the sheet's data is never held in memory as a whole, which makes this
+        suitable for exporting very large sheets.
+
+        :param path: The path of the file to write to. It is created or truncated.
+        :param format: The output format, either `"arrow_ipc"` (Arrow IPC stream), `"csv"` or
+                       `"parquet"`. Parquet files are written one row group at a time, so rows
+                       are held in memory until a row group is full.
+        :param batch_size: The maximum number of rows per written batch.
+        """
+        self._sheet.stream_to(str(path), format=format, batch_size=batch_size)
+
+    def write_parquet(self, path: str | Path, *, batch_size: int = 10_000) -> None:
+        """Writes the sheet's selected columns to a Parquet file, with the sheet's dtypes.
+
+        Equivalent to `stream_to(path, format="parquet", batch_size=batch_size)`: the data does not
+        go through pyarrow, which makes converting large sheets to Parquet cheaper.
+        """
+        self.stream_to(path, format="parquet", batch_size=batch_size)
+
+    def write_csv(self, path: str | Path, *, batch_size: int = 10_000) -> None:
+        """Writes the sheet's selected columns to a CSV file, with a header row.
+
+        Equivalent to `stream_to(path, format="csv", batch_size=batch_size)`.
+        """
+        self.stream_to(path, format="csv", batch_size=batch_size)
+
+    def to_csv_string(self) -> str:
+        """Returns the sheet's selected data as CSV, with a header row.
+
+        Handy to quickly share what was loaded, e.g. when reporting an issue, as neither pyarrow
+        nor a dataframe library is involved. Values are written with the sheet's dtypes.
+        """
+        return self._sheet.to_csv_string()
+
     def __repr__(self) -> str:
         return self._sheet.__repr__()
 
@@ -175,6 +366,10 @@ class ExcelTable:
         """Converts the table to a pyarrow `RecordBatch`"""
         return self._table.to_arrow()
 
+    def to_arrow_table(self) -> pa.Table:
+        """Converts the table to a pyarrow `Table`, made of a single `RecordBatch`"""
+        return pa.Table.from_batches([self.to_arrow()])
+
     def to_pandas(self) -> "pd.DataFrame":
         """Converts the table to a Pandas `DataFrame`.
 
@@ -192,34 +387,183 @@ class ExcelTable:
 
 
 class ExcelReader:
-    """A class representing an open Excel file and allowing to read its sheets"""
+    """A class representing an open Excel file and allowing to read its sheets.
+
+    If `range_cache_size` is passed to `read_excel`, the cells of that many recently loaded sheets
+    are kept in memory, so that loading a sheet again (e.g. with a different `n_rows` or
+    `use_columns`) does not parse it again. The cached cells are shared with the sheets loaded
+    from them, and released by `close`. XLSX sheets loaded with `eager=True` are not cached.
+    """
 
-    def __init__(self, reader: _ExcelReader) -> None:
+    def __init__(
+        self,
+        reader: _ExcelReader,
+        *,
+        sheet_header_row: int | range | Literal["auto"] | None = 0,
+    ) -> None:
         self._reader = reader
+        self._sheet_header_row = sheet_header_row
+
+    def _header_row_or_default(
+        self, header_row: int | range | Literal["auto"] | None | _Unset
+    ) -> int | range | Literal["auto"] | None:
+        """The header row of a whole sheet, falling back on the reader's `sheet_header_row`"""
+        return self._sheet_header_row if header_row is _UNSET else header_row
 
     @property
     def sheet_names(self) -> list[str]:
         """The list of sheet names"""
         return self._reader.sheet_names
 
+    def metadata(self) -> WorkbookMetadata:
+        """The document properties of the workbook: title, creator, creation and modification
+        dates.
+
+        Properties which are not set are `None`. Only read from XLSX files opened from a path or
+        bytes: every property is `None` for other workbooks. Does not require loading any sheet.
+        """
+        return self._reader.metadata()
+
+    @property
+    def sheet_names_with_indices(self) -> list[tuple[int, str]]:
+        """The list of (index, sheet name) pairs, in the workbook's order.
+
+        Indices are the ones expected by `load_sheet` and the other methods taking a sheet index,
+        hidden sheets included. Does not require loading any sheet.
+        """
+        return self._reader.sheet_names_with_indices
+
+    @property
+    def sheet_count(self) -> int:
+        """The number of sheets, hidden ones included"""
+        return self._reader.sheet_count
+
+    @property
+    def active_sheet(self) -> int | None:
+        """The index of the sheet which is active when the workbook is opened.
+
+        This is usually the sheet the workbook was saved on. `None` if the workbook does not
+        record it. Only read from XLSX workbooks opened from a path or bytes.
+        """
+        return self._reader.active_sheet
+
+    def has_sheet(self, idx_or_name: int | str) -> bool:
+        """Whether the workbook has a sheet at the given index or with the given name.
+
+        Does not require loading any sheet.
+
+        :param idx_or_name: The index (starting at 0) or the name of the sheet.
+        """
+        return self._reader.has_sheet(idx_or_name)
+
+    @property
+    def sheet_visibilities(self) -> list[tuple[str, SheetVisible]]:
+        """The list of (sheet name, visibility) pairs, in the workbook's order.
+
+        Does not require loading any sheet. OpenDocument spreadsheets have no "very hidden" state,
+        so their sheets are either `visible` or `hidden`.
+        """
+        return self._reader.sheet_visibilities
+
+    @property
+    def sheet_tab_colors(self) -> list[tuple[str, str | None]]:
+        """The list of (sheet name, tab color) pairs, in the workbook's order.
+
+        Tab colors are ARGB hex colors, as in `ExcelSheet.tab_color`. Does not require loading any
+        sheet.
+        """
+        return self._reader.sheet_tab_colors
+
+    @property
+    def visible_sheet_names(self) -> list[str]:
+        """The names of the visible sheets, in the workbook's order.
+
+        Hidden and very hidden sheets (e.g. helper sheets) are left out. Does not require loading
+        any sheet.
+        """
+        return self._reader.visible_sheet_names
+
+    @property
+    def defined_names(self) -> list[tuple[str, str]]:
+        """The list of (name, formula) pairs of the workbook's defined names.
+
+        Formulas are returned as is, e.g. `"Sheet1!$A$1:$B$10"`.
+        """
+        return self._reader.defined_names
+
+    @property
+    def defined_names_with_scope(self) -> list[DefinedName]:
+        """The workbook's defined names, along with their scope.
+
+        Names local to a sheet have the name of this sheet as scope, and can be defined once per
+        sheet. Names belonging to the whole workbook have no scope. Scopes are only read from XLSX
+        workbooks opened from a path or bytes.
+        """
+        return self._reader.defined_names_with_scope
+
+    def defined_names_for_sheet(self, sheet_name: str) -> list[tuple[str, str, str]]:
+        """The defined names referring to a range of the given sheet.
+
+        Returns a list of (name, sheet name, range) tuples, the range being an A1-style range
+        (e.g. `"A1:B10"`) that can be passed to `load_range`. Defined names that are not a
+        reference to a single range (constants, formulas, several areas...) are skipped.
+
+        :param sheet_name: The name of the sheet.
+        """
+        return self._reader.defined_names_for_sheet(sheet_name)
+
     def load_sheet(
         self,
         idx_or_name: int | str,
         *,
-        header_row: int | None = 0,
+        header_row: int | range | Literal["auto"] | None | _Unset = _UNSET,
         column_names: list[str] | None = None,
         skip_rows: int | None = None,
         n_rows: int | None = None,
         schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
         dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        case_insensitive_sheet_name: bool = False,
     ) -> ExcelSheet:
         """Loads a sheet lazily by index or name.
 
-        :param idx_or_name: The index (starting at 0) or the name of the sheet to load.
-        :param header_row: The index of the row containing the column labels, default index is 0.
+        :param idx_or_name: The index (starting at 0) or the name of the sheet to load. The name
+                            can also be the sheet's code name (the name VBA refers to it by),
+                            which is kept when the sheet is renamed. Code names are only read
+                            from XLSX workbooks opened from a path or bytes.
+        :param header_row: The index of the row containing the column labels. Defaults to the
+                           `sheet_header_row` the reader was opened with, i.e. 0 unless specified
+                           otherwise to `read_excel`: an explicitly passed value takes precedence.
                            If `None`, the sheet does not have any column labels.
+                           If `"auto"`, the header row is detected among the first rows of
+                           the sheet: it is the first row only containing strings which is
+                           followed by a row containing other values (numbers, dates...).
+                           Falls back to the first non-empty row if no such row is found.
+                           If a range of consecutive rows (e.g. `range(0, 2)`), the column
+                           labels span several rows: the non-empty cells of each column are
+                           joined with `" / "`, e.g. `"2024 / Q1"`.
+                           The selected row (the first one for a range) is available via
+                           `ExcelSheet.header_row`.
                            Any rows before the `header_row` will be automatically skipped.
         :param column_names: Overrides headers found in the document.
                              If `column_names` is used, `header_row` will be ignored.
@@ -233,41 +577,584 @@ class ExcelReader:
                             - if `skip_rows` is a number, it skips the specified number
                             of rows from the start of the sheet.
         :param schema_sample_rows: Specifies how many rows should be used to determine
-                                   the dtype of a column. Cannot be 0. A specific dtype can be
-                                   enforced for some or all columns through the `dtypes` parameter.
-                                   If `None`, all rows will be used.
+                                   the dtype of a column. A specific dtype can be enforced for
+                                   some or all columns through the `dtypes` parameter.
+                                   If `None` or 0, all rows will be used.
+        :param schema_sample_strategy: Specifies which rows are sampled to determine the dtype of
+                                       a column. `head` (the default) samples the first
+                                       `schema_sample_rows` rows, whereas `head_tail` samples
+                                       half of them at the top of the sheet and the other half
+                                       at its bottom, which catches type changes happening at
+                                       the end of a sheet (e.g. a summary row).
+        :param expand_sample_on_null_columns: If `True`, the dtype of columns which only contain
+                                              null values within the sampled rows is determined
+                                              from all their rows, so sparse columns are not
+                                              loaded as nulls. Otherwise, a warning is logged
+                                              for such columns. Defaults to `False`.
         :param dtype_coercion: Specifies how type coercion should behave. `coerce` (the default)
                                will try to coerce different dtypes in a column to the same one,
                                whereas `strict` will raise an error in case a column contains
-                               several dtypes. Note that this only applies to columns whose dtype
-                               is guessed, i.e. not specified via `dtypes`.
+                               several dtypes. `strict_no_bool_numeric` behaves like `coerce`,
+                               except that booleans are never coerced to another type, which is
+                               useful for flag columns. With these, numbers in columns coerced
+                               to strings are formatted as Excel displays them (e.g. `"29.02"`
+                               and `"1000"` rather than `"29.020000000000003"` and `"1000.0"`).
+                               `raw_string` loads every column as strings without any
+                               coercion, numbers keeping the full precision they are stored
+                               with (e.g. `"29.020000000000003"`), which is useful to compare
+                               stored values. Note that dtype coercion only applies to columns
+                               whose dtype is guessed, i.e. not specified via `dtypes`.
         :param use_columns: Specifies the columns to use. Can either be:
                             - `None` to select all columns
                             - A list of strings and ints, the column names and/or indices
-                              (starting at 0)
+                              (starting at 0). A string can also be a range of column names
+                              (e.g. `"Revenue:Profit"`), selecting every column between both
+                              names, inclusively
                             - A string, a comma separated list of Excel column letters and column
                               ranges (e.g. `“A:E”` or `“A,C,E:F”`, which would result in
                               `A,B,C,D,E` and `A,C,E,F`)
+                            - A compiled regex (e.g. `re.compile(r"^Q[1-4]_")`), selecting
+                              every column whose name it matches, in the order of the sheet.
+                              Its pattern must be supported by Rust's `regex` crate, which
+                              rejects look-arounds and backreferences
                             - A callable, a function that takes a column and returns a boolean
-                              indicating whether the column should be used
+                              indicating whether the column should be used. The column's dtype
+                              is already determined when the callable is called (e.g.
+                              `lambda col: col.dtype == "float"`), which means the dtype of
+                              every available column has to be inferred, whereas only the
+                              selected columns are inspected with a static selection.
+        :param exclude_columns: The names and/or indices (starting at 0) of the columns not to
+                                use, every other column being used, in the order of the sheet.
+                                Names can be ranges of column names, as in `use_columns`. A
+                                `ColumnNotFoundError` is raised if an excluded column does not
+                                exist. Cannot be used together with `use_columns`.
         :param dtypes: An optional dtype (for all columns)
                        or dict of dtypes with keys as column indices or names.
+                       Keys can also be column letters (e.g. `"B"`), like in `use_columns`
+                       ranges. A letter key applies to the column at that position unless it
+                       has its own dtype by index or name, so a column named `"B"` takes the
+                       dtype of the `"B"` key, and so does the second column.
+                       `dtypes="string"` loads every column as strings, numbers being
+                       formatted as Excel displays them (e.g. `"29.02"` rather than
+                       `"29.020000000000003"`) unless `dtype_coercion` is `raw_string`.
+        :param skip_empty_columns: If `True`, columns containing only null values in the sampled
+                                   rows are not selected, or in all their rows if their sample
+                                   is expanded with `expand_sample_on_null_columns`. Only
+                                   applies when `use_columns` is `None`. Defaults to `False`.
+        :param skip_hidden_columns: If `True`, columns hidden in the sheet are not selected. Only
+                                    applies when `use_columns` is `None`. Column visibility is
+                                    only read from XLSX workbooks. Defaults to `False`.
+        :param stop_at_empty_row: If `True`, the data stops at its first row whose cells are all
+                                  empty, leaving out anything below it (e.g. notes in a footer).
+                                  Combined with `n_rows`, whichever ends first applies.
+                                  Defaults to `False`.
+        :param null_values: The strings that should be considered as null values, replacing the
+                            default ones (`""`, `"NA"`, `"NULL"`, `"null"`, `"None"`, `"NaN"`,
+                            `"#N/A"`...). These are ignored when determining the dtype of a
+                            column, and are read as null in string columns. An empty list means
+                            that no string is considered as null. If `None` (the default), the
+                            default null strings are ignored when determining dtypes, but kept
+                            as is in string columns.
+        :param error_as_null: If `True`, cells containing any error are read as null. Otherwise,
+                              only `#N/A`, `#VALUE!`, `#NULL!`, `#REF!`, `#NUM!` and
+                              `#GETTING_DATA` are, and other errors (e.g. `#DIV/0!`) raise a
+                              `CalamineCellError` when determining the dtype of a column.
+                              `#SPILL!` and `#CALC!` cells cannot be read at all, not even as
+                              null: the sheet cannot be loaded and a `CalamineError` is raised.
+                              Defaults to `False`.
+        :param parse_string_booleans: If `True`, a column whose strings are all `"true"`,
+                                      `"false"`, `"yes"` or `"no"` (case-insensitive) is read as
+                                      a boolean column, its strings being converted to booleans.
+                                      Columns also containing other strings remain string
+                                      columns. Defaults to `False`.
+        :param parse_numeric_strings: If `True`, a column whose strings are all numbers stored as
+                                      text (e.g. `"1.2E5"`), possibly mixed with actual numbers,
+                                      is read as a float column. Columns also containing other
+                                      strings remain string columns. Defaults to `False`.
+        :param int_as_float: If `True`, columns whose dtype is guessed as `int` are read as `float`
+                             columns instead, e.g. to match pipelines where missing integers are
+                             represented as NaN. Their `dtype_from` is `"int_as_float"`. dtypes
+                             provided with `dtypes` are kept as is. Defaults to `False`.
+        :param trim_strings: If `True`, leading and trailing whitespace is removed from the strings
+                             of string columns. Strings which are empty once trimmed are read as
+                             null. Defaults to `False`.
+        :param float_string_precision: The maximum number of digits after the decimal point of
+                                       floats converted to strings, e.g. in columns loaded as
+                                       strings. Trailing zeros are trimmed. Defaults to `9`.
+        :param keep_leading_zeros: If `True`, numbers whose number format pads them with leading
+                                   zeros (e.g. `00000` for zip codes) are read as the text Excel
+                                   displays, e.g. `1234` as `"01234"`, so that columns mixing
+                                   them with IDs stored as text (e.g. `"01234"`) keep both forms.
+                                   Text cells are always read as stored. Number formats are only
+                                   read from XLSX workbooks opened from a path or bytes.
+                                   Defaults to `False`.
+        :param datetime_tz: If set, datetime columns are tagged with this timezone (e.g. `"UTC"`
+                            or `"+02:00"`), which makes them timezone-aware. Values are not
+                            converted: they are expected to already be expressed in this
+                            timezone. Defaults to `None`, which produces naive datetimes.
+        :param unnamed_column_prefix: The prefix of the names generated for columns without a
+                                      name, which are followed by the column's index (e.g.
+                                      `"__UNNAMED__0"`). Generated names are deduplicated like
+                                      any other column name. Defaults to `"__UNNAMED__"`.
+        :param duplicate_column_strategy: How columns sharing the same name are told apart:
+                                          - `"suffix"`: `id`, `id_1`, `id_2`...
+                                          - `"pandas"`: `id`, `id.1`, `id.2`..., like pandas
+                                          - `"error"`: an error is raised
+                                          Defaults to `"suffix"`.
+        :param fill_forward_columns: The columns, by index or name, whose null values are replaced
+                                     with the last non-null value above them, e.g. categories
+                                     only written on the first row of each group. Values are
+                                     never filled from rows which are not loaded (such as
+                                     skipped rows). Defaults to `None`.
+        :param rename_columns: A mapping of columns, by index or name, to the name they should
+                               have instead, e.g. to normalize names. Columns are renamed before
+                               their names are deduplicated, and are then referred to by their
+                               new name (e.g. in `dtypes`). An error is raised if a column to
+                               rename does not exist. Defaults to `None`.
+        :param case_insensitive_sheet_name: If `True` and no sheet name matches `idx_or_name`
+                                            exactly, the sheet whose name matches it
+                                            case-insensitively is loaded. An error is raised
+                                            if several sheets match. Defaults to `False`.
         """
         return ExcelSheet(
             self._reader.load_sheet(
                 idx_or_name=idx_or_name,
-                header_row=header_row,
+                header_row=self._header_row_or_default(header_row),
                 column_names=column_names,
                 skip_rows=skip_rows,
                 n_rows=n_rows,
                 schema_sample_rows=schema_sample_rows,
+                schema_sample_strategy=schema_sample_strategy,
+                expand_sample_on_null_columns=expand_sample_on_null_columns,
                 dtype_coercion=dtype_coercion,
                 use_columns=use_columns,
+                exclude_columns=exclude_columns,
                 dtypes=dtypes,
+                skip_empty_columns=skip_empty_columns,
+                skip_hidden_columns=skip_hidden_columns,
+                stop_at_empty_row=stop_at_empty_row,
+                null_values=null_values,
+                error_as_null=error_as_null,
+                parse_string_booleans=parse_string_booleans,
+                parse_numeric_strings=parse_numeric_strings,
+                int_as_float=int_as_float,
+                trim_strings=trim_strings,
+                float_string_precision=float_string_precision,
+                keep_leading_zeros=keep_leading_zeros,
+                datetime_tz=datetime_tz,
+                unnamed_column_prefix=unnamed_column_prefix,
+                duplicate_column_strategy=duplicate_column_strategy,
+                fill_forward_columns=fill_forward_columns,
+                rename_columns=rename_columns,
                 eager=False,
+                case_insensitive_sheet_name=case_insensitive_sheet_name,
+            )
+        )
+
+    def load_sheets(
+        self,
+        idxs_or_names: list[int | str],
+        *,
+        header_row: int | range | Literal["auto"] | None | _Unset = _UNSET,
+        column_names: list[str] | None = None,
+        skip_rows: int | None = None,
+        n_rows: int | None = None,
+        schema_sample_rows: int | None = 1_000,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
+        dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        case_insensitive_sheet_name: bool = False,
+    ) -> list[ExcelSheet]:
+        """Loads several sheets lazily by index or name, with the same parameters.
+
+        Equivalent to calling `load_sheet` for each sheet. All sheets are looked up before any of
+        them is loaded, and an error is raised as soon as a sheet cannot be loaded.
+
+        :param idxs_or_names: The indices (starting at 0) and/or the names of the sheets to load.
+
+        Refer to `load_sheet` for the documentation of the other parameters
+        """
+        return [
+            ExcelSheet(sheet)
+            for sheet in self._reader.load_sheets(
+                idxs_or_names,
+                header_row=self._header_row_or_default(header_row),
+                column_names=column_names,
+                skip_rows=skip_rows,
+                n_rows=n_rows,
+                schema_sample_rows=schema_sample_rows,
+                schema_sample_strategy=schema_sample_strategy,
+                expand_sample_on_null_columns=expand_sample_on_null_columns,
+                dtype_coercion=dtype_coercion,
+                use_columns=use_columns,
+                exclude_columns=exclude_columns,
+                dtypes=dtypes,
+                skip_empty_columns=skip_empty_columns,
+                skip_hidden_columns=skip_hidden_columns,
+                stop_at_empty_row=stop_at_empty_row,
+                null_values=null_values,
+                error_as_null=error_as_null,
+                parse_string_booleans=parse_string_booleans,
+                parse_numeric_strings=parse_numeric_strings,
+                int_as_float=int_as_float,
+                trim_strings=trim_strings,
+                float_string_precision=float_string_precision,
+                keep_leading_zeros=keep_leading_zeros,
+                datetime_tz=datetime_tz,
+                unnamed_column_prefix=unnamed_column_prefix,
+                duplicate_column_strategy=duplicate_column_strategy,
+                fill_forward_columns=fill_forward_columns,
+                rename_columns=rename_columns,
+                case_insensitive_sheet_name=case_insensitive_sheet_name,
             )
+        ]
+
+    def load_sheets_union(
+        self,
+        idxs_or_names: list[int | str],
+        *,
+        header_row: int | range | Literal["auto"] | None | _Unset = _UNSET,
+        column_names: list[str] | None = None,
+        skip_rows: int | None = None,
+        n_rows: int | None = None,
+        schema_sample_rows: int | None = 1_000,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
+        dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        add_sheet_name_column: bool = False,
+        case_insensitive_sheet_name: bool = False,
+    ) -> pa.RecordBatch:
+        """Loads several sheets with the same parameters, and concatenates their rows into a
+        single pyarrow `RecordBatch`.
+
+        Useful for workbooks containing one sheet per period (e.g. one per day) with the same
+        columns. The sheets must have the same columns, in the same order and with the same dtypes,
+        otherwise an `InvalidParametersError` naming the first differing sheet and column is
+        raised. Use `dtypes` to enforce the same dtypes when guesses differ between sheets.
+
+        :param idxs_or_names: The indices (starting at 0) and/or the names of the sheets to load,
+                              in the order their rows are concatenated.
+        :param add_sheet_name_column: If `True`, a `__sheet_name__` column holding the name of the
+                                      sheet each row comes from is appended. Defaults to `False`.
+
+        Refer to `load_sheet` for the documentation of the other parameters
+        """
+        return self._reader.load_sheets_union(
+            idxs_or_names,
+            header_row=self._header_row_or_default(header_row),
+            column_names=column_names,
+            skip_rows=skip_rows,
+            n_rows=n_rows,
+            schema_sample_rows=schema_sample_rows,
+            schema_sample_strategy=schema_sample_strategy,
+            expand_sample_on_null_columns=expand_sample_on_null_columns,
+            dtype_coercion=dtype_coercion,
+            use_columns=use_columns,
+            exclude_columns=exclude_columns,
+            dtypes=dtypes,
+            skip_empty_columns=skip_empty_columns,
+            skip_hidden_columns=skip_hidden_columns,
+            stop_at_empty_row=stop_at_empty_row,
+            null_values=null_values,
+            error_as_null=error_as_null,
+            parse_string_booleans=parse_string_booleans,
+            parse_numeric_strings=parse_numeric_strings,
+            int_as_float=int_as_float,
+            trim_strings=trim_strings,
+            float_string_precision=float_string_precision,
+            keep_leading_zeros=keep_leading_zeros,
+            datetime_tz=datetime_tz,
+            unnamed_column_prefix=unnamed_column_prefix,
+            duplicate_column_strategy=duplicate_column_strategy,
+            fill_forward_columns=fill_forward_columns,
+            rename_columns=rename_columns,
+            add_sheet_name_column=add_sheet_name_column,
+            case_insensitive_sheet_name=case_insensitive_sheet_name,
         )
 
+    def load_range(
+        self,
+        idx_or_name: int | str,
+        a1_range: str,
+        *,
+        header_row: int | None = 0,
+        column_names: list[str] | None = None,
+        skip_rows: int = 0,
+        n_rows: int | None = None,
+        schema_sample_rows: int | None = 1_000,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
+        dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        case_insensitive_sheet_name: bool = False,
+    ) -> ExcelSheet:
+        """Loads a rectangular region of a sheet, by index or name.
+
+        Useful when the data does not start at A1, or is surrounded by notes.
+
+        :param idx_or_name: The index (starting at 0) or the name of the sheet to load.
+        :param a1_range: The region to load, as an A1-style range (e.g. `"C5:H40"`). Both of its
+                         corners are included.
+        :param header_row: The index of the row containing the column labels, relative to the
+                           region. Default index is 0, i.e. the first row of the region, whatever
+                           the reader's `sheet_header_row`.
+                           If `None`, the region does not have any column labels.
+        :param skip_rows: Specifies how many rows should be skipped after the `header_row`.
+
+        Other parameters behave as in `load_sheet`, and indices passed to `use_columns` and
+        `dtypes` are relative to the region.
+        """
+        return ExcelSheet(
+            self._reader.load_range(
+                idx_or_name=idx_or_name,
+                a1_range=a1_range,
+                header_row=header_row,
+                column_names=column_names,
+                skip_rows=skip_rows,
+                n_rows=n_rows,
+                schema_sample_rows=schema_sample_rows,
+                schema_sample_strategy=schema_sample_strategy,
+                expand_sample_on_null_columns=expand_sample_on_null_columns,
+                dtype_coercion=dtype_coercion,
+                use_columns=use_columns,
+                exclude_columns=exclude_columns,
+                dtypes=dtypes,
+                skip_empty_columns=skip_empty_columns,
+                skip_hidden_columns=skip_hidden_columns,
+                stop_at_empty_row=stop_at_empty_row,
+                null_values=null_values,
+                error_as_null=error_as_null,
+                parse_string_booleans=parse_string_booleans,
+                parse_numeric_strings=parse_numeric_strings,
+                int_as_float=int_as_float,
+                trim_strings=trim_strings,
+                float_string_precision=float_string_precision,
+                keep_leading_zeros=keep_leading_zeros,
+                datetime_tz=datetime_tz,
+                unnamed_column_prefix=unnamed_column_prefix,
+                duplicate_column_strategy=duplicate_column_strategy,
+                fill_forward_columns=fill_forward_columns,
+                rename_columns=rename_columns,
+                case_insensitive_sheet_name=case_insensitive_sheet_name,
+            )
+        )
+
+    def load_defined_name(
+        self,
+        name: str,
+        *,
+        header_row: int | None = 0,
+        column_names: list[str] | None = None,
+        skip_rows: int = 0,
+        n_rows: int | None = None,
+        schema_sample_rows: int | None = 1_000,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
+        dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        case_insensitive_sheet_name: bool = False,
+    ) -> ExcelSheet:
+        """Loads the region of a sheet a defined name (also known as named range) refers to.
+
+        The defined name must refer to a single range of a sheet: an `InvalidParametersError` is
+        raised for constants, formulas or names referring to several areas.
+
+        :param name: The defined name to load.
+        :param case_insensitive_sheet_name: If `True` and no sheet name matches the one the
+                                            defined name refers to exactly, the sheet whose name
+                                            matches it case-insensitively is loaded. Defaults to
+                                            `False`.
+
+        Other parameters behave as in `load_range`.
+        """
+        return ExcelSheet(
+            self._reader.load_defined_name(
+                name=name,
+                header_row=header_row,
+                column_names=column_names,
+                skip_rows=skip_rows,
+                n_rows=n_rows,
+                schema_sample_rows=schema_sample_rows,
+                schema_sample_strategy=schema_sample_strategy,
+                expand_sample_on_null_columns=expand_sample_on_null_columns,
+                dtype_coercion=dtype_coercion,
+                use_columns=use_columns,
+                exclude_columns=exclude_columns,
+                dtypes=dtypes,
+                skip_empty_columns=skip_empty_columns,
+                skip_hidden_columns=skip_hidden_columns,
+                stop_at_empty_row=stop_at_empty_row,
+                null_values=null_values,
+                error_as_null=error_as_null,
+                parse_string_booleans=parse_string_booleans,
+                parse_numeric_strings=parse_numeric_strings,
+                int_as_float=int_as_float,
+                trim_strings=trim_strings,
+                float_string_precision=float_string_precision,
+                keep_leading_zeros=keep_leading_zeros,
+                datetime_tz=datetime_tz,
+                unnamed_column_prefix=unnamed_column_prefix,
+                duplicate_column_strategy=duplicate_column_strategy,
+                fill_forward_columns=fill_forward_columns,
+                rename_columns=rename_columns,
+                case_insensitive_sheet_name=case_insensitive_sheet_name,
+            )
+        )
+
+    def available_column_names(
+        self,
+        idx_or_name: int | str,
+        *,
+        header_row: int | range | Literal["auto"] | None | _Unset = _UNSET,
+        column_names: list[str] | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        case_insensitive_sheet_name: bool = False,
+    ) -> list[str]:
+        """The names of the columns available in a sheet, as in `ExcelSheet.available_columns`.
+
+        Unlike `load_sheet`, the dtypes of the columns are not determined, which makes this
+        cheaper for wide sheets when only the column names are needed, e.g. to validate a header.
+
+        :param idx_or_name: The index (starting at 0) or the name of the sheet.
+
+        Refer to `load_sheet` for the documentation of the other parameters
+        """
+        return self._reader.available_column_names(
+            idx_or_name,
+            header_row=self._header_row_or_default(header_row),
+            column_names=column_names,
+            unnamed_column_prefix=unnamed_column_prefix,
+            duplicate_column_strategy=duplicate_column_strategy,
+            case_insensitive_sheet_name=case_insensitive_sheet_name,
+        )
+
+    def sheet_header(
+        self, idx_or_name: int | str, *, header_row: int | range | Literal["auto"] = 0
+    ) -> list[str]:
+        """The cells of the header row of a sheet, as strings.
+
+        Cells are returned as they are written in the sheet: empty cells are empty strings, and
+        names are neither generated nor deduplicated. Unlike `available_column_names`, this is
+        meant for quickly discovering the labels of many files.
+
+        :param idx_or_name: The index (starting at 0) or the name of the sheet.
+        :param header_row: The index of the header row, a range of header rows or `"auto"` to
+                           detect it as in `load_sheet`. Defaults to 0.
+        """
+        return self._reader.sheet_header(idx_or_name, header_row=header_row)
+
+    def sheet_dimensions(self, idx_or_name: int | str) -> tuple[int, int]:
+        """The (height, width) of the used range of a sheet, header included.
+
+        For XLSX workbooks opened from a path or bytes, the dimension recorded in the workbook is
+        used and no cell is read, which makes this cheap. Otherwise, the sheet's data has to be
+        loaded, which is as costly as loading the sheet.
+
+        :param idx_or_name: The index (starting at 0) or the name of the sheet.
+        """
+        return self._reader.sheet_dimensions(idx_or_name)
+
+    def sheet_formulas(self, idx_or_name: int | str) -> list[tuple[int, int, str]]:
+        """The formulas of a sheet, as a list of (row, column, formula) tuples.
+
+        Row and column indices start at 0 and are absolute within the sheet. Cells without
+        a formula are omitted. The formula of a dynamic array (e.g. `=SEQUENCE(5)`) is only held by
+        the cell it is written in, the cells it spills into hold its values, which are loaded as
+        regular data.
+
+        :param idx_or_name: The index (starting at 0) or the name of the sheet.
+        """
+        return self._reader.sheet_formulas(idx_or_name)
+
+    def cell_styles(self, idx_or_name: int | str) -> list[tuple[int, int, CellStyle]]:
+        """The styles of the cells of a sheet, as a list of (row, column, style) tuples.
+
+        Row and column indices start at 0 and are absolute within the sheet. Only cells with a
+        fill color or a bold font are listed. Fill colors are only known if they are defined as
+        RGB values, not as theme colors.
+
+        Styles are only read from XLSX files opened from a path or bytes: an empty list is
+        returned for other workbooks. Loading data is not affected by styles.
+
+        :param idx_or_name: The index (starting at 0) or the name of the sheet.
+        """
+        return self._reader.cell_styles(idx_or_name)
+
     def table_names(self, sheet_name: str | None = None) -> list[str]:
         """The list of table names.
 
@@ -278,6 +1165,15 @@ class ExcelReader:
         """
         return self._reader.table_names(sheet_name)
 
+    def table_info(self, name: str) -> TableInfo:
+        """The metadata of a table: its sheet, range, column names and header and totals rows.
+
+        The table's data is not loaded. Only available for XLSX files opened from a path or bytes.
+
+        :param name: The name of the table
+        """
+        return self._reader.table_info(name)
+
     @typing.overload
     def load_table(
         self,
@@ -288,9 +1184,29 @@ class ExcelReader:
         skip_rows: int = 0,
         n_rows: int | None = None,
         schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
         dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        exclude_totals_row: bool = False,
         eager: Literal[False] = ...,
     ) -> ExcelTable: ...
     @typing.overload
@@ -303,9 +1219,29 @@ class ExcelReader:
         skip_rows: int = 0,
         n_rows: int | None = None,
         schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
         dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        exclude_totals_row: bool = False,
         eager: Literal[True] = ...,
     ) -> pa.RecordBatch: ...
     def load_table(
@@ -317,9 +1253,29 @@ class ExcelReader:
         skip_rows: int = 0,
         n_rows: int | None = None,
         schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
         dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        exclude_totals_row: bool = False,
         eager: bool = False,
     ) -> ExcelTable | pa.RecordBatch:
         """Loads a table by name.
@@ -337,25 +1293,143 @@ class ExcelReader:
                           If `header_row` is `None`, it skips the number of rows from the
                           start of the sheet.
         :param schema_sample_rows: Specifies how many rows should be used to determine
-                                   the dtype of a column. Cannot be 0. A specific dtype can be
-                                   enforced for some or all columns through the `dtypes` parameter.
-                                   If `None`, all rows will be used.
+                                   the dtype of a column. A specific dtype can be enforced for
+                                   some or all columns through the `dtypes` parameter.
+                                   If `None` or 0, all rows will be used.
+        :param schema_sample_strategy: Specifies which rows are sampled to determine the dtype of
+                                       a column. `head` (the default) samples the first
+                                       `schema_sample_rows` rows, whereas `head_tail` samples
+                                       half of them at the top of the sheet and the other half
+                                       at its bottom, which catches type changes happening at
+                                       the end of a sheet (e.g. a summary row).
+        :param expand_sample_on_null_columns: If `True`, the dtype of columns which only contain
+                                              null values within the sampled rows is determined
+                                              from all their rows, so sparse columns are not
+                                              loaded as nulls. Otherwise, a warning is logged
+                                              for such columns. Defaults to `False`.
         :param dtype_coercion: Specifies how type coercion should behave. `coerce` (the default)
                                will try to coerce different dtypes in a column to the same one,
                                whereas `strict` will raise an error in case a column contains
-                               several dtypes. Note that this only applies to columns whose dtype
-                               is guessed, i.e. not specified via `dtypes`.
+                               several dtypes. `strict_no_bool_numeric` behaves like `coerce`,
+                               except that booleans are never coerced to another type, which is
+                               useful for flag columns. With these, numbers in columns coerced
+                               to strings are formatted as Excel displays them (e.g. `"29.02"`
+                               and `"1000"` rather than `"29.020000000000003"` and `"1000.0"`).
+                               `raw_string` loads every column as strings without any
+                               coercion, numbers keeping the full precision they are stored
+                               with (e.g. `"29.020000000000003"`), which is useful to compare
+                               stored values. Note that dtype coercion only applies to columns
+                               whose dtype is guessed, i.e. not specified via `dtypes`.
         :param use_columns: Specifies the columns to use. Can either be:
                             - `None` to select all columns
                             - A list of strings and ints, the column names and/or indices
-                              (starting at 0)
+                              (starting at 0). A string can also be a range of column names
+                              (e.g. `"Revenue:Profit"`), selecting every column between both
+                              names, inclusively
                             - A string, a comma separated list of Excel column letters and column
                               ranges (e.g. `“A:E”` or `“A,C,E:F”`, which would result in
                               `A,B,C,D,E` and `A,C,E,F`)
+                            - A compiled regex (e.g. `re.compile(r"^Q[1-4]_")`), selecting
+                              every column whose name it matches, in the order of the sheet.
+                              Its pattern must be supported by Rust's `regex` crate, which
+                              rejects look-arounds and backreferences
                             - A callable, a function that takes a column and returns a boolean
-                              indicating whether the column should be used
+                              indicating whether the column should be used. The column's dtype
+                              is already determined when the callable is called (e.g.
+                              `lambda col: col.dtype == "float"`), which means the dtype of
+                              every available column has to be inferred, whereas only the
+                              selected columns are inspected with a static selection.
+        :param exclude_columns: The names and/or indices (starting at 0) of the columns not to
+                                use, every other column being used, in the order of the sheet.
+                                Names can be ranges of column names, as in `use_columns`. A
+                                `ColumnNotFoundError` is raised if an excluded column does not
+                                exist. Cannot be used together with `use_columns`.
         :param dtypes: An optional dtype (for all columns)
                        or dict of dtypes with keys as column indices or names.
+                       Keys can also be column letters (e.g. `"B"`), like in `use_columns`
+                       ranges. A letter key applies to the column at that position unless it
+                       has its own dtype by index or name, so a column named `"B"` takes the
+                       dtype of the `"B"` key, and so does the second column.
+                       `dtypes="string"` loads every column as strings, numbers being
+                       formatted as Excel displays them (e.g. `"29.02"` rather than
+                       `"29.020000000000003"`) unless `dtype_coercion` is `raw_string`.
+        :param skip_empty_columns: If `True`, columns containing only null values in the sampled
+                                   rows are not selected, or in all their rows if their sample
+                                   is expanded with `expand_sample_on_null_columns`. Only
+                                   applies when `use_columns` is `None`. Defaults to `False`.
+        :param skip_hidden_columns: If `True`, columns hidden in the sheet are not selected. Only
+                                    applies when `use_columns` is `None`. Column visibility is
+                                    only read from XLSX workbooks. Defaults to `False`.
+        :param stop_at_empty_row: If `True`, the data stops at its first row whose cells are all
+                                  empty, leaving out anything below it (e.g. notes in a footer).
+                                  Combined with `n_rows`, whichever ends first applies.
+                                  Defaults to `False`.
+        :param null_values: The strings that should be considered as null values, replacing the
+                            default ones (`""`, `"NA"`, `"NULL"`, `"null"`, `"None"`, `"NaN"`,
+                            `"#N/A"`...). These are ignored when determining the dtype of a
+                            column, and are read as null in string columns. An empty list means
+                            that no string is considered as null. If `None` (the default), the
+                            default null strings are ignored when determining dtypes, but kept
+                            as is in string columns.
+        :param error_as_null: If `True`, cells containing any error are read as null. Otherwise,
+                              only `#N/A`, `#VALUE!`, `#NULL!`, `#REF!`, `#NUM!` and
+                              `#GETTING_DATA` are, and other errors (e.g. `#DIV/0!`) raise a
+                              `CalamineCellError` when determining the dtype of a column.
+                              `#SPILL!` and `#CALC!` cells cannot be read at all, not even as
+                              null: the sheet cannot be loaded and a `CalamineError` is raised.
+                              Defaults to `False`.
+        :param parse_string_booleans: If `True`, a column whose strings are all `"true"`,
+                                      `"false"`, `"yes"` or `"no"` (case-insensitive) is read as
+                                      a boolean column, its strings being converted to booleans.
+                                      Columns also containing other strings remain string
+                                      columns. Defaults to `False`.
+        :param parse_numeric_strings: If `True`, a column whose strings are all numbers stored as
+                                      text (e.g. `"1.2E5"`), possibly mixed with actual numbers,
+                                      is read as a float column. Columns also containing other
+                                      strings remain string columns. Defaults to `False`.
+        :param int_as_float: If `True`, columns whose dtype is guessed as `int` are read as `float`
+                             columns instead, e.g. to match pipelines where missing integers are
+                             represented as NaN. Their `dtype_from` is `"int_as_float"`. dtypes
+                             provided with `dtypes` are kept as is. Defaults to `False`.
+        :param trim_strings: If `True`, leading and trailing whitespace is removed from the strings
+                             of string columns. Strings which are empty once trimmed are read as
+                             null. Defaults to `False`.
+        :param float_string_precision: The maximum number of digits after the decimal point of
+                                       floats converted to strings, e.g. in columns loaded as
+                                       strings. Trailing zeros are trimmed. Defaults to `9`.
+        :param keep_leading_zeros: If `True`, numbers whose number format pads them with leading
+                                   zeros (e.g. `00000` for zip codes) are read as the text Excel
+                                   displays, e.g. `1234` as `"01234"`, so that columns mixing
+                                   them with IDs stored as text (e.g. `"01234"`) keep both forms.
+                                   Text cells are always read as stored. Number formats are only
+                                   read from XLSX workbooks opened from a path or bytes.
+                                   Defaults to `False`.
+        :param datetime_tz: If set, datetime columns are tagged with this timezone (e.g. `"UTC"`
+                            or `"+02:00"`), which makes them timezone-aware. Values are not
+                            converted: they are expected to already be expressed in this
+                            timezone. Defaults to `None`, which produces naive datetimes.
+        :param unnamed_column_prefix: The prefix of the names generated for columns without a
+                                      name, which are followed by the column's index (e.g.
+                                      `"__UNNAMED__0"`). Generated names are deduplicated like
+                                      any other column name. Defaults to `"__UNNAMED__"`.
+        :param duplicate_column_strategy: How columns sharing the same name are told apart:
+                                          - `"suffix"`: `id`, `id_1`, `id_2`...
+                                          - `"pandas"`: `id`, `id.1`, `id.2`..., like pandas
+                                          - `"error"`: an error is raised
+                                          Defaults to `"suffix"`.
+        :param fill_forward_columns: The columns, by index or name, whose null values are replaced
+                                     with the last non-null value above them, e.g. categories
+                                     only written on the first row of each group. Values are
+                                     never filled from rows which are not loaded (such as
+                                     skipped rows). Defaults to `None`.
+        :param rename_columns: A mapping of columns, by index or name, to the name they should
+                               have instead, e.g. to normalize names. Columns are renamed before
+                               their names are deduplicated, and are then referred to by their
+                               new name (e.g. in `dtypes`). An error is raised if a column to
+                               rename does not exist. Defaults to `None`.
+        :param exclude_totals_row: If `True`, the totals row of the table (e.g. a row of sums
+                                   added by Excel) is not loaded as data. Only available for XLSX
+                                   files opened from a path or bytes. Defaults to `False`.
         """
         output = self._reader.load_table(  # type:ignore[call-overload,misc]
             name=name,
@@ -364,9 +1438,29 @@ class ExcelReader:
             skip_rows=skip_rows,
             n_rows=n_rows,
             schema_sample_rows=schema_sample_rows,
+            schema_sample_strategy=schema_sample_strategy,
+            expand_sample_on_null_columns=expand_sample_on_null_columns,
             dtype_coercion=dtype_coercion,
             use_columns=use_columns,
+            exclude_columns=exclude_columns,
             dtypes=dtypes,
+            skip_empty_columns=skip_empty_columns,
+            skip_hidden_columns=skip_hidden_columns,
+            stop_at_empty_row=stop_at_empty_row,
+            null_values=null_values,
+            error_as_null=error_as_null,
+            parse_string_booleans=parse_string_booleans,
+            parse_numeric_strings=parse_numeric_strings,
+            int_as_float=int_as_float,
+            trim_strings=trim_strings,
+            float_string_precision=float_string_precision,
+            keep_leading_zeros=keep_leading_zeros,
+            datetime_tz=datetime_tz,
+            unnamed_column_prefix=unnamed_column_prefix,
+            duplicate_column_strategy=duplicate_column_strategy,
+            fill_forward_columns=fill_forward_columns,
+            rename_columns=rename_columns,
+            exclude_totals_row=exclude_totals_row,
             eager=eager,
         )
         if eager:
@@ -377,14 +1471,34 @@ class ExcelReader:
         self,
         idx_or_name: int | str,
         *,
-        header_row: int | None = 0,
+        header_row: int | range | Literal["auto"] | None | _Unset = _UNSET,
         column_names: list[str] | None = None,
         skip_rows: int | None = None,
         n_rows: int | None = None,
         schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | None = None,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: list[str] | list[int] | str | re.Pattern[str] | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
         dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        case_insensitive_sheet_name: bool = False,
     ) -> pa.RecordBatch:
         """Loads a sheet eagerly by index or name.
 
@@ -395,29 +1509,69 @@ class ExcelReader:
         """
         return self._reader.load_sheet(
             idx_or_name=idx_or_name,
-            header_row=header_row,
+            header_row=self._header_row_or_default(header_row),
             column_names=column_names,
             skip_rows=skip_rows,
             n_rows=n_rows,
             schema_sample_rows=schema_sample_rows,
+            schema_sample_strategy=schema_sample_strategy,
+            expand_sample_on_null_columns=expand_sample_on_null_columns,
             dtype_coercion=dtype_coercion,
             use_columns=use_columns,
+            exclude_columns=exclude_columns,
             dtypes=dtypes,
+            skip_empty_columns=skip_empty_columns,
+            skip_hidden_columns=skip_hidden_columns,
+            stop_at_empty_row=stop_at_empty_row,
+            null_values=null_values,
+            error_as_null=error_as_null,
+            parse_string_booleans=parse_string_booleans,
+            parse_numeric_strings=parse_numeric_strings,
+            int_as_float=int_as_float,
+            trim_strings=trim_strings,
+            float_string_precision=float_string_precision,
+            keep_leading_zeros=keep_leading_zeros,
+            datetime_tz=datetime_tz,
+            unnamed_column_prefix=unnamed_column_prefix,
+            duplicate_column_strategy=duplicate_column_strategy,
+            fill_forward_columns=fill_forward_columns,
+            rename_columns=rename_columns,
             eager=True,
+            case_insensitive_sheet_name=case_insensitive_sheet_name,
         )
 
     def load_sheet_by_name(
         self,
         name: str,
         *,
-        header_row: int | None = 0,
+        header_row: int | range | Literal["auto"] | None | _Unset = _UNSET,
         column_names: list[str] | None = None,
         skip_rows: int | None = None,
         n_rows: int | None = None,
         schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
         dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        case_insensitive_sheet_name: bool = False,
     ) -> ExcelSheet:
         """Loads a sheet by name.
 
@@ -425,28 +1579,67 @@ class ExcelReader:
         """
         return self.load_sheet(
             name,
-            header_row=header_row,
+            header_row=self._header_row_or_default(header_row),
             column_names=column_names,
             skip_rows=skip_rows,
             n_rows=n_rows,
             schema_sample_rows=schema_sample_rows,
+            schema_sample_strategy=schema_sample_strategy,
+            expand_sample_on_null_columns=expand_sample_on_null_columns,
             dtype_coercion=dtype_coercion,
             use_columns=use_columns,
+            exclude_columns=exclude_columns,
             dtypes=dtypes,
+            skip_empty_columns=skip_empty_columns,
+            skip_hidden_columns=skip_hidden_columns,
+            stop_at_empty_row=stop_at_empty_row,
+            null_values=null_values,
+            error_as_null=error_as_null,
+            parse_string_booleans=parse_string_booleans,
+            parse_numeric_strings=parse_numeric_strings,
+            int_as_float=int_as_float,
+            trim_strings=trim_strings,
+            float_string_precision=float_string_precision,
+            keep_leading_zeros=keep_leading_zeros,
+            datetime_tz=datetime_tz,
+            unnamed_column_prefix=unnamed_column_prefix,
+            duplicate_column_strategy=duplicate_column_strategy,
+            fill_forward_columns=fill_forward_columns,
+            rename_columns=rename_columns,
+            case_insensitive_sheet_name=case_insensitive_sheet_name,
         )
 
     def load_sheet_by_idx(
         self,
         idx: int,
         *,
-        header_row: int | None = 0,
+        header_row: int | range | Literal["auto"] | None | _Unset = _UNSET,
         column_names: list[str] | None = None,
         skip_rows: int | None = None,
         n_rows: int | None = None,
         schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
         dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
     ) -> ExcelSheet:
         """Loads a sheet by index.
 
@@ -454,28 +1647,94 @@ class ExcelReader:
         """
         return self.load_sheet(
             idx,
-            header_row=header_row,
+            header_row=self._header_row_or_default(header_row),
             column_names=column_names,
             skip_rows=skip_rows,
             n_rows=n_rows,
             schema_sample_rows=schema_sample_rows,
+            schema_sample_strategy=schema_sample_strategy,
+            expand_sample_on_null_columns=expand_sample_on_null_columns,
             dtype_coercion=dtype_coercion,
             use_columns=use_columns,
+            exclude_columns=exclude_columns,
             dtypes=dtypes,
+            skip_empty_columns=skip_empty_columns,
+            skip_hidden_columns=skip_hidden_columns,
+            stop_at_empty_row=stop_at_empty_row,
+            null_values=null_values,
+            error_as_null=error_as_null,
+            parse_string_booleans=parse_string_booleans,
+            parse_numeric_strings=parse_numeric_strings,
+            int_as_float=int_as_float,
+            trim_strings=trim_strings,
+            float_string_precision=float_string_precision,
+            keep_leading_zeros=keep_leading_zeros,
+            datetime_tz=datetime_tz,
+            unnamed_column_prefix=unnamed_column_prefix,
+            duplicate_column_strategy=duplicate_column_strategy,
+            fill_forward_columns=fill_forward_columns,
+            rename_columns=rename_columns,
         )
 
+    def close(self) -> None:
+        """Releases the file handle or buffer held by the reader.
+
+        Loading data or reading anything else from the workbook afterwards (e.g. `defined_names`
+        or `has_sheet`, which may look sheets up by code name) raises an `InvalidParametersError`,
+        whereas sheets and tables which were already loaded remain usable, as do the sheet names,
+        `sheet_count` and `sheet_visibilities`. Closing a reader several times is allowed. The
+        cached sheet cells are released as well. The reader can also be used as a context manager,
+        which closes it on exit.
+        """
+        self._reader.close()
+
+    def __enter__(self) -> ExcelReader:
+        return self
+
+    def __exit__(self, *_: object) -> None:
+        self.close()
+
     def __repr__(self) -> str:
         return self._reader.__repr__()
 
 
-def read_excel(source: Path | str | bytes) -> ExcelReader:
+def read_excel(
+    source: Path | str | bytes | typing.BinaryIO,
+    *,
+    format: WorkbookFormat | None = None,
+    sheet_header_row: int | range | Literal["auto"] | None = 0,
+    range_cache_size: int = 0,
+) -> ExcelReader:
     """Opens and loads an excel file.
 
-    :param source: The path to a file or its content as bytes
+    :param source: The path to a file, its content as bytes, or a binary file-like object (with
+                   `read` and `seek` methods, e.g. `io.BytesIO`, a file opened in `"rb"` mode
+                   or an entry of a zip archive opened with `zipfile.ZipFile.open`).
+                   File-like objects are read on demand rather than copied in memory, so they
+                   must stay open as long as sheets are loaded from the reader. Hidden columns
+                   are not available for file-like objects.
+    :param format: Forces the format of the workbook (`"xlsx"`, `"xls"`, `"xlsb"` or `"ods"`).
+                   If `None` (the default), the format is guessed from the file's extension
+                   or content. Useful for files with a wrong extension.
+    :param sheet_header_row: The default `header_row` of the whole sheets loaded by the reader,
+                             used by `load_sheet`, `load_sheet_eager`, `load_sheet_by_name`,
+                             `load_sheet_by_idx`, `load_sheets`, `load_sheets_union` and
+                             `available_column_names` when no `header_row` is passed to them:
+                             an explicitly passed `header_row` takes precedence. Useful to load
+                             many sheets the same way (e.g. `None` for workbooks without any
+                             column labels). It does not apply to `load_range`,
+                             `load_defined_name` and `load_table`, whose header rows are
+                             relative to their region. Defaults to 0.
+    :param range_cache_size: The number of recently loaded sheets whose cells are kept in memory,
+                             so that loading them again (e.g. with other options) does not parse
+                             them again. Defaults to 0, i.e. no caching.
     """
     if isinstance(source, (str, Path)):
         source = expanduser(source)
-    return ExcelReader(_read_excel(source))
+    return ExcelReader(
+        _read_excel(source, format=format, range_cache_size=range_cache_size),
+        sheet_header_row=sheet_header_row,
+    )
 
 
 __all__ = (
@@ -486,6 +1745,13 @@ __all__ = (
     ## Python types
     "DType",
     "DTypeMap",
+    "DTypeCoercion",
+    "DuplicateColumnStrategy",
+    "OutputFormat",
+    "SchemaSampleStrategy",
+    "SheetVisible",
+    "UseColumns",
+    "WorkbookFormat",
     # Excel reader
     "ExcelReader",
     # Excel sheet
@@ -494,11 +1760,20 @@ __all__ = (
     "DTypeFrom",
     "ColumnNameFrom",
     "ColumnInfo",
+    # Table metadata
+    "TableInfo",
+    # Cell styles
+    "CellStyle",
+    # Defined names
+    "DefinedName",
+    # Workbook metadata
+    "WorkbookMetadata",
     # Exceptions
     "FastExcelError",
     "CannotRetrieveCellDataError",
     "CalamineCellError",
     "CalamineError",
+    "EncryptedWorkbookError",
     "SheetNotFoundError",
     "ColumnNotFoundError",
     "ArrowError",
@@ -1,15 +1,24 @@
 from __future__ import annotations
 
+import re
 import typing
 from typing import Callable, Literal
 
 import pyarrow as pa
 
-DType = Literal["null", "int", "float", "string", "boolean", "datetime", "date", "duration"]
+DType = Literal["null", "int", "float", "string", "boolean", "datetime", "date", "duration", "time"]
 DTypeMap = dict[str | int, DType]
+DTypeCoercion = Literal["coerce", "strict", "strict_no_bool_numeric", "raw_string"]
 ColumnNameFrom = Literal["provided", "looked_up", "generated"]
-DTypeFrom = Literal["provided_for_all", "provided_by_index", "provided_by_name", "guessed"]
+DTypeFrom = Literal[
+    "provided_for_all", "provided_by_index", "provided_by_name", "guessed", "int_as_float"
+]
 SheetVisible = Literal["visible", "hidden", "veryhidden"]
+WorkbookFormat = Literal["xlsx", "xls", "xlsb", "ods"]
+OutputFormat = Literal["arrow_ipc", "csv", "parquet"]
+SchemaSampleStrategy = Literal["head", "head_tail"]
+DuplicateColumnStrategy = Literal["suffix", "pandas", "error"]
+UseColumns = list[str] | list[int] | str | re.Pattern[str] | Callable[[ColumnInfo], bool]
 
 class ColumnInfo:
     def __init__(
@@ -31,6 +40,46 @@ class ColumnInfo:
     def column_name_from(self) -> ColumnNameFrom: ...
     @property
     def dtype_from(self) -> DTypeFrom: ...
+    @property
+    def observed_dtypes(self) -> list[DType] | None: ...
+
+class TableInfo:
+    @property
+    def name(self) -> str: ...
+    @property
+    def sheet_name(self) -> str: ...
+    @property
+    def range(self) -> str: ...
+    @property
+    def column_names(self) -> list[str]: ...
+    @property
+    def has_header_row(self) -> bool: ...
+    @property
+    def has_totals_row(self) -> bool: ...
+
+class CellStyle:
+    @property
+    def fill_color(self) -> str | None: ...
+    @property
+    def bold(self) -> bool: ...
+
+class DefinedName:
+    @property
+    def name(self) -> str: ...
+    @property
+    def formula(self) -> str: ...
+    @property
+    def scope(self) -> str | None: ...
+
+class WorkbookMetadata:
+    @property
+    def title(self) -> str | None: ...
+    @property
+    def creator(self) -> str | None: ...
+    @property
+    def created(self) -> str | None: ...
+    @property
+    def modified(self) -> str | None: ...
 
 class _ExcelSheet:
     @property
@@ -46,6 +95,22 @@ class _ExcelSheet:
     def total_height(self) -> int:
         """The sheet's total height"""
     @property
+    def skipped_row_count(self) -> int:
+        """The number of data rows skipped because of `skip_rows`"""
+    def is_empty(self) -> bool:
+        """Whether the sheet has no data rows"""
+    def has_only_null_columns(self) -> bool:
+        """Whether all selected columns have the null dtype"""
+    @property
+    def header_row(self) -> int | None:
+        """The index of the sheet's row the column labels were read from"""
+    @property
+    def data_bounds(self) -> tuple[int, int] | None:
+        """The indices of the first and last non-empty rows of the sheet"""
+    @property
+    def hidden_columns(self) -> list[int]:
+        """The indices of the sheet's hidden columns"""
+    @property
     def offset(self) -> int:
         """The sheet's offset before data starts"""
     @property
@@ -60,8 +125,27 @@ class _ExcelSheet:
     @property
     def visible(self) -> SheetVisible:
         """The visibility of the sheet"""
+    @property
+    def tab_color(self) -> str | None:
+        """The ARGB hex color of the sheet's tab, if set"""
     def to_arrow(self) -> pa.RecordBatch:
         """Converts the sheet to a pyarrow `RecordBatch`"""
+    def to_arrow_with_schema(self, schema: pa.Schema) -> pa.RecordBatch:
+        """Converts the sheet to a pyarrow `RecordBatch` with the given schema"""
+    def column_by_name(self, name: str) -> pa.Array:
+        """Loads a single selected column, by name"""
+    def column_by_index(self, index: int) -> pa.Array:
+        """Loads a single selected column, by index"""
+    def raw_cell_string(self, row: int, col: int) -> str | None:
+        """The raw value of a cell as a string, relative to the first data row"""
+    def arrow_schema(self) -> pa.Schema:
+        """The pyarrow schema of the selected columns"""
+    def schema_hint(self) -> list[tuple[str, str]]:
+        """The name and the pyarrow type of every selected column"""
+    def stream_to(self, path: str, *, format: OutputFormat, batch_size: int = 10_000) -> None:
+        """Writes the sheet to a file in the given format, one batch at a time"""
+    def to_csv_string(self) -> str:
+        """Returns the sheet's selected data as CSV"""
 
 class _ExcelTable:
     @property
@@ -102,30 +186,137 @@ class _ExcelReader:
         self,
         idx_or_name: str | int,
         *,
-        header_row: int | None = 0,
+        header_row: int | range | Literal["auto"] | None = 0,
         column_names: list[str] | None = None,
         skip_rows: int | None = None,
         n_rows: int | None = None,
         schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
         dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
         eager: Literal[False] = ...,
+        case_insensitive_sheet_name: bool = False,
     ) -> _ExcelSheet: ...
     @typing.overload
     def load_sheet(
         self,
         idx_or_name: str | int,
         *,
-        header_row: int | None = 0,
+        header_row: int | range | Literal["auto"] | None = 0,
         column_names: list[str] | None = None,
         skip_rows: int | None = None,
         n_rows: int | None = None,
         schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | None = None,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: list[str] | list[int] | str | re.Pattern[str] | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
         dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
         eager: Literal[True] = ...,
+        case_insensitive_sheet_name: bool = False,
+    ) -> pa.RecordBatch: ...
+    def load_sheets(
+        self,
+        idxs_or_names: list[int | str],
+        *,
+        header_row: int | range | Literal["auto"] | None = 0,
+        column_names: list[str] | None = None,
+        skip_rows: int | None = None,
+        n_rows: int | None = None,
+        schema_sample_rows: int | None = 1_000,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
+        dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        case_insensitive_sheet_name: bool = False,
+    ) -> list[_ExcelSheet]: ...
+    def load_sheets_union(
+        self,
+        idxs_or_names: list[int | str],
+        *,
+        header_row: int | range | Literal["auto"] | None = 0,
+        column_names: list[str] | None = None,
+        skip_rows: int | None = None,
+        n_rows: int | None = None,
+        schema_sample_rows: int | None = 1_000,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
+        dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        add_sheet_name_column: bool = False,
+        case_insensitive_sheet_name: bool = False,
     ) -> pa.RecordBatch: ...
     @typing.overload
     def load_table(
@@ -137,9 +328,29 @@ class _ExcelReader:
         skip_rows: int = 0,
         n_rows: int | None = None,
         schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
         dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        exclude_totals_row: bool = False,
         eager: Literal[False] = ...,
     ) -> _ExcelTable: ...
     @typing.overload
@@ -152,16 +363,145 @@ class _ExcelReader:
         skip_rows: int = 0,
         n_rows: int | None = None,
         schema_sample_rows: int | None = 1_000,
-        dtype_coercion: Literal["coerce", "strict"] = "coerce",
-        use_columns: list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None = None,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
         dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        exclude_totals_row: bool = False,
         eager: Literal[True] = ...,
     ) -> pa.RecordBatch: ...
+    def load_range(
+        self,
+        idx_or_name: int | str,
+        a1_range: str,
+        *,
+        header_row: int | None = 0,
+        column_names: list[str] | None = None,
+        skip_rows: int = 0,
+        n_rows: int | None = None,
+        schema_sample_rows: int | None = 1_000,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
+        dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        case_insensitive_sheet_name: bool = False,
+    ) -> _ExcelSheet: ...
+    def load_defined_name(
+        self,
+        name: str,
+        *,
+        header_row: int | None = 0,
+        column_names: list[str] | None = None,
+        skip_rows: int = 0,
+        n_rows: int | None = None,
+        schema_sample_rows: int | None = 1_000,
+        schema_sample_strategy: SchemaSampleStrategy = "head",
+        expand_sample_on_null_columns: bool = False,
+        dtype_coercion: DTypeCoercion = "coerce",
+        use_columns: UseColumns | None = None,
+        exclude_columns: list[str] | list[int] | None = None,
+        dtypes: DType | DTypeMap | None = None,
+        skip_empty_columns: bool = False,
+        skip_hidden_columns: bool = False,
+        stop_at_empty_row: bool = False,
+        null_values: list[str] | None = None,
+        error_as_null: bool = False,
+        parse_string_booleans: bool = False,
+        parse_numeric_strings: bool = False,
+        int_as_float: bool = False,
+        trim_strings: bool = False,
+        float_string_precision: int = 9,
+        keep_leading_zeros: bool = False,
+        datetime_tz: str | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        fill_forward_columns: list[int] | list[str] | None = None,
+        rename_columns: dict[int | str, str] | None = None,
+        case_insensitive_sheet_name: bool = False,
+    ) -> _ExcelSheet: ...
+    def available_column_names(
+        self,
+        idx_or_name: int | str,
+        *,
+        header_row: int | range | Literal["auto"] | None = 0,
+        column_names: list[str] | None = None,
+        unnamed_column_prefix: str = "__UNNAMED__",
+        duplicate_column_strategy: DuplicateColumnStrategy = "suffix",
+        case_insensitive_sheet_name: bool = False,
+    ) -> list[str]: ...
+    def sheet_header(
+        self, idx_or_name: int | str, *, header_row: int | range | Literal["auto"] = 0
+    ) -> list[str]: ...
+    def sheet_dimensions(self, idx_or_name: int | str) -> tuple[int, int]: ...
+    def sheet_formulas(self, idx_or_name: int | str) -> list[tuple[int, int, str]]: ...
+    def cell_styles(self, idx_or_name: int | str) -> list[tuple[int, int, CellStyle]]: ...
+    @property
+    def defined_names(self) -> list[tuple[str, str]]: ...
+    @property
+    def defined_names_with_scope(self) -> list[DefinedName]: ...
+    def defined_names_for_sheet(self, sheet_name: str) -> list[tuple[str, str, str]]: ...
     @property
     def sheet_names(self) -> list[str]: ...
+    @property
+    def sheet_names_with_indices(self) -> list[tuple[int, str]]: ...
+    def metadata(self) -> WorkbookMetadata: ...
+    @property
+    def sheet_count(self) -> int: ...
+    @property
+    def active_sheet(self) -> int | None: ...
+    def has_sheet(self, idx_or_name: int | str) -> bool: ...
+    @property
+    def sheet_visibilities(self) -> list[tuple[str, SheetVisible]]: ...
+    @property
+    def sheet_tab_colors(self) -> list[tuple[str, str | None]]: ...
+    @property
+    def visible_sheet_names(self) -> list[str]: ...
     def table_names(self, sheet_name: str | None = None) -> list[str]: ...
+    def table_info(self, name: str) -> TableInfo: ...
+    def close(self) -> None: ...
 
-def read_excel(source: str | bytes) -> _ExcelReader:
+def read_excel(
+    source: str | bytes | typing.BinaryIO,
+    *,
+    format: WorkbookFormat | None = None,
+    range_cache_size: int = 0,
+) -> _ExcelReader:
     """Reads an excel file and returns an ExcelReader"""
 
 __version__: str
@@ -171,7 +511,13 @@ class FastExcelError(Exception): ...
 class UnsupportedColumnTypeCombinationError(FastExcelError): ...
 class CannotRetrieveCellDataError(FastExcelError): ...
 class CalamineCellError(FastExcelError): ...
-class CalamineError(FastExcelError): ...
+class CalamineError(FastExcelError):
+    """Raised when calamine cannot read a workbook or a sheet.
+
+    This includes sheets containing `#SPILL!` or `#CALC!` cells, which calamine does not support
+    yet: they cannot be read as null, even with `error_as_null=True`.
+    """
+class EncryptedWorkbookError(FastExcelError): ...
 class SheetNotFoundError(FastExcelError): ...
 class ColumnNotFoundError(FastExcelError): ...
 class ArrowError(FastExcelError): ...
@@ -26,3 +26,10 @@ def fastexcel_read(test_file_path: str):
     for sheet_name in reader.sheet_names:
         sheet = reader.load_sheet_by_name(sheet_name)
         sheet.to_arrow()
+
+
+def fastexcel_read_columns(test_file_path: str, use_columns: list[int]):
+    reader = read_excel(test_file_path)
+    for sheet_name in reader.sheet_names:
+        sheet = reader.load_sheet_by_name(sheet_name, use_columns=use_columns)
+        sheet.to_arrow()
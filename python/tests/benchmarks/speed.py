@@ -3,7 +3,7 @@ Compare read performance with fastexcel, xlrd and different openpyxl options
 """
 
 import pytest
-from readers import fastexcel_read, pyxl_read, xlrd_read
+from readers import fastexcel_read, fastexcel_read_columns, pyxl_read, xlrd_read
 
 
 @pytest.fixture
@@ -49,3 +49,20 @@ def test_pyxl_with_formulas(benchmark, formula_xlsx):
 @pytest.mark.benchmark(group="xlsx")
 def test_fastexcel_with_formulas(benchmark, formula_xlsx):
     benchmark(fastexcel_read, formula_xlsx)
+
+
+@pytest.fixture
+def wide_data_xlsx():
+    return "./python/tests/benchmarks/fixtures/wide_data.xlsx"
+
+
+@pytest.mark.benchmark(group="xlsx")
+def test_fastexcel_wide_xlsx(benchmark, wide_data_xlsx):
+    # 300 columns, which is where building columns in parallel (`parallel` feature) pays off
+    benchmark(fastexcel_read, wide_data_xlsx)
+
+
+@pytest.mark.benchmark(group="xlsx")
+def test_fastexcel_wide_xlsx_few_columns(benchmark, wide_data_xlsx):
+    # Only the dtypes of the 3 selected columns are inferred, out of 300
+    benchmark(fastexcel_read_columns, wide_data_xlsx, [0, 150, 299])
@@ -43,3 +43,112 @@ def test_alias_generation_with_use_columns(use_columns: list[str] | list[int] |
             }
         ).with_columns(pl.col("col_2").str.strptime(pl.Datetime, "%F %T").dt.cast_time_unit("ms")),
     )
+
+
+def test_unnamed_column_prefix() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("no-header.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, header_row=None, unnamed_column_prefix="col_")
+    assert [col.name for col in sheet.available_columns] == ["col_0", "col_1"]
+    pl_assert_frame_equal(
+        sheet.to_polars(), pl.DataFrame({"col_0": ["a", "b"], "col_1": [0.0, 1.0]})
+    )
+
+
+def test_unnamed_column_prefix_is_deduplicated() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+
+    # The name generated for the second column collides with the provided one
+    sheet = excel_reader.load_sheet(0, column_names=["col_1"], unnamed_column_prefix="col_")
+    assert [col.name for col in sheet.available_columns] == ["col_1", "col_1_1"]
+
+
+@pytest.mark.parametrize(
+    "duplicate_column_strategy, expected_names",
+    [("suffix", ["id", "name", "id_1"]), ("pandas", ["id", "name", "id.1"])],
+)
+def test_duplicate_column_strategy(
+    duplicate_column_strategy: fastexcel.DuplicateColumnStrategy, expected_names: list[str]
+) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-duplicate-ids.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, duplicate_column_strategy=duplicate_column_strategy)
+    assert [col.name for col in sheet.available_columns] == expected_names
+    pl_assert_frame_equal(
+        sheet.to_polars(),
+        pl.DataFrame(
+            {
+                expected_names[0]: [1.0, 2.0],
+                expected_names[1]: ["apple", "pear"],
+                expected_names[2]: [10.0, 20.0],
+            }
+        ),
+    )
+    names = excel_reader.available_column_names(
+        0, duplicate_column_strategy=duplicate_column_strategy
+    )
+    assert names == expected_names
+
+
+def test_duplicate_column_strategy_error() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-duplicate-ids.xlsx"))
+
+    with pytest.raises(fastexcel.InvalidParametersError, match='duplicate column name "id"'):
+        excel_reader.load_sheet(0, duplicate_column_strategy="error")
+
+    with pytest.raises(fastexcel.InvalidParametersError, match='duplicate column name "id"'):
+        excel_reader.available_column_names(0, duplicate_column_strategy="error")
+
+    # Sheets without duplicates load as usual
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    sheet = excel_reader.load_sheet(0, duplicate_column_strategy="error")
+    assert [col.name for col in sheet.available_columns] == ["Month", "Year"]
+
+
+def test_rename_columns() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    expected = pl.DataFrame({"month": [1.0, 2.0], "year": [2019.0, 2020.0]})
+
+    sheet = excel_reader.load_sheet(0, rename_columns={0: "month", "Year": "year"})
+    assert [col.name for col in sheet.selected_columns] == ["month", "year"]
+    assert [col.column_name_from for col in sheet.selected_columns] == ["provided", "provided"]
+    pl_assert_frame_equal(sheet.to_polars(), expected)
+
+    rb = excel_reader.load_sheet_eager(0, rename_columns={0: "month", "Year": "year"})
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+
+def test_renamed_columns_are_referred_to_by_their_new_name() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    sheet = excel_reader.load_sheet(
+        0, rename_columns={0: "month"}, use_columns=["month"], dtypes={"month": "int"}
+    )
+    pl_assert_frame_equal(sheet.to_polars(), pl.DataFrame({"month": [1, 2]}))
+
+    # Without a header row, columns are renamed by their generated name
+    sheet = excel_reader.load_sheet(
+        0,
+        header_row=None,
+        skip_rows=1,
+        rename_columns={"__UNNAMED__1": "year"},
+        use_columns=["year"],
+    )
+    pl_assert_frame_equal(sheet.to_polars(), pl.DataFrame({"year": [2019.0, 2020.0]}))
+
+
+def test_renamed_columns_are_deduplicated() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    sheet = excel_reader.load_sheet(0, rename_columns={"Month": "Year"})
+    assert [col.name for col in sheet.selected_columns] == ["Year", "Year_1"]
+
+    with pytest.raises(fastexcel.InvalidParametersError, match='duplicate column name "Year"'):
+        excel_reader.load_sheet(
+            0, rename_columns={"Month": "Year"}, duplicate_column_strategy="error"
+        )
+
+
+@pytest.mark.parametrize("rename_columns", [{2: "day"}, {"Day": "day"}])
+def test_rename_missing_column(rename_columns: dict[int | str, str]) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    with pytest.raises(fastexcel.ColumnNotFoundError, match="could not find a column to rename"):
+        excel_reader.load_sheet(0, rename_columns=rename_columns)
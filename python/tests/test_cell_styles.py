@@ -0,0 +1,45 @@
+from __future__ import annotations
+
+import fastexcel
+import polars as pl
+from polars.testing import assert_frame_equal as pl_assert_frame_equal
+
+from utils import path_for_fixture
+
+
+def test_cell_styles() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-cell-styles.xlsx"))
+
+    styles = {
+        (row, col): (style.fill_color, style.bold)
+        for row, col, style in excel_reader.cell_styles("Checks")
+    }
+    assert styles == {
+        (0, 0): (None, True),
+        (0, 1): (None, True),
+        (2, 1): ("FFFF0000", False),
+        (3, 0): (None, True),
+        (3, 1): ("FFFFFF00", False),
+    }
+    assert excel_reader.cell_styles(0) == excel_reader.cell_styles("Checks")
+    assert repr(excel_reader.cell_styles(0)[0][2]) == "CellStyle(fill_color=None, bold=True)"
+
+
+def test_cell_styles_do_not_change_values() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-cell-styles.xlsx"))
+
+    pl_assert_frame_equal(
+        excel_reader.load_sheet(0).to_polars(),
+        pl.DataFrame({"item": ["apple", "pear", "total"], "amount": [3.0, -5.0, -2.0]}),
+    )
+
+
+def test_cell_styles_unsupported_formats() -> None:
+    excel_reader = fastexcel.read_excel(
+        path_for_fixture("fixture-sheets-different-visibilities.ods")
+    )
+    assert excel_reader.cell_styles(0) == []
+
+    with open(path_for_fixture("sheet-with-cell-styles.xlsx"), "rb") as f:
+        excel_reader = fastexcel.read_excel(f)
+    assert excel_reader.cell_styles(0) == []
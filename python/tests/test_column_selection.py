@@ -349,6 +349,14 @@ Context:
         excel_reader_single_sheet_with_unnamed_columns.load_sheet(0, use_columns=[42])
 
 
+@pytest.mark.parametrize("use_columns", ["XFE", "A:AAAAAAAAAAAAAAAAAAAA", "A,ZZZZZZZZZZZZZZZZ"])
+def test_single_sheet_invalid_column_indices_letters_too_large(
+    excel_reader_single_sheet_with_unnamed_columns: fastexcel.ExcelReader, use_columns: str
+) -> None:
+    with pytest.raises(fastexcel.InvalidParametersError, match="column reference too large"):
+        excel_reader_single_sheet_with_unnamed_columns.load_sheet(0, use_columns=use_columns)
+
+
 def test_use_columns_with_column_names() -> None:
     excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet-with-types.xlsx"))
 
@@ -460,6 +468,34 @@ def test_use_columns_with_callable() -> None:
     ]
 
 
+def test_use_columns_callable_receives_dtype() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+
+    sheet = excel_reader.load_sheet(2, use_columns=lambda col: col.dtype == "float")
+    assert [(c.name, c.dtype) for c in sheet.selected_columns] == [
+        ("col1", "float"),
+        ("__UNNAMED__1", "float"),
+        ("__UNNAMED__3", "float"),
+    ]
+    pl_assert_frame_equal(
+        sheet.to_polars(),
+        pl.DataFrame(
+            {"col1": [2.0, 3.0], "__UNNAMED__1": [1.5, 2.5], "__UNNAMED__3": [-5.0, -6.0]}
+        ),
+    )
+
+    # Specified dtypes are applied before the callable is called
+    seen: list[tuple[str, str, str]] = []
+
+    def use_column(col: fastexcel.ColumnInfo) -> bool:
+        seen.append((col.name, col.dtype, col.dtype_from))
+        return col.dtype == "float"
+
+    sheet = excel_reader.load_sheet(2, dtypes={"col1": "string"}, use_columns=use_column)
+    assert seen[0] == ("col1", "string", "provided_by_name")
+    assert [c.name for c in sheet.selected_columns] == ["__UNNAMED__1", "__UNNAMED__3"]
+
+
 def test_use_columns_with_bad_callable() -> None:
     excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
     with pytest.raises(
@@ -549,3 +585,205 @@ def test_use_columns_dtypes_eager_loading(
 
         assert pd_df.columns.to_list() == use_columns
         assert pl_df.columns == use_columns
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_skip_empty_columns(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-trailing-empty-columns.xlsx"))
+
+    sheet = excel_reader.load_sheet(0)
+    assert [col.name for col in sheet.selected_columns] == [
+        "name",
+        "value",
+        "__UNNAMED__2",
+        "__UNNAMED__3",
+    ]
+
+    sheet = excel_reader.load_sheet(0, skip_empty_columns=True)
+    assert [col.name for col in sheet.selected_columns] == ["name", "value"]
+    # Available columns are not affected
+    assert len(sheet.available_columns) == 4
+
+    expected = pl.DataFrame({"name": ["a", "b", "c"], "value": [1.0, 2.0, 3.0]})
+    if eager:
+        pl_assert_frame_equal(
+            pl.DataFrame(excel_reader.load_sheet_eager(0, skip_empty_columns=True)), expected
+        )
+    else:
+        pl_assert_frame_equal(sheet.to_polars(), expected)
+
+
+def test_skip_empty_columns_with_explicit_selection() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-trailing-empty-columns.xlsx"))
+
+    # Explicitly selected columns are kept, even if they are empty
+    sheet = excel_reader.load_sheet(0, use_columns=[1, 3], skip_empty_columns=True)
+    assert [(col.name, col.index) for col in sheet.selected_columns] == [
+        ("value", 1),
+        ("__UNNAMED__3", 3),
+    ]
+    assert sheet.to_polars()["value"].to_list() == [1.0, 2.0, 3.0]
+
+    # Columns with a specified dtype are kept too
+    sheet = excel_reader.load_sheet(0, dtypes={3: "string"}, skip_empty_columns=True)
+    assert [col.name for col in sheet.selected_columns] == ["name", "value", "__UNNAMED__3"]
+
+
+def test_use_columns_with_name_range() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-grouped-categories.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, use_columns=["group:amount"])
+    assert [col.name for col in sheet.selected_columns] == ["group", "product", "amount"]
+
+    # Ranges can be mixed with names and indices
+    sheet = excel_reader.load_sheet(0, use_columns=["product:amount", 0])
+    assert [col.name for col in sheet.selected_columns] == ["product", "amount", "category"]
+
+    with pytest.raises(
+        fastexcel.InvalidParametersError, match='end of range is before start: "amount:group"'
+    ):
+        excel_reader.load_sheet(0, use_columns=["amount:group"])
+
+    with pytest.raises(fastexcel.ColumnNotFoundError, match='column with name "group:nope"'):
+        excel_reader.load_sheet(0, use_columns=["group:nope"])
+
+
+def test_use_columns_with_pattern() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-quarterly-columns.xlsx"))
+    expected = pl.DataFrame(
+        {
+            "Q1_sales": [10.0, 8.0],
+            "Q2_sales": [12.0, 7.0],
+            "Q3_sales": [9.0, 11.0],
+            "Q4_sales": [14.0, 10.0],
+        }
+    )
+
+    sheet = excel_reader.load_sheet(0, use_columns=re.compile(r"^Q[1-4]_sales"))
+    assert [col.name for col in sheet.selected_columns] == expected.columns
+    pl_assert_frame_equal(sheet.to_polars(), expected)
+
+    rb = excel_reader.load_sheet_eager(0, use_columns=re.compile(r"^Q[1-4]_sales"))
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+    # Flags with an inline equivalent are kept
+    sheet = excel_reader.load_sheet(0, use_columns=re.compile(r"^q1_", re.IGNORECASE))
+    assert [col.name for col in sheet.selected_columns] == ["Q1_sales", "Q1_target"]
+
+    # No matching column results in an empty selection
+    assert excel_reader.load_sheet(0, use_columns=re.compile("^Q5")).selected_columns == []
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="invalid regex"):
+        excel_reader.load_sheet(0, use_columns=re.compile(r"(?<=Q)1"))
+
+
+def test_exclude_columns() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-quarterly-columns.xlsx"))
+    expected = pl.DataFrame(
+        {
+            "region": ["north", "south"],
+            "Q1_sales": [10.0, 8.0],
+            "Q2_sales": [12.0, 7.0],
+            "Q3_sales": [9.0, 11.0],
+            "Q4_sales": [14.0, 10.0],
+        }
+    )
+
+    sheet = excel_reader.load_sheet(0, exclude_columns=["Q1_target", "total_sales"])
+    assert [col.name for col in sheet.selected_columns] == expected.columns
+    # Excluded columns remain available
+    assert len(sheet.available_columns) == 7
+    pl_assert_frame_equal(sheet.to_polars(), expected)
+
+    rb = excel_reader.load_sheet_eager(0, exclude_columns=[5, 6])
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+    with pytest.raises(fastexcel.ColumnNotFoundError, match="could not find a column to exclude"):
+        excel_reader.load_sheet(0, exclude_columns=["Q5_sales"])
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="cannot be used together"):
+        excel_reader.load_sheet(0, use_columns=["region"], exclude_columns=["total_sales"])
+
+
+@pytest.mark.parametrize("eager", [False, True])
+def test_column_names_and_use_columns_of_different_lengths(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    with pytest.raises(
+        fastexcel.InvalidParametersError,
+        match="column_names and use_columns must have the same length",
+    ):
+        # Contradictory options are reported before the sheet is read, so skip_rows being out of
+        # bounds does not matter
+        excel_reader.load_sheet(
+            0, column_names=["a", "b"], use_columns=[0], skip_rows=1_000, eager=eager
+        )
+
+
+def test_use_columns_names_with_column_names() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    with pytest.raises(
+        fastexcel.InvalidParametersError,
+        match='use_columns can only contain integers when used with columns_names, got "Year"',
+    ):
+        excel_reader.load_sheet(0, column_names=["a", "b"], use_columns=[0, "Year"])
+
+    with pytest.raises(
+        fastexcel.InvalidParametersError, match="column_names and use_columns must have the same"
+    ):
+        excel_reader.load_sheets([0], column_names=["a"], use_columns=[0, 1])
+
+    with pytest.raises(
+        fastexcel.InvalidParametersError, match="column_names and use_columns must have the same"
+    ):
+        excel_reader.load_range(0, "A1:B3", column_names=["a"], use_columns=[0, 1])
+
+
+def test_duplicate_column_names_with_error_strategy() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    with pytest.raises(
+        fastexcel.InvalidParametersError,
+        match='duplicate column name "a" in column_names',
+    ):
+        excel_reader.load_sheet(0, column_names=["a", "a"], duplicate_column_strategy="error")
+
+    # Duplicates are told apart with the other strategies
+    sheet = excel_reader.load_sheet(0, column_names=["a", "a"])
+    assert [col.name for col in sheet.selected_columns] == ["a", "a_1"]
+
+
+def test_names_without_header_row() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    with pytest.raises(
+        fastexcel.InvalidParametersError,
+        match=(
+            'column "Month" cannot be referred to by name without a header row nor column_names, '
+            'columns are named "__UNNAMED__<index>"'
+        ),
+    ):
+        excel_reader.load_sheet(0, header_row=None, use_columns=["Month"])
+
+    with pytest.raises(fastexcel.InvalidParametersError, match='column "Year" cannot be referred'):
+        excel_reader.load_sheet(0, header_row=None, fill_forward_columns=["Year"])
+
+    with pytest.raises(fastexcel.InvalidParametersError, match='column "Year" cannot be referred'):
+        excel_reader.load_sheet(
+            0, header_row=None, exclude_columns=["Year"], unnamed_column_prefix="col_"
+        )
+
+    # Generated names can be referred to
+    sheet = excel_reader.load_sheet(0, header_row=None, use_columns=["__UNNAMED__1"])
+    assert [col.name for col in sheet.selected_columns] == ["__UNNAMED__1"]
+    sheet = excel_reader.load_sheet(
+        0, header_row=None, use_columns=["col_0"], unnamed_column_prefix="col_"
+    )
+    assert [col.name for col in sheet.selected_columns] == ["col_0"]
+
+
+def test_table_options_are_validated() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-tables.xlsx"))
+
+    with pytest.raises(
+        fastexcel.InvalidParametersError,
+        match="column_names and use_columns must have the same length",
+    ):
+        excel_reader.load_table("users", column_names=["a", "b"], use_columns=[0])
@@ -0,0 +1,129 @@
+from __future__ import annotations
+
+import fastexcel
+import pytest
+
+from utils import path_for_fixture
+
+
+def test_defined_names() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-defined-names.xlsx"))
+
+    assert excel_reader.defined_names == [
+        ("prices", "Data!$B$2:$C$4"),
+        ("rate", "Data!$C$6"),
+        ("vat", "0.2"),
+        ("split", "Data!$B$2:$B$4,Data!$C$2:$C$4"),
+        ("other", "'Other Sheet'!$A$1:$B$3"),
+    ]
+
+
+def test_defined_names_for_sheet() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-defined-names.xlsx"))
+
+    # The constant and the multi-area names are skipped
+    assert excel_reader.defined_names_for_sheet("Data") == [
+        ("prices", "Data", "B2:C4"),
+        ("rate", "Data", "C6:C6"),
+    ]
+    assert excel_reader.defined_names_for_sheet("Other Sheet") == [
+        ("other", "Other Sheet", "A1:B3"),
+    ]
+
+
+def test_defined_names_for_sheet_load_range() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-defined-names.xlsx"))
+
+    [(_, sheet_name, a1_range)] = [
+        defined_name
+        for defined_name in excel_reader.defined_names_for_sheet("Data")
+        if defined_name[0] == "prices"
+    ]
+    sheet = excel_reader.load_range(sheet_name, a1_range)
+    assert sheet.to_polars().to_dict(as_series=False) == {
+        "product": ["apple", "pear"],
+        "price": [1.5, 2.0],
+    }
+
+
+def test_defined_names_for_sheet_not_found() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-defined-names.xlsx"))
+
+    with pytest.raises(fastexcel.SheetNotFoundError, match='sheet with name "nope" not found'):
+        excel_reader.defined_names_for_sheet("nope")
+
+
+def test_load_defined_name() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-defined-names.xlsx"))
+
+    sheet = excel_reader.load_defined_name("prices")
+    assert sheet.name == "Data"
+    assert sheet.to_polars().to_dict(as_series=False) == {
+        "product": ["apple", "pear"],
+        "price": [1.5, 2.0],
+    }
+
+    sheet = excel_reader.load_defined_name("other", header_row=None)
+    assert sheet.to_polars().to_dict(as_series=False) == {
+        "__UNNAMED__0": ["a", "1", "3"],
+        "__UNNAMED__1": ["b", "2", "4"],
+    }
+
+
+def test_load_defined_name_case_insensitive_sheet_name() -> None:
+    # The defined name refers to "DATA" whereas the sheet is named "Data"
+    excel_reader = fastexcel.read_excel(
+        path_for_fixture("sheet-with-defined-name-in-other-case.xlsx")
+    )
+
+    with pytest.raises(fastexcel.SheetNotFoundError):
+        excel_reader.load_defined_name("prices")
+
+    sheet = excel_reader.load_defined_name("prices", case_insensitive_sheet_name=True)
+    assert sheet.name == "Data"
+    assert sheet.to_polars().to_dict(as_series=False) == {
+        "product": ["apple", "pear"],
+        "price": [1.5, 2.0],
+    }
+
+
+@pytest.mark.parametrize(
+    "name, expected_message",
+    [
+        ("nope", 'defined name "nope" not found'),
+        ("vat", '"0.2" is not a reference to a range of a sheet'),
+        ("split", "refers to several areas"),
+    ],
+)
+def test_load_defined_name_invalid(name: str, expected_message: str) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-defined-names.xlsx"))
+
+    with pytest.raises(fastexcel.InvalidParametersError, match=expected_message):
+        excel_reader.load_defined_name(name)
+
+
+def test_defined_names_with_scope() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-local-defined-names.xlsx"))
+
+    defined_names = excel_reader.defined_names_with_scope
+    assert [(dn.name, dn.formula, dn.scope) for dn in defined_names] == [
+        ("rate", "0.2", None),
+        # Sheet-local names can be defined once per sheet
+        ("sales", "North!$B$2:$B$3", "North"),
+        ("sales", "South!$B$2:$B$3", "South"),
+    ]
+    assert repr(defined_names[1]) == (
+        'DefinedName(name="sales", formula="North!$B$2:$B$3", scope="North")'
+    )
+
+
+def test_defined_names_with_scope_global_names() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-defined-names.xlsx"))
+
+    assert [(dn.name, dn.scope) for dn in excel_reader.defined_names_with_scope] == [
+        ("prices", None),
+        ("rate", None),
+        ("vat", None),
+        ("split", None),
+        ("other", None),
+    ]
@@ -7,6 +7,7 @@ import fastexcel
 import numpy as np
 import pandas as pd
 import polars as pl
+import pyarrow as pa
 import pytest
 from pandas.testing import assert_frame_equal as pd_assert_frame_equal
 from polars.testing import assert_frame_equal as pl_assert_frame_equal
@@ -165,6 +166,40 @@ def test_sheet_with_mixed_dtypes_specify_dtypes(
     assert pl_df["Employee ID"].to_list() == (expected_data if dtype != "duration" else [None] * 5)
 
 
+@pytest.mark.parametrize("eager", [False, True])
+def test_dtypes_by_column_letter(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    expected = pl.DataFrame({"Month": [1.0, 2.0], "Year": ["2019", "2020"]})
+
+    sheet = excel_reader.load_sheet(0, dtypes={"B": "string"})
+    assert [(col.dtype, col.dtype_from) for col in sheet.selected_columns] == [
+        ("float", "guessed"),
+        ("string", "provided_by_index"),
+    ]
+    if eager:
+        rb = excel_reader.load_sheet(0, dtypes={"B": "string"}, eager=True)
+        pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+    else:
+        pl_assert_frame_equal(sheet.to_polars(), expected)
+
+
+def test_dtypes_by_index_or_name_take_precedence_over_column_letters() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, dtypes={"B": "string", 1: "int"})
+    assert [col.dtype for col in sheet.selected_columns] == ["float", "int"]
+
+    sheet = excel_reader.load_sheet(0, dtypes={"B": "string", "Year": "int"})
+    assert [col.dtype for col in sheet.selected_columns] == ["float", "int"]
+
+    # A column named like a letter is looked up by name
+    sheet = excel_reader.load_sheet(0, column_names=["B", "A"], dtypes={"B": "string"})
+    assert [(col.dtype, col.dtype_from) for col in sheet.selected_columns] == [
+        ("string", "provided_by_name"),
+        ("string", "provided_by_index"),
+    ]
+
+
 @pytest.mark.parametrize(
     "dtypes,expected,expected_pd_dtype,expected_pl_dtype",
     [
@@ -196,6 +231,115 @@ def test_sheet_datetime_conversion(
     assert pl_df["Date"].to_list() == [expected] * 9
 
 
+@pytest.mark.parametrize("datetime_tz", ["UTC", "+02:00"])
+def test_sheet_datetime_tz(datetime_tz: str) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, datetime_tz=datetime_tz)
+    expected_type = pa.timestamp("ms", tz=datetime_tz)
+    rb = sheet.to_arrow()
+    assert rb.schema.field("Date").type == expected_type
+    # Values are not converted, only tagged with the timezone
+    assert rb["Date"].cast(pa.timestamp("ms")).to_pylist() == [datetime(2023, 7, 21)] * 9
+
+    rb_eager = excel_reader.load_sheet_eager(0, datetime_tz=datetime_tz)
+    assert rb_eager.schema.field("Date").type == expected_type
+
+    table = fastexcel.read_excel(path_for_fixture("sheet-with-tables.xlsx")).load_table(
+        "users", datetime_tz=datetime_tz
+    )
+    assert table.to_arrow().schema.field("Date").type == expected_type
+
+
+def test_sheet_datetime_tz_empty() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    with pytest.raises(
+        fastexcel.InvalidParametersError, match="datetime_tz cannot be an empty string"
+    ):
+        excel_reader.load_sheet(0, datetime_tz="")
+
+
+def test_schema_hint() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    sheet = excel_reader.load_sheet(0)
+    assert sheet.schema_hint() == [
+        (field.name, str(field.type)) for field in sheet.to_arrow().schema
+    ]
+
+    sheet = excel_reader.load_sheet(
+        0,
+        use_columns=["Date", "Asset ID", "Mixed dates"],
+        dtypes={"Asset ID": "int"},
+        datetime_tz="UTC",
+    )
+    assert sheet.schema_hint() == [
+        ("Date", "timestamp[ms, tz=UTC]"),
+        ("Asset ID", "int64"),
+        ("Mixed dates", "string"),
+    ]
+
+
+def test_arrow_schema() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    sheet = excel_reader.load_sheet(0)
+    assert sheet.arrow_schema() == sheet.to_arrow().schema
+
+    sheet = excel_reader.load_sheet(
+        0,
+        use_columns=["Date", "Asset ID", "Mixed dates"],
+        dtypes={"Asset ID": "int"},
+        datetime_tz="UTC",
+    )
+    assert sheet.arrow_schema() == pa.schema(
+        [
+            ("Date", pa.timestamp("ms", tz="UTC")),
+            ("Asset ID", pa.int64()),
+            ("Mixed dates", pa.string()),
+        ]
+    )
+
+
+def test_observed_dtypes() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, dtypes={"Details": "string"})
+    columns = {column.name: column for column in sheet.available_columns}
+    assert columns["Mixed dates"].dtype == "string"
+    assert columns["Mixed dates"].observed_dtypes == ["string", "datetime"]
+    assert columns["Asset ID"].observed_dtypes == ["float", "string"]
+    # The dtype was not guessed
+    assert columns["Details"].observed_dtypes is None
+
+    # Only the sampled rows are taken into account
+    sheet = excel_reader.load_sheet(0, schema_sample_rows=5)
+    columns = {column.name: column for column in sheet.available_columns}
+    assert columns["Mixed dates"].dtype == "datetime"
+    assert columns["Mixed dates"].observed_dtypes == ["datetime"]
+
+
+@pytest.mark.parametrize("schema_sample_strategy", ["head", "head_tail"])
+def test_schema_sample_rows_zero_samples_all_rows(
+    schema_sample_strategy: fastexcel.SchemaSampleStrategy,
+) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
+
+    def dtypes(schema_sample_rows: int | None) -> dict[str, fastexcel.DType]:
+        sheet = excel_reader.load_sheet(
+            0,
+            schema_sample_rows=schema_sample_rows,
+            schema_sample_strategy=schema_sample_strategy,
+        )
+        return {column.name: column.dtype for column in sheet.available_columns}
+
+    # 0 is the same as None: all rows are sampled, and mixed columns are coerced to strings
+    assert dtypes(0) == dtypes(None)
+    assert dtypes(0)["Mixed dates"] == "string"
+    assert dtypes(0)["Asset ID"] == "string"
+
+
 @pytest.mark.parametrize("eager", [True, False])
 @pytest.mark.parametrize("dtype_coercion", ["coerce", None])
 def test_dtype_coercion_behavior__coerce(
@@ -225,7 +369,9 @@ def test_dtype_coercion_behavior__strict_sampling_eveything(eager: bool) -> None
     excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-dtypes-columns.xlsx"))
 
     with pytest.raises(
-        fastexcel.UnsupportedColumnTypeCombinationError, match="type coercion is strict"
+        fastexcel.UnsupportedColumnTypeCombinationError,
+        match=r"type coercion is strict and column at index 0 contains(.|\n)*"
+        r"could not determine dtype for column 'Employee ID' \(index 0\)",
     ):
         if eager:
             excel_reader.load_sheet_eager(0, dtype_coercion="strict")
@@ -353,3 +499,502 @@ def test_fallback_infer_dtypes(mocker) -> None:
         ),
     ]
     assert sheet.to_polars().dtypes == [pl.Float64, pl.String]
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_schema_sample_strategy_head_tail(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-summary-row.xlsx"))
+
+    def load(**kwargs: Any) -> pl.DataFrame:
+        if eager:
+            return pl.DataFrame(excel_reader.load_sheet_eager(0, schema_sample_rows=10, **kwargs))
+        return excel_reader.load_sheet(0, schema_sample_rows=10, **kwargs).to_polars()
+
+    # The summary row is not sampled by default: "TOTAL" is not a number and becomes null
+    head_df = load()
+    assert head_df.dtypes == [pl.Float64, pl.Float64]
+    assert head_df["id"].to_list()[-1] is None
+
+    head_tail_df = load(schema_sample_strategy="head_tail")
+    assert head_tail_df.dtypes == [pl.Utf8, pl.Float64]
+    assert head_tail_df["id"].to_list() == [str(i) for i in range(1, 51)] + ["TOTAL"]
+
+    sheet = excel_reader.load_sheet(0, schema_sample_rows=10, schema_sample_strategy="head_tail")
+    assert [col.dtype for col in sheet.selected_columns] == ["string", "float"]
+
+
+def test_schema_sample_strategy_invalid() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-summary-row.xlsx"))
+
+    with pytest.raises(
+        fastexcel.InvalidParametersError, match='unsupported schema_sample_strategy: "tail"'
+    ):
+        excel_reader.load_sheet(0, schema_sample_strategy="tail")  # type: ignore[arg-type]
+
+
+def test_dtype_coercion_behavior__strict_no_bool_numeric() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-bools-and-numbers.xlsx"))
+
+    # By default, booleans are coerced to numbers
+    sheet = excel_reader.load_sheet(0)
+    assert [col.dtype for col in sheet.selected_columns] == ["float", "float", "boolean"]
+    assert sheet.to_polars()["flag"].to_list() == [1.0, 0.0, 1.0]
+
+    with pytest.raises(
+        fastexcel.UnsupportedColumnTypeCombinationError,
+        match=r"column at index 0 contains \{Float, Bool\}(.|\n)*"
+        r"could not determine dtype for column 'flag' \(index 0\)",
+    ):
+        excel_reader.load_sheet(0, dtype_coercion="strict_no_bool_numeric")
+
+    # Columns with a specified dtype are not affected, neither are columns without booleans or
+    # with booleans only
+    sheet = excel_reader.load_sheet(
+        0, dtype_coercion="strict_no_bool_numeric", dtypes={"flag": "float"}
+    )
+    assert [col.dtype for col in sheet.selected_columns] == ["float", "float", "boolean"]
+    assert sheet.to_polars()["flag"].to_list() == [1.0, 0.0, 1.0]
+
+
+@pytest.mark.parametrize("dtype_coercion", ["coerce", "strict_no_bool_numeric"])
+@pytest.mark.parametrize("eager", [True, False])
+def test_dtype_coercion_behavior__numbers_coerced_to_string(
+    dtype_coercion: fastexcel.DTypeCoercion, eager: bool
+) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-numbers-and-strings.xlsx"))
+
+    def load(**kwargs: Any) -> pl.DataFrame:
+        if eager:
+            return pl.DataFrame(
+                excel_reader.load_sheet_eager(0, dtype_coercion=dtype_coercion, **kwargs)
+            )
+        return excel_reader.load_sheet(0, dtype_coercion=dtype_coercion, **kwargs).to_polars()
+
+    # Numbers are formatted as Excel displays them, 29.02 being stored as 29.020000000000003
+    df = load()
+    assert df.dtypes == [pl.String, pl.Float64]
+    assert df["value"].to_list() == ["29.02", "1000", "unknown"]
+
+    df = load(dtypes={"amount": "string"})
+    assert df["amount"].to_list() == ["29.02", "1000", "12.5"]
+
+
+@pytest.mark.parametrize("dtype_coercion", ["coerce", "strict", "strict_no_bool_numeric"])
+@pytest.mark.parametrize("eager", [True, False])
+def test_all_string_dtypes(dtype_coercion: fastexcel.DTypeCoercion, eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-numbers-and-strings.xlsx"))
+
+    if eager:
+        df = pl.DataFrame(
+            excel_reader.load_sheet_eager(0, dtypes="string", dtype_coercion=dtype_coercion)
+        )
+    else:
+        df = excel_reader.load_sheet(0, dtypes="string", dtype_coercion=dtype_coercion).to_polars()
+
+    # Every column is a string column, numbers being formatted as Excel displays them whatever
+    # the dtype coercion
+    assert df.to_dict(as_series=False) == {
+        "value": ["29.02", "1000", "unknown"],
+        "amount": ["29.02", "1000", "12.5"],
+    }
+
+
+@pytest.mark.parametrize(
+    "float_string_precision, expected",
+    [
+        (1, ["29", "1000", "12.5"]),
+        (2, ["29.02", "1000", "12.5"]),
+        (9, ["29.02", "1000", "12.5"]),
+        (15, ["29.020000000000003", "1000", "12.5"]),
+    ],
+)
+@pytest.mark.parametrize("eager", [True, False])
+def test_float_string_precision(
+    float_string_precision: int, expected: list[str], eager: bool
+) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-numbers-and-strings.xlsx"))
+    kwargs: dict[str, Any] = {
+        "dtypes": {"amount": "string"},
+        "float_string_precision": float_string_precision,
+    }
+
+    if eager:
+        df = pl.DataFrame(excel_reader.load_sheet_eager(0, **kwargs))
+    else:
+        df = excel_reader.load_sheet(0, **kwargs).to_polars()
+
+    assert df["amount"].to_list() == expected
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_dtype_coercion_behavior__raw_string(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-numbers-and-strings.xlsx"))
+
+    def load(**kwargs: Any) -> pl.DataFrame:
+        if eager:
+            return pl.DataFrame(
+                excel_reader.load_sheet_eager(0, dtype_coercion="raw_string", **kwargs)
+            )
+        return excel_reader.load_sheet(0, dtype_coercion="raw_string", **kwargs).to_polars()
+
+    # Every column is a string column, and numbers are not rounded
+    df = load()
+    assert df.dtypes == [pl.String, pl.String]
+    assert df.to_dict(as_series=False) == {
+        "value": ["29.020000000000003", "1000", "unknown"],
+        "amount": ["29.020000000000003", "1000", "12.5"],
+    }
+
+    # Specified dtypes are still applied
+    df = load(dtypes={"amount": "float"})
+    assert df.dtypes == [pl.String, pl.Float64]
+
+
+@pytest.mark.parametrize("eager", [True, False])
+def test_integers_out_of_i64_range(eager: bool) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-big-integers.xlsx"))
+
+    def load(**kwargs: Any) -> pl.DataFrame:
+        if eager:
+            return pl.DataFrame(excel_reader.load_sheet_eager(0, **kwargs))
+        return excel_reader.load_sheet(0, **kwargs).to_polars()
+
+    # Numbers are read as floats by default, which can hold the identifier
+    df = load()
+    assert df.dtypes == [pl.Float64, pl.Float64]
+    assert df["id"].to_list() == [12345678901234567890.0, 42.0]
+
+    # The identifier cannot be an int: it must not be silently saturated
+    with pytest.raises(
+        fastexcel.CannotRetrieveCellDataError,
+        match=r"does not fit in a 64-bit integer(.|\n)*could not create int column \"id\"",
+    ):
+        load(dtypes={"id": "int"})
+
+    assert load(dtypes={"count": "int"})["count"].to_list() == [1, 2]
+
+
+def test_to_arrow_with_schema() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    sheet = excel_reader.load_sheet(0)
+    # Guessed from the data
+    assert sheet.schema_hint() == [("Month", "double"), ("Year", "double")]
+
+    schema = pa.schema([pa.field("Year", pa.int64())], metadata={"source": "registry"})
+    rb = sheet.to_arrow_with_schema(schema)
+    # Year is coerced to int, and Month is ignored
+    assert rb.schema == schema
+    assert rb.schema.metadata == {b"source": b"registry"}
+    assert rb.to_pydict() == {"Year": [2019, 2020]}
+
+
+def test_to_arrow_with_schema_errors() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    sheet = excel_reader.load_sheet(0)
+
+    with pytest.raises(fastexcel.ColumnNotFoundError, match='column with name "Day" not found'):
+        sheet.to_arrow_with_schema(pa.schema([("Year", pa.int64()), ("Day", pa.int64())]))
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="unsupported arrow type: Int32"):
+        sheet.to_arrow_with_schema(pa.schema([("Year", pa.int32())]))
+
+
+def test_null_column_in_sample_warns(mocker) -> None:
+    import logging
+
+    logger_instance_mock = mocker.patch("logging.getLogger", autospec=True).return_value
+
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-sparse-column.xlsx"))
+    sheet = excel_reader.load_sheet(0, schema_sample_rows=2)
+
+    # The only value of the column is not sampled, so its dtype falls back to string
+    assert sheet.available_columns[1].dtype == "string"
+    logger_instance_mock.makeRecord.assert_any_call(
+        "fastexcel.types.python.excelsheet.column_info",
+        logging.WARNING,
+        mocker.ANY,
+        mocker.ANY,
+        mocker.ANY,
+        mocker.ANY,
+        mocker.ANY,
+    )
+
+
+def test_expand_sample_on_null_columns() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-sparse-column.xlsx"))
+    expected = pl.DataFrame(
+        {"id": [1.0, 2.0, 3.0, 4.0, 5.0], "comment": [None, None, None, "late", None]}
+    )
+
+    sheet = excel_reader.load_sheet(0, schema_sample_rows=2, expand_sample_on_null_columns=True)
+    assert [col.dtype for col in sheet.available_columns] == ["float", "string"]
+    assert sheet.available_columns[1].dtype_from == "guessed"
+    pl_assert_frame_equal(sheet.to_polars(), expected)
+
+    rb = excel_reader.load_sheet_eager(0, schema_sample_rows=2, expand_sample_on_null_columns=True)
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+    # Columns with no value at all keep the dtype guessed from the sample
+    excel_reader = fastexcel.read_excel(path_for_fixture("null-column.xlsx"))
+    sheet = excel_reader.load_sheet(0, schema_sample_rows=1, expand_sample_on_null_columns=True)
+    columns = {col.name: col for col in sheet.available_columns}
+    assert columns["nullonly"].dtype == "string"
+    assert columns["nullonly"].dtype_from == "guessed"
+
+
+def test_expand_sample_on_null_columns_with_skip_empty_columns() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-sparse-column.xlsx"))
+
+    # The column is only null within the sample, so it is not dropped once its sample is expanded
+    sheet = excel_reader.load_sheet(
+        0, schema_sample_rows=2, expand_sample_on_null_columns=True, skip_empty_columns=True
+    )
+    assert [col.name for col in sheet.selected_columns] == ["id", "comment"]
+    assert sheet.to_polars()["comment"].to_list() == [None, None, None, "late", None]
+
+    # Without expanding the sample, it cannot be told apart from an empty column
+    sheet = excel_reader.load_sheet(0, schema_sample_rows=2, skip_empty_columns=True)
+    assert [col.name for col in sheet.selected_columns] == ["id"]
+
+    # Columns with no value at all are dropped either way
+    excel_reader = fastexcel.read_excel(path_for_fixture("null-column.xlsx"))
+    sheet = excel_reader.load_sheet(
+        0, schema_sample_rows=1, expand_sample_on_null_columns=True, skip_empty_columns=True
+    )
+    assert "nullonly" not in [col.name for col in sheet.selected_columns]
+
+
+def test_cell_errors_raise_by_default() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-cell-errors.xlsx"))
+    # #N/A and #VALUE! are null, but #NAME? and #DIV/0! are not
+    with pytest.raises(fastexcel.CalamineCellError):
+        excel_reader.load_sheet("Data")
+
+    with pytest.raises(fastexcel.CalamineCellError):
+        excel_reader.load_sheet_eager("Data")
+
+
+def test_error_as_null() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-cell-errors.xlsx"))
+    expected = pl.DataFrame(
+        {
+            "name": ["apple", None, "pear", "plum"],
+            "amount": [1.0, 2.0, None, 4.0],
+            "ratio": [0.5, None, 0.25, None],
+        }
+    )
+
+    sheet = excel_reader.load_sheet("Data", error_as_null=True)
+    assert [col.dtype for col in sheet.selected_columns] == ["string", "float", "float"]
+    pl_assert_frame_equal(sheet.to_polars(), expected)
+
+    rb = excel_reader.load_sheet_eager("Data", error_as_null=True)
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+
+def test_unselected_columns_are_not_inspected() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-cell-errors.xlsx"))
+    # Only "amount" is inspected, its other columns contain errors which cannot be read as null
+    sheet = excel_reader.load_sheet("Data", use_columns=["amount"])
+    assert [col.dtype for col in sheet.selected_columns] == ["float"]
+    pl_assert_frame_equal(sheet.to_polars(), pl.DataFrame({"amount": [1.0, 2.0, None, 4.0]}))
+
+    # The dtypes of the available columns are only determined when they are requested
+    with pytest.raises(fastexcel.CalamineCellError):
+        sheet.available_columns
+
+    rb = excel_reader.load_sheet_eager("Data", use_columns=[1])
+    assert rb.column("amount").to_pylist() == [1.0, 2.0, None, 4.0]
+
+    # A callable needs the dtype of every available column
+    with pytest.raises(fastexcel.CalamineCellError):
+        excel_reader.load_sheet("Data", use_columns=lambda col: col.name == "amount")
+
+
+@pytest.mark.parametrize("sheet_name, error", [("Spill", "#SPILL!"), ("Calc", "#CALC!")])
+@pytest.mark.parametrize("error_as_null", [False, True])
+def test_unsupported_cell_errors(sheet_name: str, error: str, error_as_null: bool) -> None:
+    # calamine rejects these errors when parsing the sheet, before any dtype is determined
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-spill-and-calc-errors.xlsx"))
+    with pytest.raises(fastexcel.CalamineError, match=f"Unsupported cell error value '{error}'"):
+        excel_reader.load_sheet(sheet_name, error_as_null=error_as_null)
+
+
+def test_string_booleans_are_strings_by_default() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-string-booleans.xlsx"))
+    sheet = excel_reader.load_sheet("Data")
+    assert [col.dtype for col in sheet.selected_columns] == ["string"] * 4
+    assert sheet.to_polars()["active"].to_list() == ["Yes", "No", None, "yes"]
+
+
+def test_parse_string_booleans() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-string-booleans.xlsx"))
+    expected = pl.DataFrame(
+        {
+            "name": ["apple", "pear", "plum", "kiwi"],
+            "active": [True, False, None, True],
+            "verified": [True, False, True, False],
+            # "maybe" is not a boolean, so the column remains a string column
+            "answer": ["yes", "no", "maybe", "No"],
+        }
+    )
+
+    sheet = excel_reader.load_sheet("Data", parse_string_booleans=True)
+    dtypes = [col.dtype for col in sheet.selected_columns]
+    assert dtypes == ["string", "boolean", "boolean", "string"]
+    pl_assert_frame_equal(sheet.to_polars(), expected)
+
+    rb = excel_reader.load_sheet_eager("Data", parse_string_booleans=True)
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+
+def test_parse_string_booleans_with_provided_dtype() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-string-booleans.xlsx"))
+    sheet = excel_reader.load_sheet(
+        "Data", dtypes={"answer": "boolean"}, parse_string_booleans=True
+    )
+    # Strings which are not booleans are read as null
+    assert sheet.to_polars()["answer"].to_list() == [True, False, None, False]
+
+
+def test_strings_are_not_trimmed_by_default() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-padded-strings.xlsx"))
+    df = excel_reader.load_sheet("Data").to_polars()
+    assert df["name"].to_list() == ["  apple", "pear ", " plum "]
+    assert df["city"].to_list() == ["Paris  ", "   ", " NA "]
+
+
+def test_trim_strings() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-padded-strings.xlsx"))
+    expected = pl.DataFrame(
+        {
+            "name": ["apple", "pear", "plum"],
+            # Strings which are empty once trimmed are read as null
+            "city": ["Paris", None, "NA"],
+            "code": [1.0, 2.0, 3.0],
+        }
+    )
+
+    df = excel_reader.load_sheet("Data", trim_strings=True).to_polars()
+    pl_assert_frame_equal(df, expected)
+
+    rb = excel_reader.load_sheet_eager("Data", trim_strings=True)
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+
+def test_trim_strings_with_null_values() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-padded-strings.xlsx"))
+    df = excel_reader.load_sheet("Data", trim_strings=True, null_values=["NA"]).to_polars()
+    # Trimmed strings are compared to the null values
+    assert df["city"].to_list() == ["Paris", None, None]
+
+
+def test_numeric_strings_are_strings_by_default() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-numeric-strings.xlsx"))
+    sheet = excel_reader.load_sheet("Data")
+    assert [col.dtype for col in sheet.selected_columns] == ["string"] * 4
+    assert sheet.to_polars()["reading"].to_list() == ["1.2E5", "3.5", "-2.5E-3"]
+
+
+def test_parse_numeric_strings() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-numeric-strings.xlsx"))
+    expected = pl.DataFrame(
+        {
+            "sample": ["a", "b", "c"],
+            "reading": [120000.0, 3.5, -0.0025],
+            "mixed": [1.5, 2.0, None],
+            # "abc" is not a number, so the column remains a string column
+            "code": ["1.2E5", "abc", "7"],
+        }
+    )
+
+    sheet = excel_reader.load_sheet("Data", parse_numeric_strings=True)
+    dtypes = [col.dtype for col in sheet.selected_columns]
+    assert dtypes == ["string", "float", "float", "string"]
+    pl_assert_frame_equal(sheet.to_polars(), expected)
+
+    rb = excel_reader.load_sheet_eager("Data", parse_numeric_strings=True)
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+
+def test_integers_are_ints_by_default() -> None:
+    # XLSB workbooks store integers as such, whereas XLSX ones only have floats
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-integers.xlsb"))
+    sheet = excel_reader.load_sheet("Data")
+    assert [col.dtype for col in sheet.selected_columns] == ["int", "int", "float", "string"]
+    assert [col.dtype_from for col in sheet.selected_columns] == ["guessed"] * 4
+
+
+def test_int_as_float() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-integers.xlsb"))
+    expected = pl.DataFrame(
+        {
+            "id": [1.0, 2.0, 3.0],
+            "quantity": [10.0, 20.0, None],
+            "price": [1.5, 2.25, 3.0],
+            "label": ["a", "b", "c"],
+        }
+    )
+
+    sheet = excel_reader.load_sheet("Data", int_as_float=True)
+    assert [col.dtype for col in sheet.selected_columns] == ["float", "float", "float", "string"]
+    assert [col.dtype_from for col in sheet.selected_columns] == [
+        "int_as_float",
+        "int_as_float",
+        "guessed",
+        "guessed",
+    ]
+    pl_assert_frame_equal(sheet.to_polars(), expected)
+
+    rb = excel_reader.load_sheet_eager("Data", int_as_float=True)
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+
+def test_int_as_float_keeps_provided_dtypes() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-integers.xlsb"))
+    sheet = excel_reader.load_sheet("Data", int_as_float=True, dtypes={"id": "int"})
+    assert [(col.dtype, col.dtype_from) for col in sheet.selected_columns] == [
+        ("int", "provided_by_name"),
+        ("float", "int_as_float"),
+        ("float", "guessed"),
+        ("string", "guessed"),
+    ]
+    assert sheet.to_polars()["id"].to_list() == [1, 2, 3]
+
+
+def test_leading_zeros_are_not_kept_by_default() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-zero-padded-numbers.xlsx"))
+    df = excel_reader.load_sheet("Orders").to_polars()
+    # Text cells are read as stored, numbers lose the leading zeros of their number format
+    assert df["zip_code"].to_list() == ["01234", "1234", "75001", "00501", "501"]
+
+
+def test_keep_leading_zeros() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-zero-padded-numbers.xlsx"))
+    expected = pl.DataFrame(
+        {
+            "zip_code": ["01234", "01234", "75001", "00501", "00501"],
+            "city": ["Amherst", "Amherst", "Paris", "Holtsville", "Holtsville"],
+            "amount": [1.5, 2.0, 3.0, 4.0, 5.0],
+        }
+    )
+
+    df = excel_reader.load_sheet("Orders", keep_leading_zeros=True).to_polars()
+    pl_assert_frame_equal(df, expected)
+
+    rb = excel_reader.load_sheet_eager("Orders", keep_leading_zeros=True)
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+
+def test_keep_leading_zeros_in_range() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-zero-padded-numbers.xlsx"))
+    sheet = excel_reader.load_range("Orders", "A3:B6", header_row=None, keep_leading_zeros=True)
+    assert sheet.to_polars()["__UNNAMED__0"].to_list() == ["01234", "75001", "00501", "00501"]
+
+
+def test_keep_leading_zeros_from_file_like() -> None:
+    # Number formats cannot be read from file-like objects, numbers are read as usual
+    with open(path_for_fixture("sheet-with-zero-padded-numbers.xlsx"), "rb") as f:
+        excel_reader = fastexcel.read_excel(f)
+        df = excel_reader.load_sheet("Orders", keep_leading_zeros=True).to_polars()
+    assert df["zip_code"].to_list() == ["01234", "1234", "75001", "00501", "501"]
@@ -1,16 +1,18 @@
 from __future__ import annotations
 
-from datetime import date, datetime, timedelta
+from datetime import date, datetime, time, timedelta
 
 import fastexcel
 import numpy as np
 import pandas as pd
 import polars as pl
+import pytest
 from pandas.testing import assert_frame_equal as pd_assert_frame_equal
 from polars.datatypes import DataType as PolarsDataType
 from polars.datatypes import Date as PlDate
 from polars.datatypes import Datetime as PlDateTime
 from polars.datatypes import Duration as PlDuration
+from polars.datatypes import Time as PlTime
 from polars.datatypes import Utf8 as PlUtf8
 from polars.testing import assert_frame_equal as pl_assert_frame_equal
 
@@ -81,3 +83,63 @@ def test_sheet_with_offset_header_row_and_durations() -> None:
         timedelta(hours=1, minutes=18, seconds=43),
         timedelta(hours=7, minutes=16, seconds=51),
     ]
+
+
+def test_sheet_with_times() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-times.xlsx"))
+    sheet = excel_reader.load_sheet(0)
+
+    assert [col.dtype for col in sheet.selected_columns] == ["string", "time", "datetime"]
+
+    expected_times = [time(13, 45), time(8, 30, 15), time(23, 59, 59)]
+    pl_df = sheet.to_polars()
+    assert pl_df["time"].dtype == PlTime()
+    assert pl_df["time"].to_list() == expected_times
+    assert pl_df["datetime"].to_list() == [
+        datetime(2023, 3, 15, 13, 45),
+        datetime(2023, 3, 16, 12),
+        datetime(2023, 3, 17),
+    ]
+
+    pd_df = sheet.to_pandas()
+    assert pd_df["time"].to_list() == expected_times
+
+
+def test_sheet_with_times_specify_dtypes() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-times.xlsx"))
+    sheet = excel_reader.load_sheet(0, dtypes={"time": "string", "datetime": "time"})
+
+    pl_df = sheet.to_polars()
+    assert pl_df["time"].to_list() == ["13:45:00", "08:30:15", "23:59:59"]
+    assert pl_df["datetime"].dtype == PlTime()
+    assert pl_df["datetime"].to_list() == [time(13, 45), time(12), time(0)]
+
+
+def test_sheet_with_1904_dates() -> None:
+    # Workbooks using the 1904 date system store serials which are 1462 days smaller than in the
+    # 1900 date system: they must be read as the same dates
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-1904-dates.xlsx"))
+    sheet = excel_reader.load_sheet(0)
+
+    assert [col.dtype for col in sheet.selected_columns] == ["datetime", "datetime", "time"]
+    pl_df = sheet.to_polars()
+    assert pl_df["datetime"].to_list() == [datetime(2023, 7, 21, 12), datetime(2023, 7, 22, 6)]
+    assert pl_df["date"].to_list() == [datetime(2023, 7, 21), datetime(2023, 7, 22)]
+    assert pl_df["time"].to_list() == [time(13, 45), time(8, 30, 1)]
+
+    rb = excel_reader.load_sheet_eager(0, dtypes={"date": "date", "datetime": "string"})
+    assert rb["date"].to_pylist() == [date(2023, 7, 21), date(2023, 7, 22)]
+    assert rb["datetime"].to_pylist() == ["2023-07-21 12:00:00", "2023-07-22 06:00:00"]
+
+
+@pytest.mark.parametrize("dtype_coercion", ["coerce", "strict"])
+def test_sheet_with_times_and_datetimes(dtype_coercion: fastexcel.DTypeCoercion) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-times-and-datetimes.xlsx"))
+    sheet = excel_reader.load_sheet(0, dtype_coercion=dtype_coercion)
+
+    # Times of day are datetimes without a date, so the column holds datetimes
+    assert [col.dtype for col in sheet.selected_columns] == ["datetime"]
+    pl_df = sheet.to_polars()
+    assert pl_df["when"].dtype == PlDateTime(time_unit="ms", time_zone=None)
+    assert pl_df["when"].dt.time().to_list() == [time(6), time(13, 45), time(0)]
+    assert pl_df["when"].dt.date().to_list()[::2] == [date(2023, 3, 15), date(2023, 3, 17)]
@@ -0,0 +1,24 @@
+from __future__ import annotations
+
+import fastexcel
+import polars as pl
+from polars.testing import assert_frame_equal as pl_assert_frame_equal
+
+from utils import path_for_fixture
+
+
+def test_spilled_array_values_are_read_as_data() -> None:
+    # A2 holds =SEQUENCE(5), which spills into A2:A6. The spilled values are stored in the
+    # cells they spill into, so they are read like any other value
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-spilled-array.xlsx"))
+    expected = pl.DataFrame({"n": [1.0, 2.0, 3.0, 4.0, 5.0], "label": ["a", "b", "c", "d", "e"]})
+
+    sheet = excel_reader.load_sheet("Data")
+    assert sheet.height == 5
+    pl_assert_frame_equal(sheet.to_polars(), expected)
+
+    rb = excel_reader.load_sheet_eager("Data")
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+    # Only the anchor of the spilled range holds the formula
+    assert excel_reader.sheet_formulas("Data") == [(1, 0, "_xlfn.SEQUENCE(5)")]
@@ -1,7 +1,9 @@
 from datetime import date, datetime, timedelta
+from typing import Any
 
 import fastexcel
 import polars as pl
+import pytest
 from pandas.testing import assert_frame_equal as pd_assert_frame_equal
 from polars.testing import assert_frame_equal as pl_assert_frame_equal
 from pyarrow import RecordBatch
@@ -35,6 +37,27 @@ def test_multiple_sheets_with_unnamed_columns():
     pl_assert_frame_equal(eager_polars, lazy_polars)
 
 
+@pytest.mark.parametrize(
+    "fixture, kwargs",
+    [
+        ("fixture-single-sheet-with-types.xlsx", {}),
+        ("fixture-multi-dtypes-columns.xlsx", {}),
+        ("sheet-with-cell-errors.xlsx", {"error_as_null": True}),
+        ("sheet-with-na.xlsx", {"skip_rows": 1, "n_rows": 2}),
+        ("sheet-with-numbers-and-strings.xlsx", {"dtypes": "string"}),
+        ("sheet-with-tables.xlsx", {"header_row": None}),
+    ],
+)
+def test_eager_and_lazy_sheets_are_identical(fixture: str, kwargs: dict[str, Any]) -> None:
+    """Eager XLSX loads read cells by reference rather than copying them, which must not change
+    the loaded data"""
+    excel_reader = fastexcel.read_excel(path_for_fixture(fixture))
+
+    eager = excel_reader.load_sheet_eager(0, **kwargs)
+    lazy = excel_reader.load_sheet(0, **kwargs).to_arrow()
+    assert eager.equals(lazy)
+
+
 def test_eager_with_an_ods_file_should_return_a_recordbatch() -> None:
     ods_reader = fastexcel.read_excel(path_for_fixture("dates.ods"))
 
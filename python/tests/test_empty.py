@@ -11,3 +11,28 @@ def test_empty(path: str) -> None:
 
     assert sheet.to_pandas().empty
     assert sheet.to_polars().is_empty()
+
+
+@pytest.mark.parametrize("path", ("empty.ods", "empty.xlsx"))
+def test_empty_sheet_is_empty(path: str) -> None:
+    sheet = fastexcel.read_excel(path_for_fixture(path)).load_sheet(0)
+
+    assert sheet.is_empty()
+    assert sheet.has_only_null_columns()
+
+
+def test_sheet_with_only_null_columns() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("null-column.xlsx"))
+
+    sheet = excel_reader.load_sheet(0)
+    assert not sheet.is_empty()
+    assert not sheet.has_only_null_columns()
+
+    sheet = excel_reader.load_sheet(0, use_columns=["nullonly"])
+    assert not sheet.is_empty()
+    assert sheet.has_only_null_columns()
+
+    # All data rows are skipped
+    sheet = excel_reader.load_sheet(0, skip_rows=20)
+    assert sheet.height == 0
+    assert sheet.is_empty()
@@ -7,7 +7,7 @@ from utils import path_for_fixture
 
 
 def test_read_excel_bad_type() -> None:
-    expected_message = "source must be a string or bytes"
+    expected_message = "source must be a string, bytes or a binary file-like object"
     with pytest.raises(fastexcel.InvalidParametersError, match=expected_message):
         fastexcel.read_excel(42)  # type: ignore[arg-type]
 
@@ -56,6 +56,35 @@ Context:
     assert exc_info.value.__doc__ == "Sheet was not found"
 
 
+def test_sheet_name_ambiguous_case_insensitive_error() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-names-differing-by-case.xlsx"))
+    expected_message = (
+        'sheet name "Data" is ambiguous, several sheets match it case-insensitively: '
+        '"data", "DATA"'
+    )
+
+    with pytest.raises(fastexcel.InvalidParametersError, match=expected_message):
+        excel_reader.load_sheet("Data", case_insensitive_sheet_name=True)
+
+    with pytest.raises(fastexcel.SheetNotFoundError):
+        excel_reader.load_sheet("Data")
+
+
+def test_encrypted_workbook_error() -> None:
+    path = path_for_fixture("encrypted-workbook.xlsx")
+    expected_message = "workbook is encrypted: encrypted workbooks are not supported"
+
+    with pytest.raises(fastexcel.EncryptedWorkbookError, match=expected_message) as exc_info:
+        fastexcel.read_excel(path)
+
+    assert exc_info.value.__doc__ == "Workbook is encrypted"
+
+    with open(path, "rb") as f:
+        content = f.read()
+    with pytest.raises(fastexcel.EncryptedWorkbookError, match=expected_message):
+        fastexcel.read_excel(content)
+
+
 @pytest.mark.parametrize(
     "exc_class, expected_docstring",
     [
@@ -70,6 +99,7 @@ Context:
             "calamine returned an error regarding the content of the cell",
         ),
         (fastexcel.CalamineError, "Generic calamine error"),
+        (fastexcel.EncryptedWorkbookError, "Workbook is encrypted"),
         (fastexcel.ColumnNotFoundError, "Column was not found"),
         (fastexcel.SheetNotFoundError, "Sheet was not found"),
         (fastexcel.ArrowError, "Generic arrow error"),
@@ -78,19 +108,3 @@ Context:
 )
 def test_docstrings(exc_class: type[Exception], expected_docstring: str) -> None:
     assert exc_class.__doc__ == expected_docstring
-
-
-def test_schema_sample_rows_must_be_nonzero() -> None:
-    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
-
-    with pytest.raises(
-        fastexcel.InvalidParametersError,
-        match="schema_sample_rows cannot be 0, as it would prevent dtype inferring",
-    ):
-        excel_reader.load_sheet(0, schema_sample_rows=0)
-
-    with pytest.raises(
-        fastexcel.InvalidParametersError,
-        match="schema_sample_rows cannot be 0, as it would prevent dtype inferring",
-    ):
-        excel_reader.load_table("my-table", schema_sample_rows=0)
@@ -1,11 +1,16 @@
 from __future__ import annotations
 
+import io
+import zipfile
 from datetime import datetime
+from pathlib import Path
 from typing import Any
 
 import fastexcel
 import pandas as pd
 import polars as pl
+import pyarrow as pa
+import pyarrow.parquet as pq
 import pytest
 from pandas.testing import assert_frame_equal as pd_assert_frame_equal
 from polars.testing import assert_frame_equal as pl_assert_frame_equal
@@ -58,6 +63,119 @@ def test_single_sheet_bytes():
     pl_assert_frame_equal(sheet_by_idx.to_polars(), pl_expected)
 
 
+def test_single_sheet_file_like() -> None:
+    expected = pl.DataFrame({"Month": [1.0, 2.0], "Year": [2019.0, 2020.0]})
+
+    with open(path_for_fixture("fixture-single-sheet.xlsx"), "rb") as f:
+        excel_reader = fastexcel.read_excel(f)
+        assert excel_reader.sheet_names == ["January"]
+        pl_assert_frame_equal(excel_reader.load_sheet(0).to_polars(), expected)
+        pl_assert_frame_equal(
+            pl.from_arrow(excel_reader.load_sheet_eager(0)),  # type:ignore[arg-type]
+            expected,
+        )
+
+    with open(path_for_fixture("fixture-single-sheet.xlsx"), "rb") as f:
+        buffer = io.BytesIO(f.read())
+    excel_reader = fastexcel.read_excel(buffer, format="xlsx")
+    pl_assert_frame_equal(excel_reader.load_sheet("January").to_polars(), expected)
+
+
+def test_single_sheet_from_zip_entry(tmp_path: Path) -> None:
+    expected = pl.DataFrame({"Month": [1.0, 2.0], "Year": [2019.0, 2020.0]})
+
+    # A workbook stored in an archive, itself stored in an archive
+    inner_buffer = io.BytesIO()
+    with zipfile.ZipFile(inner_buffer, "w") as inner_archive:
+        inner_archive.write(path_for_fixture("fixture-single-sheet.xlsx"), "reports/book.xlsx")
+    archive_path = tmp_path / "archive.zip"
+    with zipfile.ZipFile(archive_path, "w", compression=zipfile.ZIP_DEFLATED) as archive:
+        archive.writestr("inner.zip", inner_buffer.getvalue())
+
+    with zipfile.ZipFile(archive_path) as archive, archive.open("inner.zip") as inner_entry:
+        with zipfile.ZipFile(inner_entry) as inner_archive:
+            # Entries are read on demand, without being extracted first
+            with inner_archive.open("reports/book.xlsx") as entry:
+                excel_reader = fastexcel.read_excel(entry)
+                assert excel_reader.sheet_names == ["January"]
+                pl_assert_frame_equal(excel_reader.load_sheet(0).to_polars(), expected)
+
+
+def test_file_like_text_mode() -> None:
+    with pytest.raises(fastexcel.CalamineError, match="opened in binary mode"):
+        fastexcel.read_excel(io.StringIO("not bytes"), format="xlsx")  # type:ignore[arg-type]
+
+
+def test_single_sheet_with_forced_format(tmp_path) -> None:
+    # An xlsx file with a wrong extension cannot be opened with format auto-detection
+    wrong_extension_path = tmp_path / "fixture-single-sheet.xls"
+    with open(path_for_fixture("fixture-single-sheet.xlsx"), "rb") as f:
+        wrong_extension_path.write_bytes(f.read())
+
+    with pytest.raises(fastexcel.CalamineError):
+        fastexcel.read_excel(wrong_extension_path)
+
+    for source in (wrong_extension_path, wrong_extension_path.read_bytes()):
+        excel_reader = fastexcel.read_excel(source, format="xlsx")
+        assert excel_reader.sheet_names == ["January"]
+        pl_assert_frame_equal(
+            excel_reader.load_sheet(0).to_polars(),
+            pl.DataFrame({"Month": [1.0, 2.0], "Year": [2019.0, 2020.0]}),
+        )
+
+    # The chosen reader rejects the file
+    with pytest.raises(fastexcel.CalamineError, match="as ods"):
+        fastexcel.read_excel(wrong_extension_path, format="ods")
+
+    with pytest.raises(fastexcel.InvalidParametersError, match='unsupported workbook format: "csv"'):
+        fastexcel.read_excel(wrong_extension_path, format="csv")  # type:ignore[arg-type]
+
+
+def test_load_sheet_case_insensitive_sheet_name() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+
+    with pytest.raises(fastexcel.SheetNotFoundError):
+        excel_reader.load_sheet("FEBRUARY")
+
+    sheet = excel_reader.load_sheet("FEBRUARY", case_insensitive_sheet_name=True)
+    assert sheet.name == "February"
+    assert excel_reader.load_sheet_by_name("february", case_insensitive_sheet_name=True).name == (
+        "February"
+    )
+    rb = excel_reader.load_sheet_eager("february", case_insensitive_sheet_name=True)
+    assert rb.schema.names == ["Month", "Year"]
+
+    # Exact matches are preferred over case-insensitive ones
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-names-differing-by-case.xlsx"))
+    assert excel_reader.load_sheet("DATA", case_insensitive_sheet_name=True).name == "DATA"
+    assert excel_reader.load_sheet("data", case_insensitive_sheet_name=True).name == "data"
+    assert excel_reader.load_sheet("other", case_insensitive_sheet_name=True).name == "Other"
+
+
+def test_load_sheet_by_code_name() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-code-names.xlsx"))
+    expected = {"name": ["apple", "pear"], "amount": [1.0, 2.0]}
+
+    # "Renamed data" was renamed, but is still referred to as "DataSheet" by VBA
+    sheet = excel_reader.load_sheet("DataSheet")
+    assert sheet.name == "Renamed data"
+    assert sheet.to_polars().to_dict(as_series=False) == expected
+    rb = excel_reader.load_sheet_eager("DataSheet")
+    assert rb.to_pydict() == expected
+    assert excel_reader.load_sheet("Sheet1").name == "Summary"
+    assert excel_reader.has_sheet("DataSheet")
+
+    # Code names are only matched exactly
+    with pytest.raises(fastexcel.SheetNotFoundError):
+        excel_reader.load_sheet("datasheet")
+
+    # They cannot be read from file-like objects
+    with open(path_for_fixture("sheet-with-code-names.xlsx"), "rb") as f:
+        excel_reader = fastexcel.read_excel(f)
+        assert excel_reader.load_sheet("Renamed data").name == "Renamed data"
+        assert not excel_reader.has_sheet("DataSheet")
+
+
 def test_single_sheet_with_types():
     excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet-with-types.xlsx"))
     assert excel_reader.sheet_names == ["Sheet1"]
@@ -189,6 +307,79 @@ def test_sheets_with_no_header():
     pl_assert_frame_equal(sheet_by_idx.to_polars(), pl_expected)
 
 
+def test_reader_default_sheet_header_row():
+    excel_reader = fastexcel.read_excel(
+        path_for_fixture("fixture-changing-header-location.xlsx"), sheet_header_row=None
+    )
+    unnamed_columns = ["__UNNAMED__0", "__UNNAMED__1", "__UNNAMED__2"]
+
+    # The reader's default applies to whole sheets when header_row is omitted
+    assert excel_reader.load_sheet("Sheet2").to_polars().columns == unnamed_columns
+    assert excel_reader.load_sheet_eager("Sheet2").schema.names == unnamed_columns
+    assert excel_reader.load_sheet_by_name("Sheet2").height == 2
+    assert excel_reader.load_sheet_by_idx(1).height == 2
+    assert all(
+        sheet.header_row is None for sheet in excel_reader.load_sheets(["Sheet1", "Sheet2"])
+    )
+    assert excel_reader.load_sheets_union(["Sheet2"]).num_rows == 2
+    assert excel_reader.available_column_names("Sheet2") == unnamed_columns
+
+    # Explicitly passed values take precedence
+    sheet = excel_reader.load_sheet("Sheet1", header_row=0)
+    assert sheet.header_row == 0
+    assert sheet.to_polars().columns == ["Month", "Year"]
+    assert excel_reader.load_sheet_eager("Sheet2", header_row=0).num_rows == 1
+    assert excel_reader.load_sheet_by_name("Sheet2", header_row=0).height == 1
+    assert excel_reader.load_sheet_by_idx(1, header_row=0).height == 1
+    assert all(
+        sheet.header_row == 0
+        for sheet in excel_reader.load_sheets(["Sheet1", "Sheet2"], header_row=0)
+    )
+    assert excel_reader.load_sheets_union(["Sheet2"], header_row=0).num_rows == 1
+    assert excel_reader.available_column_names("Sheet2", header_row=0) != unnamed_columns
+
+    # Regions have header rows of their own, relative to the region
+    assert excel_reader.load_range("Sheet2", "A1:C2").height == 1
+    excel_reader = fastexcel.read_excel(
+        path_for_fixture("sheet-with-defined-names.xlsx"), sheet_header_row=None
+    )
+    assert excel_reader.load_defined_name("prices").to_polars().columns == ["product", "price"]
+    excel_reader = fastexcel.read_excel(
+        path_for_fixture("sheet-with-tables.xlsx"), sheet_header_row=None
+    )
+    assert excel_reader.load_table("users").available_columns[0].name == "User Id"
+
+    # The default header row is 0
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-changing-header-location.xlsx"))
+    assert excel_reader.load_sheet("Sheet1").to_polars().columns == ["Month", "Year"]
+
+
+@pytest.mark.parametrize("range_cache_size", [0, 1, 4])
+def test_load_sheet_again_with_other_options(range_cache_size: int):
+    path = path_for_fixture("fixture-changing-header-location.xlsx")
+    excel_reader = fastexcel.read_excel(path, range_cache_size=range_cache_size)
+    options_list: list[dict[str, Any]] = [
+        {},
+        {"n_rows": 1},
+        {"header_row": None},
+        {"header_row": None, "skip_rows": 2},
+        {"header_row": 3},
+        {},
+    ]
+
+    # Loading sheets from the same reader gives the same result as loading them from a new one
+    for sheet_name in ["Sheet1", "Sheet3", "Sheet1"]:
+        for options in options_list:
+            expected = fastexcel.read_excel(path).load_sheet(sheet_name, **options).to_polars()
+            pl_assert_frame_equal(
+                excel_reader.load_sheet(sheet_name, **options).to_polars(), expected
+            )
+
+    excel_reader.close()
+    with pytest.raises(fastexcel.InvalidParametersError, match="reader is closed"):
+        excel_reader.load_sheet("Sheet1")
+
+
 def test_sheets_with_empty_rows_before_header():
     excel_reader = fastexcel.read_excel(path_for_fixture("fixture-changing-header-location.xlsx"))
     assert excel_reader.sheet_names == ["Sheet1", "Sheet2", "Sheet3"]
@@ -261,6 +452,57 @@ def test_sheets_with_skipping_headers():
     pl_assert_frame_equal(sheet_by_idx.to_polars(), pl_expected)
 
 
+@pytest.mark.parametrize(
+    "header_row, skip_rows, n_rows, expected_skipped, expected_height, expected_total_height",
+    [
+        (0, None, None, 0, 3, 3),
+        (0, 1, None, 1, 2, 3),
+        (0, 3, None, 3, 0, 3),
+        # n_rows cuts the data short
+        (0, 1, 1, 1, 1, 3),
+        # Without a header row, the header is part of the data
+        (None, 2, None, 2, 2, 4),
+        (None, None, None, 0, 4, 4),
+    ],
+)
+def test_skipped_row_count(
+    header_row: int | None,
+    skip_rows: int | None,
+    n_rows: int | None,
+    expected_skipped: int,
+    expected_height: int,
+    expected_total_height: int,
+) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet-with-types.xlsx"))
+    sheet = excel_reader.load_sheet(0, header_row=header_row, skip_rows=skip_rows, n_rows=n_rows)
+    assert sheet.skipped_row_count == expected_skipped
+    assert sheet.height == expected_height
+    assert sheet.total_height == expected_total_height
+    if n_rows is None:
+        assert sheet.total_height - sheet.skipped_row_count == sheet.height
+
+
+def test_raw_cell_string() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+
+    sheet = excel_reader.load_sheet(0)
+    assert sheet.raw_cell_string(0, 0) == "1"
+    assert sheet.raw_cell_string(1, 1) == "2020"
+    # Out of the data
+    assert sheet.raw_cell_string(2, 0) is None
+    assert sheet.raw_cell_string(0, 2) is None
+
+    # Rows are relative to the first data row
+    assert excel_reader.load_sheet(0, skip_rows=1).raw_cell_string(0, 1) == "2020"
+    assert excel_reader.load_sheet(0, n_rows=1).raw_cell_string(1, 1) is None
+    assert excel_reader.load_sheet(0, header_row=None).raw_cell_string(0, 0) == "Month"
+
+    # Values are read before any dtype is applied
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-numeric-strings.xlsx"))
+    sheet = excel_reader.load_sheet("Data", parse_numeric_strings=True)
+    assert sheet.raw_cell_string(0, 1) == "1.2E5"
+
+
 def test_sheet_with_pagination():
     excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet-with-types.xlsx"))
     assert excel_reader.sheet_names == ["Sheet1"]
@@ -515,6 +757,26 @@ def test_null_strings(excel_file: str, expected_data_sheet_null_strings: dict[st
     pl_assert_frame_equal(sheet.to_polars(), pl_df)
 
 
+@pytest.mark.parametrize(
+    "null_values, expected",
+    [
+        # The default null strings are only ignored when inferring dtypes
+        (None, {"country": ["FR", "NA", "DE", "None"], "amount": [1.0, None, 3.0, 4.0]}),
+        ([], {"country": ["FR", "NA", "DE", "None"], "amount": ["1", "NA", "3", "4"]}),
+        (["NA"], {"country": ["FR", None, "DE", "None"], "amount": [1.0, None, 3.0, 4.0]}),
+    ],
+)
+def test_null_values(null_values: list[str] | None, expected: dict[str, list[Any]]) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-null-like-strings.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, null_values=null_values)
+    pl_assert_frame_equal(sheet.to_polars(), pl.DataFrame(expected))
+    pd_assert_frame_equal(sheet.to_pandas(), pd.DataFrame(expected))
+
+    rb = excel_reader.load_sheet_eager(0, null_values=null_values)
+    pl_assert_frame_equal(pl.from_arrow(rb), pl.DataFrame(expected))  # type:ignore[arg-type]
+
+
 def test_null_values_in_cells() -> None:
     excel_reader = fastexcel.read_excel(path_for_fixture("fixture-invalid-cell-value.xlsx"))
     sheet = excel_reader.load_sheet(0)
@@ -588,3 +850,502 @@ def test_header_row_and_skip_rows(
         .to_polars(),
         pl.DataFrame(expected),
     )
+
+
+def test_to_arrow_table() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    sheet = excel_reader.load_sheet(0)
+
+    table = sheet.to_arrow_table()
+    assert isinstance(table, pa.Table)
+    assert table.to_batches() == [sheet.to_arrow()]
+
+    users = fastexcel.read_excel(path_for_fixture("sheet-with-tables.xlsx")).load_table("users")
+    assert users.to_arrow_table().equals(pa.Table.from_batches([users.to_arrow()]))
+
+
+def test_column_by_name_and_index() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-quarterly-columns.xlsx"))
+    sheet = excel_reader.load_sheet(0, use_columns=["region", "Q2_sales"])
+
+    assert sheet.column_by_name("Q2_sales") == sheet.to_arrow().column("Q2_sales")
+    assert sheet.column_by_index(0).to_pylist() == ["north", "south"]
+    assert sheet.column_by_index(2).to_pylist() == [12.0, 7.0]
+
+    # Rows are the same as in the whole sheet
+    sheet = excel_reader.load_sheet(0, skip_rows=1, dtypes={"Q1_sales": "int"})
+    assert sheet.column_by_name("Q1_sales") == pa.array([8], type=pa.int64())
+
+    # Only selected columns can be loaded
+    sheet = excel_reader.load_sheet(0, use_columns=["region"])
+    with pytest.raises(fastexcel.ColumnNotFoundError, match='column with name "Q1_sales"'):
+        sheet.column_by_name("Q1_sales")
+    with pytest.raises(fastexcel.ColumnNotFoundError, match="column at index 1"):
+        sheet.column_by_index(1)
+
+
+@pytest.mark.parametrize("batch_size", [1, 2, 10_000])
+def test_stream_to_arrow_ipc(tmp_path: Path, batch_size: int) -> None:
+    sheet = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx")).load_sheet(0)
+    output_path = tmp_path / "january.arrow"
+
+    sheet.stream_to(output_path, format="arrow_ipc", batch_size=batch_size)
+
+    with pa.ipc.open_stream(output_path) as reader:
+        batches = list(reader)
+    assert len(batches) == -(-2 // batch_size)
+    assert pa.Table.from_batches(batches).equals(pa.Table.from_batches([sheet.to_arrow()]))
+
+
+def test_stream_to_csv(tmp_path: Path) -> None:
+    sheet = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx")).load_sheet(0)
+    output_path = tmp_path / "january.csv"
+
+    sheet.stream_to(output_path, format="csv", batch_size=1)
+
+    pl_assert_frame_equal(pl.read_csv(output_path), sheet.to_polars())
+
+
+@pytest.mark.parametrize("batch_size", [1, 10_000])
+def test_write_parquet(tmp_path: Path, batch_size: int) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet-with-types.xlsx"))
+    sheet = excel_reader.load_sheet(0)
+    output_path = tmp_path / "sheet.parquet"
+
+    sheet.write_parquet(output_path, batch_size=batch_size)
+
+    # Dtypes are kept as is
+    assert pq.read_table(output_path).equals(pa.Table.from_batches([sheet.to_arrow()]))
+    pl_assert_frame_equal(pl.read_parquet(output_path), sheet.to_polars())
+
+    # The column selection and the provided dtypes are respected
+    sheet = excel_reader.load_sheet(0, use_columns=[1, 0], dtypes={0: "string"}, n_rows=2)
+    sheet.write_parquet(output_path)
+    pl_assert_frame_equal(pl.read_parquet(output_path), sheet.to_polars())
+
+
+def test_write_csv(tmp_path: Path) -> None:
+    sheet = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx")).load_sheet(0)
+    output_path = tmp_path / "january.csv"
+
+    sheet.write_csv(output_path)
+
+    pl_assert_frame_equal(pl.read_csv(output_path), sheet.to_polars())
+
+
+def test_to_csv_string() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx"))
+    sheet = excel_reader.load_sheet(0)
+
+    csv = sheet.to_csv_string()
+    pl_assert_frame_equal(pl.read_csv(io.StringIO(csv)), sheet.to_polars())
+
+    # The pagination and the column selection are respected
+    sheet = excel_reader.load_sheet(0, skip_rows=1, use_columns=[1])
+    csv = sheet.to_csv_string()
+    assert len(csv.splitlines()) == sheet.height + 1
+    pl_assert_frame_equal(pl.read_csv(io.StringIO(csv)), sheet.to_polars())
+
+
+def test_stream_to_invalid_parameters(tmp_path: Path) -> None:
+    sheet = fastexcel.read_excel(path_for_fixture("fixture-single-sheet.xlsx")).load_sheet(0)
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="batch_size cannot be 0"):
+        sheet.stream_to(tmp_path / "out.csv", format="csv", batch_size=0)
+
+    with pytest.raises(fastexcel.InvalidParametersError, match='unsupported output format: "xlsx"'):
+        sheet.stream_to(tmp_path / "out.xlsx", format="xlsx")  # type: ignore[arg-type]
+
+
+def test_sheet_formulas() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-formulas.xlsx"))
+
+    expected = [(1, 2, "A2+B2"), (2, 2, "A3+B3"), (4, 2, "SUM(C2:C3)")]
+    assert excel_reader.sheet_formulas("Formulas") == expected
+    assert excel_reader.sheet_formulas(0) == expected
+    assert excel_reader.sheet_formulas("Values") == []
+
+    # Values are still the computed ones
+    assert excel_reader.load_sheet("Formulas").to_polars()["total"].to_list() == [
+        3.0,
+        7.0,
+        None,
+        10.0,
+    ]
+
+    with pytest.raises(fastexcel.SheetNotFoundError):
+        excel_reader.sheet_formulas("idontexist")
+
+
+@pytest.mark.parametrize(
+    "path, idx_or_name, expected",
+    [
+        ("fixture-multi-sheet.xlsx", 0, (2, 2)),
+        ("fixture-multi-sheet.xlsx", "January", (2, 2)),
+        ("fixture-multi-sheet.xlsx", 2, (3, 5)),
+        # The dimension of an empty sheet is recorded as "A1", its range is loaded instead
+        ("empty.xlsx", 0, (0, 0)),
+        # Dimensions are not recorded in ODS files, ranges are loaded instead
+        ("dates.ods", 0, (2, 4)),
+        ("empty.ods", 0, (0, 0)),
+    ],
+)
+def test_sheet_dimensions(path: str, idx_or_name: int | str, expected: tuple[int, int]) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture(path))
+    assert excel_reader.sheet_dimensions(idx_or_name) == expected
+
+    with open(path_for_fixture(path), "rb") as f:
+        assert fastexcel.read_excel(f.read()).sheet_dimensions(idx_or_name) == expected
+
+
+@pytest.mark.parametrize("range_cache_size", [0, 4])
+def test_sheet_dimensions_after_loading_with_header_row(range_cache_size: int) -> None:
+    # Dimensions are not recorded in ODS files, the sheet's range is loaded again
+    excel_reader = fastexcel.read_excel(
+        path_for_fixture("dates.ods"), range_cache_size=range_cache_size
+    )
+    excel_reader.load_sheet(0, header_row=1)
+    assert excel_reader.sheet_dimensions(0) == (2, 4)
+
+
+def test_sheet_dimensions_unknown_sheet() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    with pytest.raises(fastexcel.SheetNotFoundError):
+        excel_reader.sheet_dimensions("nope")
+
+
+def test_load_sheets() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+
+    sheets = excel_reader.load_sheets([0, "With unnamed columns"], n_rows=1)
+    expected_sheets = [
+        excel_reader.load_sheet(0, n_rows=1),
+        excel_reader.load_sheet("With unnamed columns", n_rows=1),
+    ]
+    assert [sheet.name for sheet in sheets] == ["January", "With unnamed columns"]
+    for sheet, expected_sheet in zip(sheets, expected_sheets):
+        assert sheet.selected_columns == expected_sheet.selected_columns
+        pl_assert_frame_equal(sheet.to_polars(), expected_sheet.to_polars())
+
+
+def test_load_sheets_unknown_sheet() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+
+    with pytest.raises(fastexcel.SheetNotFoundError, match='Sheet "March" not found'):
+        excel_reader.load_sheets([0, "March"])
+
+
+def test_load_sheets_error_names_the_sheet() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+
+    # "col3" only exists in the last sheet
+    with pytest.raises(fastexcel.ColumnNotFoundError, match='could not load sheet "January"'):
+        excel_reader.load_sheets([2, 0], use_columns=["col3"])
+
+
+@pytest.mark.parametrize(
+    "path, idx_or_name, kwargs",
+    [
+        ("fixture-multi-sheet.xlsx", "With unnamed columns", {}),
+        ("fixture-multi-sheet.xlsx", 0, {"header_row": None}),
+        ("fixture-multi-sheet.xlsx", 1, {"column_names": ["month"]}),
+        ("fixture-single-sheet-duplicated-columns.xlsx", 0, {}),
+        ("sheet-with-title-rows.xlsx", 0, {"header_row": "auto"}),
+        ("no-header.xlsx", 0, {"header_row": None, "unnamed_column_prefix": "col_"}),
+    ],
+)
+def test_available_column_names(path: str, idx_or_name: int | str, kwargs: dict[str, Any]) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture(path))
+
+    sheet = excel_reader.load_sheet(idx_or_name, **kwargs)
+    expected = [col.name for col in sheet.available_columns]
+    assert excel_reader.available_column_names(idx_or_name, **kwargs) == expected
+
+
+@pytest.mark.parametrize("from_bytes", [True, False])
+def test_metadata(from_bytes: bool) -> None:
+    path = path_for_fixture("sheet-with-core-properties.xlsx")
+    if from_bytes:
+        with open(path, "rb") as f:
+            excel_reader = fastexcel.read_excel(f.read())
+    else:
+        excel_reader = fastexcel.read_excel(path)
+
+    metadata = excel_reader.metadata()
+    assert metadata.title == "Quarterly report"
+    assert metadata.creator == "Finance & Co"
+    assert metadata.created == "2024-01-31T12:00:00Z"
+    assert metadata.modified == "2024-02-01T08:30:00Z"
+
+
+@pytest.mark.parametrize(
+    "path", ["sheet-with-duplicate-ids.xlsx", "fixture-sheets-different-visibilities.ods"]
+)
+def test_metadata_missing(path: str) -> None:
+    metadata = fastexcel.read_excel(path_for_fixture(path)).metadata()
+    assert (metadata.title, metadata.creator, metadata.created, metadata.modified) == (
+        None,
+        None,
+        None,
+        None,
+    )
+
+
+def test_sheet_count_and_has_sheet() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    assert excel_reader.sheet_count == len(excel_reader.sheet_names) == 3
+
+    assert excel_reader.has_sheet(0)
+    assert excel_reader.has_sheet(2)
+    assert not excel_reader.has_sheet(3)
+    assert excel_reader.has_sheet("January")
+    assert not excel_reader.has_sheet("january")
+
+    # Hidden sheets are counted too
+    excel_reader = fastexcel.read_excel(
+        path_for_fixture("fixture-sheets-different-visibilities.xlsx")
+    )
+    assert excel_reader.sheet_count == 3
+    assert all(excel_reader.has_sheet(name) for name in excel_reader.sheet_names)
+
+
+def test_sheet_names_with_indices() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    assert excel_reader.sheet_names_with_indices == [
+        (0, "January"),
+        (1, "February"),
+        (2, "With unnamed columns"),
+    ]
+    for idx, name in excel_reader.sheet_names_with_indices:
+        assert excel_reader.load_sheet(idx).name == name
+
+    # Hidden sheets have an index too
+    excel_reader = fastexcel.read_excel(
+        path_for_fixture("fixture-sheets-different-visibilities.xlsx")
+    )
+    assert excel_reader.sheet_names_with_indices == [
+        (0, "Visible"),
+        (1, "Hidden"),
+        (2, "VeryHidden"),
+    ]
+
+
+def test_active_sheet() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    assert excel_reader.active_sheet == 2
+    assert excel_reader.sheet_names[excel_reader.active_sheet] == "With unnamed columns"
+
+    # The first sheet is active unless stated otherwise
+    excel_reader = fastexcel.read_excel(
+        path_for_fixture("fixture-sheets-different-visibilities.xlsx")
+    )
+    assert excel_reader.active_sheet == 0
+
+    # Workbooks without any view do not record it
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-quarterly-columns.xlsx"))
+    assert excel_reader.active_sheet is None
+
+    # Only read from XLSX workbooks
+    excel_reader = fastexcel.read_excel(
+        path_for_fixture("fixture-sheets-different-visibilities.ods")
+    )
+    assert excel_reader.active_sheet is None
+
+
+def test_sheet_header() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-duplicate-ids.xlsx"))
+    # Names are not deduplicated
+    assert excel_reader.sheet_header(0) == ["id", "name", "id"]
+    assert excel_reader.sheet_header("Sheet1", header_row=1) == ["1", "apple", "10"]
+
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-title-rows.xlsx"))
+    assert excel_reader.sheet_header(0, header_row=3) == ["product", "quantity"]
+    assert excel_reader.sheet_header(0, header_row="auto") == ["product", "quantity"]
+
+
+@pytest.mark.parametrize("from_bytes", [True, False])
+def test_close(from_bytes: bool) -> None:
+    path = path_for_fixture("fixture-multi-sheet.xlsx")
+    if from_bytes:
+        with open(path, "rb") as f:
+            excel_reader = fastexcel.read_excel(f.read())
+    else:
+        excel_reader = fastexcel.read_excel(path)
+    sheet = excel_reader.load_sheet(0)
+
+    excel_reader.close()
+    # Closing twice is fine
+    excel_reader.close()
+
+    for load in (
+        lambda: excel_reader.load_sheet(0),
+        lambda: excel_reader.load_sheet_eager(0),
+        lambda: excel_reader.load_sheets([0, 1]),
+        lambda: excel_reader.sheet_formulas(0),
+        lambda: excel_reader.sheet_header(0),
+        lambda: excel_reader.cell_styles(0),
+        lambda: excel_reader.metadata(),
+        lambda: excel_reader.table_names(),
+        lambda: excel_reader.table_info("users"),
+        lambda: excel_reader.load_table("users"),
+        lambda: excel_reader.load_range(0, "A1:B2"),
+        lambda: excel_reader.load_defined_name("name"),
+        lambda: excel_reader.load_sheets_union([0, 1]),
+        lambda: excel_reader.sheet_dimensions(0),
+        lambda: excel_reader.available_column_names(0),
+        lambda: excel_reader.defined_names,
+        lambda: excel_reader.defined_names_with_scope,
+        lambda: excel_reader.defined_names_for_sheet("January"),
+        lambda: excel_reader.active_sheet,
+        lambda: excel_reader.sheet_tab_colors,
+        # Sheets may be looked up by their code name, which is read from the workbook
+        lambda: excel_reader.has_sheet("January"),
+    ):
+        with pytest.raises(fastexcel.InvalidParametersError, match="reader is closed"):
+            load()
+
+    # Metadata and already loaded sheets remain available
+    assert excel_reader.sheet_names == ["January", "February", "With unnamed columns"]
+    assert excel_reader.sheet_count == 3
+    assert sheet.to_polars().height == sheet.height
+
+
+def test_close_context_manager() -> None:
+    with fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx")) as excel_reader:
+        assert excel_reader.load_sheet(0).height > 0
+
+    with pytest.raises(fastexcel.InvalidParametersError, match="reader is closed"):
+        excel_reader.load_sheet(0)
+
+
+def test_data_bounds() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-blank-rows.xlsx"))
+
+    # Data starts at row 2, and is followed by blank rows and a note on row 7
+    sheet = excel_reader.load_sheet("Data")
+    assert sheet.header_row == 2
+    assert sheet.data_bounds == (2, 7)
+    # Bounds do not depend on pagination
+    assert excel_reader.load_sheet("Data", skip_rows=1, n_rows=1).data_bounds == (2, 7)
+
+    assert excel_reader.load_sheet("Empty").data_bounds is None
+
+
+def test_stop_at_empty_row() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-footer.xlsx"))
+    expected = pl.DataFrame({"name": ["apple", "pear", "plum"], "amount": [1.0, 2.0, 3.0]})
+    # The footer is loaded by default
+    assert excel_reader.load_sheet("Data").height == 6
+
+    sheet = excel_reader.load_sheet("Data", stop_at_empty_row=True)
+    assert sheet.height == 3
+    pl_assert_frame_equal(sheet.to_polars(), expected)
+
+    rb = excel_reader.load_sheet_eager("Data", stop_at_empty_row=True)
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+
+@pytest.mark.parametrize(
+    ("n_rows", "skip_rows", "expected_names"),
+    [
+        # n_rows ends before the empty row
+        (2, 0, ["apple", "pear"]),
+        # The empty row ends before n_rows
+        (5, 0, ["apple", "pear", "plum"]),
+        (5, 1, ["pear", "plum"]),
+    ],
+)
+def test_stop_at_empty_row_with_n_rows(
+    n_rows: int,
+    skip_rows: int,
+    expected_names: list[str],
+) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-footer.xlsx"))
+    sheet = excel_reader.load_sheet(
+        "Data", n_rows=n_rows, skip_rows=skip_rows, stop_at_empty_row=True
+    )
+    assert sheet.to_polars()["name"].to_list() == expected_names
+
+    rb = excel_reader.load_sheet_eager(
+        "Data", n_rows=n_rows, skip_rows=skip_rows, stop_at_empty_row=True
+    )
+    assert rb.column("name").to_pylist() == expected_names
+
+
+def test_stop_at_empty_row_after_skipped_rows() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-footer.xlsx"))
+    # Only rows after the skipped ones are looked at, so the footer is loaded on its own
+    sheet = excel_reader.load_sheet("Data", skip_rows=4, stop_at_empty_row=True)
+    assert sheet.to_polars()["name"].to_list() == ["Source: internal", "Total"]
+
+
+def test_stop_at_empty_row_table() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-footer.xlsx"))
+    expected = pl.DataFrame({"name": ["apple", "pear", "plum"], "amount": [1.0, 2.0, 3.0]})
+    table = excel_reader.load_table("Items", stop_at_empty_row=True)
+    assert table.height == 3
+    pl_assert_frame_equal(table.to_polars(), expected)
+
+    rb = excel_reader.load_table("Items", stop_at_empty_row=True, eager=True)
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+
+def test_load_sheets_union() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheets-with-same-columns.xlsx"))
+    rb = excel_reader.load_sheets_union(["Monday", 1])
+    pl_assert_frame_equal(
+        pl.from_arrow(rb),  # type:ignore[arg-type]
+        pl.DataFrame({"item": ["apples", "pears", "plums"], "quantity": [3.0, 5.0, 2.0]}),
+    )
+
+    # Sheets are concatenated in the given order
+    rb = excel_reader.load_sheets_union(["Tuesday", "Monday"], use_columns=["item"])
+    assert rb.column("item").to_pylist() == ["plums", "apples", "pears"]
+
+
+def test_load_sheets_union_with_sheet_name_column() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheets-with-same-columns.xlsx"))
+    rb = excel_reader.load_sheets_union(
+        ["Monday", "Tuesday", "Wednesday"],
+        add_sheet_name_column=True,
+        dtypes={"quantity": "float"},
+        null_values=["none"],
+    )
+    pl_assert_frame_equal(
+        pl.from_arrow(rb),  # type:ignore[arg-type]
+        pl.DataFrame(
+            {
+                "item": ["apples", "pears", "plums", "apples"],
+                "quantity": [3.0, 5.0, 2.0, None],
+                "__sheet_name__": ["Monday", "Monday", "Tuesday", "Wednesday"],
+            }
+        ),
+    )
+
+
+def test_load_sheets_union_schema_mismatch() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheets-with-same-columns.xlsx"))
+    with pytest.raises(
+        fastexcel.InvalidParametersError,
+        match=(
+            'sheet "Summary" cannot be concatenated with sheet "Monday": '
+            'column 1 is named "total" instead of "quantity"'
+        ),
+    ):
+        excel_reader.load_sheets_union(["Monday", "Summary"])
+
+    # Guessed dtypes must match too
+    with pytest.raises(
+        fastexcel.InvalidParametersError,
+        match='sheet "Wednesday" .* column "quantity" is of type Utf8 instead of Float64',
+    ):
+        excel_reader.load_sheets_union(["Monday", "Wednesday"])
+
+
+def test_load_sheets_union_invalid_selection() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheets-with-same-columns.xlsx"))
+    with pytest.raises(fastexcel.InvalidParametersError, match="at least one sheet is needed"):
+        excel_reader.load_sheets_union([])
+
+    with pytest.raises(fastexcel.SheetNotFoundError):
+        excel_reader.load_sheets_union(["Monday", "Sunday"])
@@ -0,0 +1,80 @@
+from __future__ import annotations
+
+from pathlib import Path
+
+import fastexcel
+import polars as pl
+import pyarrow as pa
+import pytest
+from polars.testing import assert_frame_equal as pl_assert_frame_equal
+
+from utils import path_for_fixture
+
+EXPECTED_FILLED = {
+    "category": ["Fruits", "Fruits", "Fruits", "Vegetables", "Vegetables"],
+    "group": [1.0, 1.0, 1.0, 2.0, 2.0],
+    "product": ["apple", "pear", "plum", "leek", "kale"],
+    # Columns which are not filled keep their null values
+    "amount": [3.0, 5.0, 1.0, 2.0, None],
+}
+
+
+@pytest.mark.parametrize("fill_forward_columns", [["category", "group"], [0, 1]])
+def test_fill_forward(fill_forward_columns: list[int] | list[str]) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-grouped-categories.xlsx"))
+
+    sheet = excel_reader.load_sheet(0)
+    assert sheet.to_polars()["category"].to_list() == [
+        "Fruits",
+        None,
+        None,
+        "Vegetables",
+        None,
+    ]
+
+    sheet = excel_reader.load_sheet(0, fill_forward_columns=fill_forward_columns)
+    assert sheet.to_polars().to_dict(as_series=False) == EXPECTED_FILLED
+
+    rb = excel_reader.load_sheet_eager(0, fill_forward_columns=fill_forward_columns)
+    assert pl.from_arrow(rb).to_dict(as_series=False) == EXPECTED_FILLED  # type:ignore[union-attr]
+
+
+def test_fill_forward_table() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-grouped-categories.xlsx"))
+
+    table = excel_reader.load_table("sales", fill_forward_columns=["category", "group"])
+    assert table.to_polars().to_dict(as_series=False) == EXPECTED_FILLED
+
+
+def test_fill_forward_does_not_use_skipped_rows() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-grouped-categories.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, skip_rows=1, fill_forward_columns=["category"])
+    assert sheet.to_polars()["category"].to_list() == [None, None, "Vegetables", "Vegetables"]
+
+
+@pytest.mark.parametrize("batch_size", [1, 2])
+def test_fill_forward_across_batches(tmp_path: Path, batch_size: int) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-grouped-categories.xlsx"))
+    sheet = excel_reader.load_sheet(0, fill_forward_columns=["category", "group"])
+    output_path = tmp_path / "sales.arrow"
+
+    sheet.stream_to(output_path, format="arrow_ipc", batch_size=batch_size)
+
+    with pa.ipc.open_stream(output_path) as reader:
+        table = reader.read_all()
+    pl_assert_frame_equal(pl.from_arrow(table), sheet.to_polars())  # type:ignore[arg-type]
+
+
+def test_fill_forward_unselected_column() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-grouped-categories.xlsx"))
+
+    sheet = excel_reader.load_sheet(0, use_columns=["product"], fill_forward_columns=["category"])
+    assert sheet.to_polars().columns == ["product"]
+
+
+def test_fill_forward_unknown_column() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-grouped-categories.xlsx"))
+
+    with pytest.raises(fastexcel.ColumnNotFoundError, match="could not find a column to fill"):
+        excel_reader.load_sheet(0, fill_forward_columns=["unknown"])
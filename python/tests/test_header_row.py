@@ -0,0 +1,80 @@
+from __future__ import annotations
+
+import fastexcel
+import polars as pl
+import pytest
+from polars.testing import assert_frame_equal as pl_assert_frame_equal
+
+from utils import path_for_fixture
+
+
+def test_header_row_auto() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-title-rows.xlsx"))
+    expected = pl.DataFrame({"product": ["apple", "pear"], "quantity": [3.0, 5.0]})
+
+    sheet = excel_reader.load_sheet(0, header_row="auto")
+    assert sheet.header_row == 3
+    assert sheet.height == sheet.total_height == 2
+    pl_assert_frame_equal(sheet.to_polars(), expected)
+
+    rb = excel_reader.load_sheet_eager(0, header_row="auto")
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+
+def test_header_row_property() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-title-rows.xlsx"))
+
+    assert excel_reader.load_sheet(0).header_row == 0
+    assert excel_reader.load_sheet(0, header_row=3).header_row == 3
+    assert excel_reader.load_sheet(0, header_row=None).header_row is None
+    assert excel_reader.load_sheet(0, column_names=["a", "b"]).header_row is None
+
+
+def test_header_row_invalid() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-title-rows.xlsx"))
+
+    with pytest.raises(
+        fastexcel.InvalidParametersError, match='header_row must be an int, a range, "auto" or None'
+    ):
+        excel_reader.load_sheet(0, header_row="nope")  # type:ignore[arg-type]
+
+
+def test_header_row_range() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-two-header-rows.xlsx"))
+    expected = pl.DataFrame(
+        {
+            "product": ["apple", "pear"],
+            "2024 / Q1": [3.0, 1.0],
+            "Q2": [4.0, 2.0],
+            "2025 / Q1": [5.0, 6.0],
+        }
+    )
+
+    sheet = excel_reader.load_sheet(0, header_row=range(1, 3))
+    assert sheet.header_row == 1
+    assert sheet.height == sheet.total_height == 2
+    pl_assert_frame_equal(sheet.to_polars(), expected)
+
+    rb = excel_reader.load_sheet_eager(0, header_row=range(1, 3))
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+    assert excel_reader.sheet_header(0, header_row=range(1, 3)) == [
+        "product",
+        "2024 / Q1",
+        "Q2",
+        "2025 / Q1",
+    ]
+
+    # A range of a single row is the same as that row
+    assert excel_reader.load_sheet(0, header_row=range(1, 2)).available_columns()[1].name == "2024"
+
+
+def test_header_row_range_invalid() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-two-header-rows.xlsx"))
+
+    for header_row in (range(2, 2), range(3, 1), range(0, 4, 2)):
+        with pytest.raises(
+            fastexcel.InvalidParametersError,
+            match="header_row must be a non-empty range of consecutive rows",
+        ):
+            excel_reader.load_sheet(0, header_row=header_row)
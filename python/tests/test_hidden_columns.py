@@ -0,0 +1,62 @@
+from __future__ import annotations
+
+import fastexcel
+import polars as pl
+import pytest
+from polars.testing import assert_frame_equal as pl_assert_frame_equal
+
+from utils import path_for_fixture
+
+
+@pytest.mark.parametrize("sheet_name", ["Data", "Offset"])
+def test_hidden_columns(sheet_name: str) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-hidden-columns.xlsx"))
+
+    sheet = excel_reader.load_sheet(sheet_name)
+    # Indices are relative to the first column of the sheet's data
+    assert sheet.hidden_columns == [1]
+    assert [col.name for col in sheet.selected_columns] == ["name", "scratch", "amount"]
+
+
+@pytest.mark.parametrize("sheet_name", ["Data", "Offset"])
+def test_skip_hidden_columns(sheet_name: str) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-hidden-columns.xlsx"))
+    expected = pl.DataFrame({"name": ["apple", "pear"], "amount": [3.0, 5.0]})
+
+    sheet = excel_reader.load_sheet(sheet_name, skip_hidden_columns=True)
+    assert [col.name for col in sheet.available_columns] == ["name", "scratch", "amount"]
+    assert [col.name for col in sheet.selected_columns] == ["name", "amount"]
+    pl_assert_frame_equal(sheet.to_polars(), expected)
+
+    rb = excel_reader.load_sheet_eager(sheet_name, skip_hidden_columns=True)
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+
+def test_skip_hidden_columns_with_use_columns() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-hidden-columns.xlsx"))
+
+    # Explicitly selected columns are loaded, even if they are hidden
+    sheet = excel_reader.load_sheet(
+        "Data", skip_hidden_columns=True, use_columns=["name", "scratch"]
+    )
+    assert sheet.to_polars().to_dict(as_series=False) == {
+        "name": ["apple", "pear"],
+        "scratch": ["tmp", "tmp"],
+    }
+
+
+def test_hidden_columns_range() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-hidden-columns.xlsx"))
+
+    sheet = excel_reader.load_range("Data", "B1:C3")
+    assert sheet.hidden_columns == [0]
+
+    sheet = excel_reader.load_range("Data", "C1:C3")
+    assert sheet.hidden_columns == []
+
+
+def test_hidden_columns_from_bytes() -> None:
+    with open(path_for_fixture("sheet-with-hidden-columns.xlsx"), "rb") as f:
+        excel_reader = fastexcel.read_excel(f.read())
+
+    assert excel_reader.load_sheet("Data").hidden_columns == [1]
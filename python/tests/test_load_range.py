@@ -0,0 +1,83 @@
+from __future__ import annotations
+
+import fastexcel
+import polars as pl
+import pytest
+from polars.testing import assert_frame_equal as pl_assert_frame_equal
+
+from utils import path_for_fixture
+
+
+def test_load_range() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-surrounded-table.xlsx"))
+    sheet = excel_reader.load_range("Report", "C3:E6")
+
+    assert sheet.name == "Report"
+    assert sheet.width == 3
+    assert sheet.height == 3
+    pl_assert_frame_equal(
+        sheet.to_polars(),
+        pl.DataFrame(
+            {"id": [1.0, 2.0, 3.0], "name": ["foo", "bar", "baz"], "value": [1.5, 2.5, 3.5]}
+        ),
+    )
+    # Loading by index is equivalent
+    pl_assert_frame_equal(excel_reader.load_range(0, "c3:e6").to_polars(), sheet.to_polars())
+
+
+def test_load_range_case_insensitive_sheet_name() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-surrounded-table.xlsx"))
+    with pytest.raises(fastexcel.SheetNotFoundError):
+        excel_reader.load_range("REPORT", "C3:E6")
+
+    sheet = excel_reader.load_range("REPORT", "C3:E6", case_insensitive_sheet_name=True)
+    assert sheet.name == "Report"
+    assert sheet.to_polars().columns == ["id", "name", "value"]
+
+
+def test_load_range_relative_options() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-surrounded-table.xlsx"))
+    # Rows and columns are relative to the region
+    sheet = excel_reader.load_range(
+        "Report", "C4:E6", header_row=None, skip_rows=1, n_rows=1, use_columns=[1, 2]
+    )
+    pl_assert_frame_equal(
+        sheet.to_polars(),
+        pl.DataFrame({"__UNNAMED__1": ["bar"], "__UNNAMED__2": [2.5]}),
+    )
+
+    sheet = excel_reader.load_range(
+        "Report", "C4:D6", column_names=["identifier", "label"], dtypes={"identifier": "int"}
+    )
+    pl_assert_frame_equal(
+        sheet.to_polars(),
+        pl.DataFrame({"identifier": [1, 2, 3], "label": ["foo", "bar", "baz"]}),
+    )
+
+
+def test_load_range_beyond_data() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-surrounded-table.xlsx"))
+    # The region can extend beyond the sheet's data, missing cells are null
+    sheet = excel_reader.load_range("Report", "E3:G5")
+    assert [col.name for col in sheet.available_columns] == [
+        "value",
+        "__UNNAMED__1",
+        "__UNNAMED__2",
+    ]
+    assert sheet.to_polars()["__UNNAMED__1"].to_list() == [None, "side note"]
+
+
+@pytest.mark.parametrize(
+    "a1_range, expected_message",
+    [
+        ("C3:E", "should end with a row number"),
+        ("3:6", "should start with a column letter"),
+        ("C3", "expected a range of the form"),
+        ("E6:C3", "end of range is before its start"),
+        ("H1:J5", r"range H1:J5 is out of the sheet's bounds \(A1:F8\)"),
+    ],
+)
+def test_load_range_invalid(a1_range: str, expected_message: str) -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-surrounded-table.xlsx"))
+    with pytest.raises(fastexcel.InvalidParametersError, match=expected_message):
+        excel_reader.load_range("Report", a1_range)
@@ -11,3 +11,45 @@ def test_sheet_visibilities() -> None:
     assert reader.load_sheet(0).visible == "visible"
     assert reader.load_sheet(1).visible == "hidden"
     assert reader.load_sheet(2).visible == "veryhidden"
+
+
+def test_sheet_visibilities_from_reader() -> None:
+    file_path = path_for_fixture("fixture-sheets-different-visibilities.xlsx")
+
+    reader = fastexcel.read_excel(file_path)
+
+    assert reader.sheet_visibilities == [
+        (name, reader.load_sheet(name).visible) for name in reader.sheet_names
+    ]
+    assert [visible for _, visible in reader.sheet_visibilities] == [
+        "visible",
+        "hidden",
+        "veryhidden",
+    ]
+
+
+def test_sheet_visibilities_ods() -> None:
+    file_path = path_for_fixture("fixture-sheets-different-visibilities.ods")
+
+    reader = fastexcel.read_excel(file_path)
+
+    # OpenDocument spreadsheets have no "very hidden" state, sheets are either shown or hidden
+    assert reader.sheet_visibilities == [
+        ("Visible", "visible"),
+        ("Hidden", "hidden"),
+        ("AlsoVisible", "visible"),
+    ]
+    assert reader.load_sheet("Hidden").visible == "hidden"
+
+
+def test_visible_sheet_names() -> None:
+    reader = fastexcel.read_excel(path_for_fixture("fixture-sheets-different-visibilities.xlsx"))
+
+    # Hidden and very hidden sheets are left out, in the workbook's order
+    assert reader.visible_sheet_names == [
+        name for name, visible in reader.sheet_visibilities if visible == "visible"
+    ]
+    assert reader.visible_sheet_names == reader.sheet_names[:1]
+
+    reader = fastexcel.read_excel(path_for_fixture("fixture-sheets-different-visibilities.ods"))
+    assert reader.visible_sheet_names == ["Visible", "AlsoVisible"]
@@ -0,0 +1,43 @@
+from __future__ import annotations
+
+import fastexcel
+
+from utils import path_for_fixture
+
+
+def test_sheet_tab_colors() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-tab-colors.xlsx"))
+    assert excel_reader.sheet_tab_colors == [
+        ("Sales", "FFFF0000"),
+        # Theme colors are not RGB values
+        ("Costs", None),
+        ("Notes", "FF00B050"),
+    ]
+
+    # Tab colors are read from workbooks opened from bytes too
+    with open(path_for_fixture("sheet-with-tab-colors.xlsx"), "rb") as f:
+        excel_reader = fastexcel.read_excel(f.read())
+    assert excel_reader.sheet_tab_colors[0] == ("Sales", "FFFF0000")
+
+
+def test_sheet_tab_color() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-tab-colors.xlsx"))
+    assert excel_reader.load_sheet("Sales").tab_color == "FFFF0000"
+    assert excel_reader.load_sheet("Costs").tab_color is None
+    assert excel_reader.load_range("Notes", "A1:A2").tab_color == "FF00B050"
+
+
+def test_no_tab_colors() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("fixture-multi-sheet.xlsx"))
+    assert excel_reader.sheet_tab_colors == [
+        ("January", None),
+        ("February", None),
+        ("With unnamed columns", None),
+    ]
+    assert excel_reader.load_sheet(0).tab_color is None
+
+    # Only read from XLSX workbooks
+    excel_reader = fastexcel.read_excel(
+        path_for_fixture("fixture-sheets-different-visibilities.ods")
+    )
+    assert excel_reader.sheet_tab_colors == [(name, None) for name in excel_reader.sheet_names]
@@ -101,3 +101,99 @@ def test_load_table(path: str) -> None:
     assert isinstance(pl_df, pl.DataFrame)
     pl_assert_frame_equal(pl_df, expected_pl)
     pd_assert_frame_equal(table_eager.to_pandas(), expected_pd)
+
+
+def test_table_info() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-tables.xlsx"))
+
+    table_info = excel_reader.table_info("users")
+    assert isinstance(table_info, fastexcel.TableInfo)
+    assert table_info.name == "users"
+    assert table_info.sheet_name == "sheet1"
+    assert table_info.range == "A6:D9"
+    assert table_info.column_names == ["User Id", "FirstName", "LastName", "Date"]
+    assert table_info.has_header_row
+    assert not table_info.has_totals_row
+
+
+@pytest.mark.parametrize(
+    "name, sheet_name, range, has_totals_row",
+    [("sales", "report", "A3:B6", True), ("plain", "other", "A1:B3", False)],
+)
+def test_table_info_totals_row(
+    name: str, sheet_name: str, range: str, has_totals_row: bool
+) -> None:
+    with open(path_for_fixture("sheet-with-table-totals.xlsx"), "rb") as f:
+        excel_reader = fastexcel.read_excel(f.read())
+
+    table_info = excel_reader.table_info(name)
+    assert table_info.sheet_name == sheet_name
+    assert table_info.range == range
+    assert table_info.has_totals_row is has_totals_row
+
+
+def test_table_info_unknown_table() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-tables.xlsx"))
+
+    with pytest.raises(fastexcel.InvalidParametersError, match='table "unknown" not found'):
+        excel_reader.table_info("unknown")
+
+
+def test_table_info_not_xlsx() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("dates.ods"))
+
+    with pytest.raises(fastexcel.FastExcelError, match="only XLSX files are supported for tables"):
+        excel_reader.table_info("users")
+
+
+def test_load_table_exclude_totals_row() -> None:
+    excel_reader = fastexcel.read_excel(path_for_fixture("sheet-with-table-totals.xlsx"))
+
+    # The totals row is part of the table's data by default
+    sales = excel_reader.load_table("sales")
+    assert sales.height == 3
+    assert sales.to_polars().to_dict(as_series=False) == {
+        "product": ["apple", "pear", "Total"],
+        "amount": [3.0, 5.0, 8.0],
+    }
+
+    expected = pl.DataFrame({"product": ["apple", "pear"], "amount": [3.0, 5.0]})
+    sales = excel_reader.load_table("sales", exclude_totals_row=True)
+    assert sales.height == sales.total_height == 2
+    pl_assert_frame_equal(sales.to_polars(), expected)
+
+    rb = excel_reader.load_table("sales", exclude_totals_row=True, eager=True)
+    pl_assert_frame_equal(pl.from_arrow(rb), expected)  # type:ignore[arg-type]
+
+    # Tables without a totals row are left untouched
+    plain = excel_reader.load_table("plain", exclude_totals_row=True)
+    assert plain.to_polars().to_dict(as_series=False) == {"id": [1.0, 2.0], "name": ["a", "b"]}
+
+
+def test_load_table_skip_hidden_columns() -> None:
+    excel_reader = fastexcel.read_excel(
+        path_for_fixture("sheet-with-table-hidden-columns-and-zero-padded-numbers.xlsx")
+    )
+
+    cities = excel_reader.load_table("cities")
+    assert cities.to_polars().columns == ["zip", "internal", "city"]
+
+    # The hidden column is the sheet's third one, but the table's second one
+    cities = excel_reader.load_table("cities", skip_hidden_columns=True)
+    assert cities.to_polars().to_dict(as_series=False) == {
+        "zip": ["1234", "01500"],
+        "city": ["Lyon", "Nice"],
+    }
+
+
+def test_load_table_keep_leading_zeros() -> None:
+    excel_reader = fastexcel.read_excel(
+        path_for_fixture("sheet-with-table-hidden-columns-and-zero-padded-numbers.xlsx")
+    )
+
+    cities = excel_reader.load_table("cities", keep_leading_zeros=True)
+    assert cities.to_polars().to_dict(as_series=False) == {
+        "zip": ["01234", "01500"],
+        "internal": ["x", "y"],
+        "city": ["Lyon", "Nice"],
+    }
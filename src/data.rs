@@ -1,21 +1,26 @@
-use std::sync::Arc;
+use std::{ops::Range as RowRange, sync::Arc};
 
 use arrow::{
-    array::{Array, NullArray, RecordBatch},
-    datatypes::{Field, Schema},
+    array::{Array, NullArray, RecordBatch, UInt32Array},
+    compute::take,
+    datatypes::{DataType as ArrowDataType, Field, Schema, TimeUnit},
 };
-use calamine::{Data as CalData, DataRef as CalDataRef, DataType, Range};
+use calamine::{CellType, Data as CalData, DataRef as CalDataRef, DataType, Range};
 
 use crate::{
     error::{ErrorContext, FastExcelErrorKind, FastExcelResult},
     types::{
-        dtype::{get_dtype_for_column, DType, DTypeCoercion},
-        python::excelsheet::column_info::ColumnInfo,
+        dtype::{
+            excel_float_to_zero_padded_string, get_dtype_for_column, is_null_column, DType,
+            NullValues,
+        },
+        python::excelsheet::{column_info::ColumnInfo, LoadSheetOrTableOptions},
     },
 };
 
 pub(crate) enum ExcelSheetData<'r> {
-    Owned(Range<CalData>),
+    /// Shared with the reader's range cache, if it is enabled
+    Owned(Arc<Range<CalData>>),
     Ref(Range<CalDataRef<'r>>),
 }
 
@@ -34,6 +39,24 @@ impl ExcelSheetData<'_> {
         }
     }
 
+    /// The index of the first row of the data within its sheet
+    pub(crate) fn start_row(&self) -> usize {
+        match self {
+            ExcelSheetData::Owned(range) => range.start(),
+            ExcelSheetData::Ref(range) => range.start(),
+        }
+        .map_or(0, |(row, _)| row as usize)
+    }
+
+    /// The index of the first column of the data within its sheet
+    pub(crate) fn start_col(&self) -> usize {
+        match self {
+            ExcelSheetData::Owned(range) => range.start(),
+            ExcelSheetData::Ref(range) => range.start(),
+        }
+        .map_or(0, |(_, col)| col as usize)
+    }
+
     pub(super) fn get_as_string(&self, pos: (usize, usize)) -> Option<String> {
         match self {
             ExcelSheetData::Owned(range) => range.get(pos).and_then(|data| data.as_string()),
@@ -43,24 +66,134 @@ impl ExcelSheetData<'_> {
 
     pub(crate) fn dtype_for_column(
         &self,
-        start_row: usize,
-        end_row: usize,
+        sample_rows: &[RowRange<usize>],
+        col: usize,
+        options: &LoadSheetOrTableOptions,
+    ) -> FastExcelResult<(DType, Vec<DType>)> {
+        let rows = sample_rows.iter().cloned().flatten();
+        match self {
+            ExcelSheetData::Owned(data) => get_dtype_for_column(
+                data,
+                rows,
+                col,
+                &options.dtype_coercion,
+                &options.null_values,
+                options.error_as_null,
+                options.parse_string_booleans,
+                options.parse_numeric_strings,
+            ),
+            ExcelSheetData::Ref(data) => get_dtype_for_column(
+                data,
+                rows,
+                col,
+                &options.dtype_coercion,
+                &options.null_values,
+                options.error_as_null,
+                options.parse_string_booleans,
+                options.parse_numeric_strings,
+            ),
+        }
+    }
+
+    pub(crate) fn is_null_column(
+        &self,
+        sample_rows: &[RowRange<usize>],
         col: usize,
-        dtype_coercion: &DTypeCoercion,
-    ) -> FastExcelResult<DType> {
+        null_values: &NullValues,
+        error_as_null: bool,
+    ) -> bool {
+        let rows = sample_rows.iter().cloned().flatten();
         match self {
             ExcelSheetData::Owned(data) => {
-                get_dtype_for_column(data, start_row, end_row, col, dtype_coercion)
+                is_null_column(data, rows, col, null_values, error_as_null)
             }
             ExcelSheetData::Ref(data) => {
-                get_dtype_for_column(data, start_row, end_row, col, dtype_coercion)
+                is_null_column(data, rows, col, null_values, error_as_null)
             }
         }
     }
+
+    pub(crate) fn first_empty_row(&self, from: usize) -> Option<usize> {
+        match self {
+            ExcelSheetData::Owned(range) => first_empty_row(range, from),
+            ExcelSheetData::Ref(range) => first_empty_row(range, from),
+        }
+    }
+
+    pub(crate) fn non_empty_row_bounds(&self) -> Option<(usize, usize)> {
+        match self {
+            ExcelSheetData::Owned(range) => non_empty_row_bounds(range),
+            ExcelSheetData::Ref(range) => non_empty_row_bounds(range),
+        }
+    }
+}
+
+/// Returns the index of the first row at or after `from` whose cells are all empty
+pub(crate) fn first_empty_row<DT: CellType + DataType>(
+    range: &Range<DT>,
+    from: usize,
+) -> Option<usize> {
+    range
+        .rows()
+        .enumerate()
+        .skip(from)
+        .find(|(_, row)| row.iter().all(DataType::is_empty))
+        .map(|(row_idx, _)| row_idx)
+}
+
+/// Returns the indices of the first and last rows containing at least one non-empty cell, or
+/// `None` if every cell is empty
+pub(crate) fn non_empty_row_bounds<DT: CellType + DataType>(
+    range: &Range<DT>,
+) -> Option<(usize, usize)> {
+    range
+        .rows()
+        .enumerate()
+        .filter(|(_, row)| !row.iter().all(DataType::is_empty))
+        .fold(None, |bounds, (row_idx, _)| match bounds {
+            None => Some((row_idx, row_idx)),
+            Some((first, _)) => Some((first, row_idx)),
+        })
+}
+
+/// Replaces the numbers of the given cells with the text Excel displays for them, padded with
+/// leading zeros. `cells` are the (row, column, width) of the cells whose number format pads
+/// numbers, with absolute positions within the sheet. Cells outside of the range or which do not
+/// contain a number are left untouched.
+pub(crate) fn pad_numbers_with_zeros<DT: CellType + DataType>(
+    range: &mut Range<DT>,
+    cells: &[(usize, usize, usize)],
+    string_cell: impl Fn(String) -> DT,
+) {
+    let Some((start_row, start_col)) = range.start() else {
+        return;
+    };
+    for &(row, col, width) in cells {
+        let Some(relative_position) = row
+            .checked_sub(start_row as usize)
+            .zip(col.checked_sub(start_col as usize))
+        else {
+            continue;
+        };
+        let padded = range
+            .get(relative_position)
+            .filter(|cell| cell.is_float() || cell.is_int())
+            .and_then(DataType::as_f64)
+            .and_then(|number| excel_float_to_zero_padded_string(number, width));
+        if let Some(padded) = padded {
+            range.set_value((row as u32, col as u32), string_cell(padded));
+        }
+    }
 }
 
 impl From<Range<CalData>> for ExcelSheetData<'_> {
     fn from(range: Range<CalData>) -> Self {
+        Self::Owned(Arc::new(range))
+    }
+}
+
+impl From<Arc<Range<CalData>>> for ExcelSheetData<'_> {
+    fn from(range: Arc<Range<CalData>>) -> Self {
         Self::Owned(range)
     }
 }
@@ -76,18 +209,31 @@ mod array_impls {
 
     use arrow::array::{
         Array, BooleanArray, Date32Array, DurationMillisecondArray, Float64Array, Int64Array,
-        StringArray, TimestampMillisecondArray,
+        StringArray, Time32MillisecondArray, TimestampMillisecondArray,
     };
     use calamine::{CellType, DataType, Range};
-    use chrono::NaiveDate;
+    use chrono::{NaiveDate, Timelike};
 
-    use crate::types::dtype::excel_float_to_string;
+    use crate::{
+        error::{
+            ErrorContext, FastExcelError, FastExcelErrorKind::CannotRetrieveCellData,
+            FastExcelResult,
+        },
+        types::{
+            dtype::{
+                excel_float_to_string, is_time_of_day, string_as_bool, DTypeCoercion,
+                DEFAULT_FLOAT_STRING_PRECISION,
+            },
+            python::excelsheet::LoadSheetOrTableOptions,
+        },
+    };
 
     pub(crate) fn create_boolean_array<DT: CellType + DataType>(
         data: &Range<DT>,
         col: usize,
         offset: usize,
         limit: usize,
+        parse_string_booleans: bool,
     ) -> Arc<dyn Array> {
         Arc::new(BooleanArray::from_iter((offset..limit).map(|row| {
             data.get((row, col)).and_then(|cell| {
@@ -95,6 +241,8 @@ mod array_impls {
                     Some(b)
                 } else if let Some(i) = cell.get_int() {
                     Some(i != 0)
+                } else if let Some(s) = cell.get_string().filter(|_| parse_string_booleans) {
+                    string_as_bool(s)
                 }
                 // clippy formats else if let Some(blah) = ... { Some(x) } else { None } to the .map form
                 else {
@@ -104,15 +252,36 @@ mod array_impls {
         })))
     }
 
+    /// Floats outside of the i64 range (e.g. identifiers with 20 digits) are an error, rather
+    /// than being silently saturated
     pub(crate) fn create_int_array<DT: CellType + DataType>(
         data: &Range<DT>,
         col: usize,
         offset: usize,
         limit: usize,
-    ) -> Arc<dyn Array> {
-        Arc::new(Int64Array::from_iter(
-            (offset..limit).map(|row| data.get((row, col)).and_then(|cell| cell.as_i64())),
-        ))
+    ) -> FastExcelResult<Arc<dyn Array>> {
+        // i64::MAX is not representable as a float, i64::MAX as f64 is 2^63
+        let i64_range = i64::MIN as f64..i64::MAX as f64;
+        let values = (offset..limit)
+            .map(|row| {
+                let Some(cell) = data.get((row, col)) else {
+                    return Ok(None);
+                };
+                match cell.get_float() {
+                    Some(float) if !i64_range.contains(&float) => {
+                        Err(FastExcelError::from(CannotRetrieveCellData(row, col))
+                            .with_context(|| {
+                                format!(
+                                    "{} does not fit in a 64-bit integer, consider loading the column as float or string",
+                                    excel_float_to_string(float, DEFAULT_FLOAT_STRING_PRECISION)
+                                )
+                            }))
+                    }
+                    _ => Ok(cell.as_i64()),
+                }
+            })
+            .collect::<FastExcelResult<Vec<_>>>()?;
+        Ok(Arc::new(Int64Array::from(values)))
     }
 
     pub(crate) fn create_float_array<DT: CellType + DataType>(
@@ -131,21 +300,47 @@ mod array_impls {
         col: usize,
         offset: usize,
         limit: usize,
+        options: &LoadSheetOrTableOptions,
     ) -> Arc<dyn Array> {
+        let null_values = &options.null_values;
+        let raw_numbers = options.dtype_coercion == DTypeCoercion::RawString;
+        let trim_strings = options.trim_strings;
+        let float_string_precision = options.float_string_precision;
         Arc::new(StringArray::from_iter((offset..limit).map(|row| {
             data.get((row, col)).and_then(|cell| {
                 if cell.is_string() {
-                    cell.get_string().map(str::to_string)
+                    cell.get_string()
+                        .map(|value| if trim_strings { value.trim() } else { value })
+                        // Strings which are empty once trimmed are blank cells
+                        .filter(|value| !(trim_strings && value.is_empty()))
+                        .filter(|value| !null_values.nullifies_string(value))
+                        .map(str::to_string)
                 } else if cell.is_datetime() {
-                    cell.get_datetime()
-                        .and_then(|dt| dt.as_datetime())
-                        .map(|dt| dt.to_string())
+                    cell.get_datetime().and_then(|excel_dt| {
+                        let dt = excel_dt.as_datetime()?;
+                        Some(if is_time_of_day(&excel_dt) {
+                            dt.time().to_string()
+                        } else {
+                            dt.to_string()
+                        })
+                    })
                 } else if cell.is_datetime_iso() {
                     cell.get_datetime_iso().map(str::to_string)
                 } else if cell.is_bool() {
                     cell.get_bool().map(|v| v.to_string())
-                } else if cell.is_float() {
-                    cell.get_float().map(excel_float_to_string)
+                }
+                // Numbers are formatted the way Excel displays them, e.g. 1000.0 as "1000" and
+                // 29.020000000000003 as "29.02". Raw numbers are written with the shortest
+                // representation which reads back to the same float, which is what Excel stores
+                else if cell.is_float() {
+                    if raw_numbers {
+                        cell.get_float().map(|f| f.to_string())
+                    } else {
+                        cell.get_float()
+                            .map(|float| excel_float_to_string(float, float_string_precision))
+                    }
+                } else if cell.is_int() {
+                    cell.get_int().map(|i| i.to_string())
                 } else {
                     cell.as_string()
                 }
@@ -153,6 +348,27 @@ mod array_impls {
         })))
     }
 
+    pub(crate) fn create_time_array<DT: CellType + DataType>(
+        data: &Range<DT>,
+        col: usize,
+        offset: usize,
+        limit: usize,
+    ) -> Arc<dyn Array> {
+        Arc::new(Time32MillisecondArray::from_iter((offset..limit).map(
+            |row| {
+                data.get((row, col))
+                    .and_then(|caldt| caldt.as_datetime())
+                    .map(|dt| dt.time())
+                    .and_then(|time| {
+                        i32::try_from(
+                            time.num_seconds_from_midnight() * 1000 + time.nanosecond() / 1_000_000,
+                        )
+                        .ok()
+                    })
+            },
+        )))
+    }
+
     fn duration_type_to_i64<DT: CellType + DataType>(caldt: &DT) -> Option<i64> {
         caldt.as_duration().map(|d| d.num_milliseconds())
     }
@@ -176,14 +392,16 @@ mod array_impls {
         col: usize,
         offset: usize,
         limit: usize,
+        datetime_tz: Option<&str>,
     ) -> Arc<dyn Array> {
-        Arc::new(TimestampMillisecondArray::from_iter((offset..limit).map(
-            |row| {
+        Arc::new(
+            TimestampMillisecondArray::from_iter((offset..limit).map(|row| {
                 data.get((row, col))
                     .and_then(|caldt| caldt.as_datetime())
                     .map(|dt| dt.and_utc().timestamp_millis())
-            },
-        )))
+            }))
+            .with_timezone_opt(datetime_tz),
+        )
     }
 
     pub(crate) fn create_duration_array<DT: CellType + DataType>(
@@ -215,13 +433,10 @@ macro_rules! create_array_function {
     };
 }
 
-create_array_function!(create_boolean_array);
-create_array_function!(create_string_array);
-create_array_function!(create_int_array);
 create_array_function!(create_float_array);
-create_array_function!(create_datetime_array);
 create_array_function!(create_date_array);
 create_array_function!(create_duration_array);
+create_array_function!(create_time_array);
 
 pub(crate) use array_impls::create_boolean_array as create_boolean_array_from_range;
 pub(crate) use array_impls::create_date_array as create_date_array_from_range;
@@ -230,10 +445,97 @@ pub(crate) use array_impls::create_duration_array as create_duration_array_from_
 pub(crate) use array_impls::create_float_array as create_float_array_from_range;
 pub(crate) use array_impls::create_int_array as create_int_array_from_range;
 pub(crate) use array_impls::create_string_array as create_string_array_from_range;
+pub(crate) use array_impls::create_time_array as create_time_array_from_range;
+
+/// Dispatches ExcelData to the generic create_boolean_array implementation, which additionally
+/// needs whether strings such as "yes" should be parsed
+pub(crate) fn create_boolean_array(
+    data: &ExcelSheetData,
+    col: usize,
+    offset: usize,
+    limit: usize,
+    parse_string_booleans: bool,
+) -> Arc<dyn Array> {
+    match data {
+        ExcelSheetData::Owned(range) => {
+            array_impls::create_boolean_array(range, col, offset, limit, parse_string_booleans)
+        }
+        ExcelSheetData::Ref(range) => {
+            array_impls::create_boolean_array(range, col, offset, limit, parse_string_booleans)
+        }
+    }
+}
+
+/// Dispatches ExcelData to the generic create_int_array implementation, which can fail
+pub(crate) fn create_int_array(
+    data: &ExcelSheetData,
+    col: usize,
+    offset: usize,
+    limit: usize,
+) -> FastExcelResult<Arc<dyn Array>> {
+    match data {
+        ExcelSheetData::Owned(range) => array_impls::create_int_array(range, col, offset, limit),
+        ExcelSheetData::Ref(range) => array_impls::create_int_array(range, col, offset, limit),
+    }
+}
+
+/// Dispatches ExcelData to the generic create_string_array implementation, which additionally
+/// needs the options driving how values are converted to strings
+pub(crate) fn create_string_array(
+    data: &ExcelSheetData,
+    col: usize,
+    offset: usize,
+    limit: usize,
+    options: &LoadSheetOrTableOptions,
+) -> Arc<dyn Array> {
+    match data {
+        ExcelSheetData::Owned(range) => {
+            array_impls::create_string_array(range, col, offset, limit, options)
+        }
+        ExcelSheetData::Ref(range) => {
+            array_impls::create_string_array(range, col, offset, limit, options)
+        }
+    }
+}
 
-/// Converts a list of ColumnInfo to an arrow Schema
-pub(crate) fn selected_columns_to_schema(columns: &[ColumnInfo]) -> Schema {
-    let fields: Vec<_> = columns.iter().map(Into::<Field>::into).collect();
+/// Dispatches ExcelData to the generic create_datetime_array implementation, which additionally
+/// needs the timezone of the datetimes
+pub(crate) fn create_datetime_array(
+    data: &ExcelSheetData,
+    col: usize,
+    offset: usize,
+    limit: usize,
+    datetime_tz: Option<&str>,
+) -> Arc<dyn Array> {
+    match data {
+        ExcelSheetData::Owned(range) => {
+            array_impls::create_datetime_array(range, col, offset, limit, datetime_tz)
+        }
+        ExcelSheetData::Ref(range) => {
+            array_impls::create_datetime_array(range, col, offset, limit, datetime_tz)
+        }
+    }
+}
+
+/// Converts a list of ColumnInfo to an arrow Schema. Datetime fields are tagged with
+/// `datetime_tz`, if any
+pub(crate) fn selected_columns_to_schema(
+    columns: &[ColumnInfo],
+    datetime_tz: Option<&str>,
+) -> Schema {
+    let fields: Vec<_> = columns
+        .iter()
+        .map(|column| {
+            let field = Field::from(column);
+            match (column.dtype(), datetime_tz) {
+                (DType::DateTime, Some(tz)) => field.with_data_type(ArrowDataType::Timestamp(
+                    TimeUnit::Millisecond,
+                    Some(tz.into()),
+                )),
+                _ => field,
+            }
+        })
+        .collect();
     Schema::new(fields)
 }
 
@@ -261,35 +563,104 @@ pub(crate) fn record_batch_from_name_array_iterator<
     }
 }
 
+/// Replaces the null values of an array with the last non-null value preceding them
+pub(crate) fn fill_forward(array: &dyn Array) -> FastExcelResult<Arc<dyn Array>> {
+    let mut last_valid = None;
+    let indices: UInt32Array = (0..array.len())
+        .map(|idx| {
+            if array.is_valid(idx) {
+                last_valid = u32::try_from(idx).ok();
+            }
+            last_valid
+        })
+        .collect();
+    take(array, &indices, None)
+        .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+        .with_context(|| "could not fill null values forward")
+}
+
 /// Creates an arrow `RecordBatch` from `ExcelSheetData`. Expects the following parameters:
 /// * `columns`: a slice of `ColumnInfo`, representing the columns that should be extracted from the range
 /// * `data`: the sheets data, as an `ExcelSheetData`
+/// * `data_start`: the row index at which the loaded data starts. Rows between `data_start` and
+///   `offset` are only read to fill the first null values of filled forward columns
 /// * `offset`: the row index at which to start
 /// * `limit`: the row index at which to stop (excluded)
-pub(crate) fn record_batch_from_data_and_columns(
-    columns: &[ColumnInfo],
+/// * `options`: the options the sheet was loaded with
+pub(crate) fn record_batch_from_data_and_columns<'a>(
+    columns: &'a [ColumnInfo],
     data: &ExcelSheetData,
+    data_start: usize,
     offset: usize,
     limit: usize,
+    options: &LoadSheetOrTableOptions,
 ) -> FastExcelResult<RecordBatch> {
-    let schema = selected_columns_to_schema(columns);
-    let iter = columns.iter().map(|column_info| {
+    let null_values = &options.null_values;
+    let datetime_tz = options.datetime_tz.as_deref();
+    let schema = selected_columns_to_schema(columns, datetime_tz);
+    let build_array = |column_info: &'a ColumnInfo| -> FastExcelResult<(&'a str, Arc<dyn Array>)> {
         let col_idx = column_info.index();
         let dtype = *column_info.dtype();
-        (
-            column_info.name.as_str(),
-            match dtype {
+        let create_array = |offset: usize| -> FastExcelResult<Arc<dyn Array>> {
+            Ok(match dtype {
                 DType::Null => Arc::new(NullArray::new(limit - offset)),
-                DType::Int => create_int_array(data, col_idx, offset, limit),
+                DType::Int => {
+                    create_int_array(data, col_idx, offset, limit).with_context(|| {
+                        format!("could not create int column \"{}\"", column_info.name)
+                    })?
+                }
                 DType::Float => create_float_array(data, col_idx, offset, limit),
-                DType::String => create_string_array(data, col_idx, offset, limit),
-                DType::Bool => create_boolean_array(data, col_idx, offset, limit),
-                DType::DateTime => create_datetime_array(data, col_idx, offset, limit),
+                DType::String => create_string_array(data, col_idx, offset, limit, options),
+                DType::Bool => create_boolean_array(
+                    data,
+                    col_idx,
+                    offset,
+                    limit,
+                    options.parse_string_booleans,
+                ),
+                DType::DateTime => create_datetime_array(data, col_idx, offset, limit, datetime_tz),
                 DType::Date => create_date_array(data, col_idx, offset, limit),
                 DType::Duration => create_duration_array(data, col_idx, offset, limit),
-            },
-        )
-    });
+                DType::Time => create_time_array(data, col_idx, offset, limit),
+            })
+        };
+        let array = if options.fills_forward(column_info) {
+            // The first null values are filled with the last non-null value of the previous
+            // rows, which are not part of the batch
+            let fill_start = (data_start..offset)
+                .rev()
+                .find(|row| {
+                    !data.is_null_column(
+                        std::slice::from_ref(&(*row..*row + 1)),
+                        col_idx,
+                        null_values,
+                        options.error_as_null,
+                    )
+                })
+                .unwrap_or(offset);
+            fill_forward(create_array(fill_start)?.as_ref())?
+                .slice(offset - fill_start, limit - offset)
+        } else {
+            create_array(offset)?
+        };
+        Ok((column_info.name.as_str(), array))
+    };
+
+    // Columns are built independently from each other, so wide sheets benefit from building
+    // them in parallel
+    #[cfg(feature = "parallel")]
+    let arrays = {
+        use rayon::prelude::*;
+        columns
+            .par_iter()
+            .map(build_array)
+            .collect::<FastExcelResult<Vec<_>>>()?
+    };
+    #[cfg(not(feature = "parallel"))]
+    let arrays = columns
+        .iter()
+        .map(build_array)
+        .collect::<FastExcelResult<Vec<_>>>()?;
 
-    record_batch_from_name_array_iterator(iter, schema)
+    record_batch_from_name_array_iterator(arrays.into_iter(), schema)
 }
@@ -8,6 +8,8 @@ pub(crate) enum FastExcelErrorKind {
     CannotRetrieveCellData(usize, usize),
     CalamineCellError(calamine::CellErrorType),
     CalamineError(calamine::Error),
+    /// The workbook is encrypted, which calamine does not support even without a password
+    EncryptedWorkbook,
     SheetNotFound(IdxOrName),
     ColumnNotFound(IdxOrName),
     // Arrow errors can be of several different types (arrow::error::Error, PyError), and having
@@ -32,6 +34,11 @@ impl Display for FastExcelErrorKind {
             FastExcelErrorKind::CalamineError(calamine_error) => {
                 write!(f, "calamine error: {calamine_error}")
             }
+            FastExcelErrorKind::EncryptedWorkbook => write!(
+                f,
+                "workbook is encrypted: encrypted workbooks are not supported, even without a \
+                 password. Save a copy of the workbook without encryption and read it instead"
+            ),
             FastExcelErrorKind::SheetNotFound(idx_or_name) => {
                 let message = idx_or_name.format_message();
                 write!(f, "sheet {message} not found")
@@ -161,6 +168,13 @@ pub(crate) mod py_errors {
         FastExcelError,
         "Generic calamine error"
     );
+    // Encrypted workbook
+    create_exception!(
+        _fastexcel,
+        EncryptedWorkbookError,
+        FastExcelError,
+        "Workbook is encrypted"
+    );
     // Sheet not found
     create_exception!(
         _fastexcel,
@@ -222,6 +236,9 @@ pub(crate) mod py_errors {
                             CalamineCellError::new_err(message)
                         }
                         FastExcelErrorKind::CalamineError(_) => CalamineError::new_err(message),
+                        FastExcelErrorKind::EncryptedWorkbook => {
+                            EncryptedWorkbookError::new_err(message)
+                        }
                         FastExcelErrorKind::SheetNotFound(_) => {
                             SheetNotFoundError::new_err(message)
                         }
@@ -3,28 +3,65 @@ mod error;
 mod types;
 mod utils;
 
+use std::sync::Arc;
+
 use error::{py_errors, ErrorContext};
 use pyo3::prelude::*;
 use types::python::{
-    excelsheet::column_info::ColumnInfo, table::ExcelTable, ExcelReader, ExcelSheet,
+    cell_style::CellStyle,
+    defined_name::DefinedName,
+    excelreader::WorkbookFormat,
+    excelsheet::column_info::ColumnInfo,
+    file_like::PyFileLike,
+    table::{ExcelTable, TableInfo},
+    workbook_metadata::WorkbookMetadata,
+    ExcelReader, ExcelSheet,
 };
 
-/// Reads an excel file and returns an object allowing to access its sheets and a bit of metadata
+/// Reads an excel file and returns an object allowing to access its sheets and a bit of metadata.
+/// The file can be passed as a path, as bytes, or as a binary file-like object.
+/// If `format` is provided, the file is opened with the matching reader instead of guessing its
+/// format.
+/// Up to `range_cache_size` parsed sheets are kept in memory, so that loading them again does not
+/// parse them again
 #[pyfunction]
-fn read_excel(source: &Bound<'_, PyAny>) -> PyResult<ExcelReader> {
+#[pyo3(signature = (source, *, format = None, range_cache_size = 0))]
+fn read_excel(
+    source: &Bound<'_, PyAny>,
+    format: Option<WorkbookFormat>,
+    range_cache_size: usize,
+) -> PyResult<ExcelReader> {
     use py_errors::IntoPyResult;
 
     if let Ok(path) = source.extract::<String>() {
-        ExcelReader::try_from_path(&path)
-            .with_context(|| format!("could not load excel file at {path}"))
-            .into_pyresult()
+        match format {
+            Some(format) => ExcelReader::try_from_path_as(&path, format),
+            None => ExcelReader::try_from_path(&path),
+        }
+        .map(|reader| reader.with_range_cache_size(range_cache_size))
+        .with_context(|| format!("could not load excel file at {path}"))
+        .into_pyresult()
     } else if let Ok(bytes) = source.extract::<&[u8]>() {
-        ExcelReader::try_from(bytes)
-            .with_context(|| "could not load excel file for those bytes")
-            .into_pyresult()
+        // The bytes are copied once, to a buffer shared with the reader
+        let content: Arc<[u8]> = bytes.into();
+        match format {
+            Some(format) => ExcelReader::try_from_bytes_as(content, format),
+            None => ExcelReader::try_from(content),
+        }
+        .map(|reader| reader.with_range_cache_size(range_cache_size))
+        .with_context(|| "could not load excel file for those bytes")
+        .into_pyresult()
+    } else if let Some(file_like) = PyFileLike::from_object(source) {
+        match format {
+            Some(format) => ExcelReader::try_from_reader_as(file_like, format),
+            None => ExcelReader::try_from_reader(file_like),
+        }
+        .map(|reader| reader.with_range_cache_size(range_cache_size))
+        .with_context(|| "could not load excel file from file-like object")
+        .into_pyresult()
     } else {
         Err(py_errors::InvalidParametersError::new_err(
-            "source must be a string or bytes",
+            "source must be a string, bytes or a binary file-like object",
         ))
     }
 }
@@ -51,6 +88,10 @@ fn _fastexcel(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<ExcelSheet>()?;
     m.add_class::<ExcelReader>()?;
     m.add_class::<ExcelTable>()?;
+    m.add_class::<TableInfo>()?;
+    m.add_class::<CellStyle>()?;
+    m.add_class::<DefinedName>()?;
+    m.add_class::<WorkbookMetadata>()?;
     m.add("__version__", get_version())?;
 
     // errors
@@ -75,6 +116,10 @@ fn _fastexcel(m: &Bound<'_, PyModule>) -> PyResult<()> {
             "CalamineError",
             py.get_type_bound::<py_errors::CalamineError>(),
         ),
+        (
+            "EncryptedWorkbookError",
+            py.get_type_bound::<py_errors::EncryptedWorkbookError>(),
+        ),
         (
             "SheetNotFoundError",
             py.get_type_bound::<py_errors::SheetNotFoundError>(),
@@ -1,12 +1,12 @@
 use std::{
-    collections::{HashMap, HashSet},
+    collections::{BTreeSet, HashMap, HashSet},
     fmt::{Debug, Display},
     str::FromStr,
     sync::OnceLock,
 };
 
 use arrow::datatypes::{DataType as ArrowDataType, TimeUnit};
-use calamine::{CellErrorType, CellType, DataType, Range};
+use calamine::{CellErrorType, CellType, DataType, ExcelDateTime, Range};
 use log::warn;
 use pyo3::{
     prelude::PyAnyMethods, Bound, FromPyObject, PyAny, PyObject, PyResult, Python, ToPyObject,
@@ -16,7 +16,7 @@ use crate::error::{py_errors::IntoPyResult, FastExcelError, FastExcelErrorKind,
 
 use super::idx_or_name::IdxOrName;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Copy)]
 pub(crate) enum DType {
     Null,
     Int,
@@ -26,6 +26,7 @@ pub(crate) enum DType {
     DateTime,
     Date,
     Duration,
+    Time,
 }
 
 impl FromStr for DType {
@@ -41,6 +42,7 @@ impl FromStr for DType {
             "datetime" => Ok(Self::DateTime),
             "date" => Ok(Self::Date),
             "duration" => Ok(Self::Duration),
+            "time" => Ok(Self::Time),
             _ => Err(FastExcelErrorKind::InvalidParameters(format!(
                 "unsupported dtype: \"{raw_dtype}\""
             ))
@@ -60,6 +62,7 @@ impl Display for DType {
             DType::DateTime => "datetime",
             DType::Date => "date",
             DType::Duration => "duration",
+            DType::Time => "time",
         })
     }
 }
@@ -86,6 +89,7 @@ impl FromPyObject<'_> for DType {
 
 pub(crate) type DTypeMap = HashMap<IdxOrName, DType>;
 
+#[derive(Clone)]
 pub(crate) enum DTypes {
     All(DType),
     Map(DTypeMap),
@@ -130,6 +134,30 @@ impl From<&DType> for ArrowDataType {
             DType::DateTime => ArrowDataType::Timestamp(TimeUnit::Millisecond, None),
             DType::Date => ArrowDataType::Date32,
             DType::Duration => ArrowDataType::Duration(TimeUnit::Millisecond),
+            DType::Time => ArrowDataType::Time32(TimeUnit::Millisecond),
+        }
+    }
+}
+
+/// The reverse of `From<&DType> for ArrowDataType`. The timezone of timestamps is not checked
+impl TryFrom<&ArrowDataType> for DType {
+    type Error = FastExcelError;
+
+    fn try_from(data_type: &ArrowDataType) -> FastExcelResult<Self> {
+        match data_type {
+            ArrowDataType::Null => Ok(DType::Null),
+            ArrowDataType::Int64 => Ok(DType::Int),
+            ArrowDataType::Float64 => Ok(DType::Float),
+            ArrowDataType::Utf8 => Ok(DType::String),
+            ArrowDataType::Boolean => Ok(DType::Bool),
+            ArrowDataType::Timestamp(TimeUnit::Millisecond, _) => Ok(DType::DateTime),
+            ArrowDataType::Date32 => Ok(DType::Date),
+            ArrowDataType::Duration(TimeUnit::Millisecond) => Ok(DType::Duration),
+            ArrowDataType::Time32(TimeUnit::Millisecond) => Ok(DType::Time),
+            _ => Err(FastExcelErrorKind::InvalidParameters(format!(
+                "unsupported arrow type: {data_type}"
+            ))
+            .into()),
         }
     }
 }
@@ -138,6 +166,12 @@ impl From<&DType> for ArrowDataType {
 pub(crate) enum DTypeCoercion {
     Coerce,
     Strict,
+    /// Same as `Coerce`, except that booleans are never coerced to another type: a column
+    /// containing booleans and values of any other type is an error
+    StrictNoBoolNumeric,
+    /// Every column is loaded as strings, without any coercion. Numbers keep their full stored
+    /// precision rather than being formatted the way Excel displays them
+    RawString,
 }
 
 impl FromStr for DTypeCoercion {
@@ -147,6 +181,8 @@ impl FromStr for DTypeCoercion {
         match raw_dtype_coercion {
             "coerce" => Ok(Self::Coerce),
             "strict" => Ok(Self::Strict),
+            "strict_no_bool_numeric" => Ok(Self::StrictNoBoolNumeric),
+            "raw_string" => Ok(Self::RawString),
             _ => Err(FastExcelErrorKind::InvalidParameters(format!(
                 "unsupported dtype_coercion: \"{raw_dtype_coercion}\""
             ))
@@ -169,16 +205,85 @@ impl FromPyObject<'_> for DTypeCoercion {
     }
 }
 
-/// All the possible string values that should be considered as NULL
+/// All the possible string values that should be considered as NULL by default
 const NULL_STRING_VALUES: [&str; 19] = [
     "", "#N/A", "#N/A N/A", "#NA", "-1.#IND", "-1.#QNAN", "-NaN", "-nan", "1.#IND", "1.#QNAN",
     "<NA>", "N/A", "NA", "NULL", "NaN", "None", "n/a", "nan", "null",
 ];
 
+/// The string values that should be considered as NULL
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) enum NullValues {
+    /// `NULL_STRING_VALUES`. These are only taken into account for dtype inference: string
+    /// columns keep them as is
+    #[default]
+    Default,
+    /// User-provided values, replacing the default ones. These are taken into account for dtype
+    /// inference, and are read as null in string columns
+    Custom(Vec<String>),
+}
+
+impl NullValues {
+    /// Whether a string cell should be considered as null when inferring a column's dtype
+    pub(crate) fn contains(&self, value: &str) -> bool {
+        match self {
+            NullValues::Default => NULL_STRING_VALUES.contains(&value),
+            NullValues::Custom(values) => values.iter().any(|null_value| null_value == value),
+        }
+    }
+
+    /// Whether a string cell should be read as null in a string column
+    pub(crate) fn nullifies_string(&self, value: &str) -> bool {
+        matches!(self, NullValues::Custom(_)) && self.contains(value)
+    }
+}
+
+impl From<Option<Vec<String>>> for NullValues {
+    fn from(values: Option<Vec<String>>) -> Self {
+        values.map(NullValues::Custom).unwrap_or_default()
+    }
+}
+
+/// String values read as `true` and `false` when parsing string booleans, compared
+/// case-insensitively
+const TRUE_STRING_VALUES: [&str; 2] = ["true", "yes"];
+const FALSE_STRING_VALUES: [&str; 2] = ["false", "no"];
+
+/// The boolean a string stands for, if any
+pub(crate) fn string_as_bool(value: &str) -> Option<bool> {
+    let value = value.trim();
+    if TRUE_STRING_VALUES
+        .iter()
+        .any(|true_value| value.eq_ignore_ascii_case(true_value))
+    {
+        Some(true)
+    } else if FALSE_STRING_VALUES
+        .iter()
+        .any(|false_value| value.eq_ignore_ascii_case(false_value))
+    {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+/// The float a string stands for, if any, e.g. 120000.0 for "1.2E5". Strings are parsed the way
+/// calamine converts them to floats when building float columns
+pub(crate) fn string_as_float(value: &str) -> Option<f64> {
+    value.parse().ok()
+}
+
+/// Whether an Excel datetime only holds a time of day, i.e. its date component is the Excel epoch
+pub(crate) fn is_time_of_day(excel_datetime: &ExcelDateTime) -> bool {
+    excel_datetime.is_datetime() && (0.0..1.0).contains(&excel_datetime.as_f64())
+}
+
 fn get_cell_dtype<DT: CellType + Debug + DataType>(
     data: &Range<DT>,
     row: usize,
     col: usize,
+    null_values: &NullValues,
+    error_as_null: bool,
 ) -> FastExcelResult<DType> {
     let cell = data
         .get((row, col))
@@ -189,7 +294,7 @@ fn get_cell_dtype<DT: CellType + Debug + DataType>(
     } else if cell.is_float() {
         Ok(DType::Float)
     } else if cell.is_string() {
-        if NULL_STRING_VALUES.contains(&cell.get_string().unwrap()) {
+        if null_values.contains(cell.get_string().unwrap()) {
             Ok(DType::Null)
         } else {
             Ok(DType::String)
@@ -202,7 +307,9 @@ fn get_cell_dtype<DT: CellType + Debug + DataType>(
         let excel_datetime = cell
             .get_datetime()
             .expect("calamine indicated that cell is a datetime but get_datetime returned None");
-        Ok(if excel_datetime.is_datetime() {
+        Ok(if is_time_of_day(&excel_datetime) {
+            DType::Time
+        } else if excel_datetime.is_datetime() {
             DType::DateTime
         } else {
             DType::Duration
@@ -227,10 +334,12 @@ fn get_cell_dtype<DT: CellType + Debug + DataType>(
         Ok(DType::Null)
     } else if cell.is_error() {
         match cell.get_error() {
-            // considering cells with #N/A! or #REF! as null
+            // considering cells with #N/A! or #REF! as null, as well as #GETTING_DATA (a.k.a.
+            // #DATA!), which is a placeholder for values which were not computed yet
             Some(
-                CellErrorType::NA | CellErrorType::Value | CellErrorType::Null | CellErrorType::Ref | CellErrorType::Num,
+                CellErrorType::NA | CellErrorType::Value | CellErrorType::Null | CellErrorType::Ref | CellErrorType::Num | CellErrorType::GettingData,
             ) => Ok(DType::Null),
+            Some(_) if error_as_null => Ok(DType::Null),
             Some(err) => Err(FastExcelErrorKind::CalamineCellError(err.to_owned()).into()),
             None => Err(FastExcelErrorKind::Internal(format!(
                 "cell is an error but get_error returned None: {cell:?}"
@@ -245,79 +354,187 @@ fn get_cell_dtype<DT: CellType + Debug + DataType>(
 static FLOAT_TYPES_CELL: OnceLock<HashSet<DType>> = OnceLock::new();
 static INT_TYPES_CELL: OnceLock<HashSet<DType>> = OnceLock::new();
 static STRING_TYPES_CELL: OnceLock<HashSet<DType>> = OnceLock::new();
+static FLOAT_TYPES_NO_BOOL_CELL: OnceLock<HashSet<DType>> = OnceLock::new();
+static INT_TYPES_NO_BOOL_CELL: OnceLock<HashSet<DType>> = OnceLock::new();
+static STRING_TYPES_NO_BOOL_CELL: OnceLock<HashSet<DType>> = OnceLock::new();
+
+fn float_types(dtype_coercion: &DTypeCoercion) -> &'static HashSet<DType> {
+    match dtype_coercion {
+        DTypeCoercion::StrictNoBoolNumeric => {
+            FLOAT_TYPES_NO_BOOL_CELL.get_or_init(|| HashSet::from([DType::Int, DType::Float]))
+        }
+        _ => {
+            FLOAT_TYPES_CELL.get_or_init(|| HashSet::from([DType::Int, DType::Float, DType::Bool]))
+        }
+    }
+}
 
-fn float_types() -> &'static HashSet<DType> {
-    FLOAT_TYPES_CELL.get_or_init(|| HashSet::from([DType::Int, DType::Float, DType::Bool]))
+fn int_types(dtype_coercion: &DTypeCoercion) -> &'static HashSet<DType> {
+    match dtype_coercion {
+        DTypeCoercion::StrictNoBoolNumeric => {
+            INT_TYPES_NO_BOOL_CELL.get_or_init(|| HashSet::from([DType::Int]))
+        }
+        _ => INT_TYPES_CELL.get_or_init(|| HashSet::from([DType::Int, DType::Bool])),
+    }
 }
 
-fn int_types() -> &'static HashSet<DType> {
-    INT_TYPES_CELL.get_or_init(|| HashSet::from([DType::Int, DType::Bool]))
+fn string_types(dtype_coercion: &DTypeCoercion) -> &'static HashSet<DType> {
+    match dtype_coercion {
+        DTypeCoercion::StrictNoBoolNumeric => STRING_TYPES_NO_BOOL_CELL.get_or_init(|| {
+            HashSet::from([
+                DType::Int,
+                DType::Float,
+                DType::String,
+                DType::DateTime,
+                DType::Date,
+                DType::Time,
+            ])
+        }),
+        _ => STRING_TYPES_CELL.get_or_init(|| {
+            HashSet::from([
+                DType::Bool,
+                DType::Int,
+                DType::Float,
+                DType::String,
+                DType::DateTime,
+                DType::Date,
+                DType::Time,
+            ])
+        }),
+    }
 }
 
-fn string_types() -> &'static HashSet<DType> {
-    STRING_TYPES_CELL.get_or_init(|| {
-        HashSet::from([
-            DType::Bool,
-            DType::Int,
-            DType::Float,
-            DType::String,
-            DType::DateTime,
-            DType::Date,
-        ])
+/// Returns whether all the cells of a column at the given row indices are null
+pub(crate) fn is_null_column<DT: CellType + Debug + DataType>(
+    data: &Range<DT>,
+    rows: impl IntoIterator<Item = usize>,
+    col: usize,
+    null_values: &NullValues,
+    error_as_null: bool,
+) -> bool {
+    rows.into_iter().all(|row| {
+        matches!(
+            get_cell_dtype(data, row, col, null_values, error_as_null),
+            Ok(DType::Null)
+        )
     })
 }
 
+/// Guesses the dtype of a column by sampling its cells at the given row indices.
+///
+/// Also returns the dtypes observed in these rows (nulls included), which helps understanding why
+/// a dtype was guessed.
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn get_dtype_for_column<DT: CellType + Debug + DataType>(
     data: &Range<DT>,
-    start_row: usize,
-    end_row: usize,
+    rows: impl IntoIterator<Item = usize>,
     col: usize,
     dtype_coercion: &DTypeCoercion,
-) -> FastExcelResult<DType> {
-    let mut column_types = (start_row..end_row)
-        .map(|row| get_cell_dtype(data, row, col))
+    null_values: &NullValues,
+    error_as_null: bool,
+    parse_string_booleans: bool,
+    parse_numeric_strings: bool,
+) -> FastExcelResult<(DType, Vec<DType>)> {
+    let mut sampled_rows = 0;
+    // Whether a string which does not stand for a boolean was found
+    let mut non_boolean_string = false;
+    // Whether a string which is not a number (e.g. "1.2E5") was found
+    let mut non_numeric_string = false;
+    let mut column_types = rows
+        .into_iter()
+        .inspect(|_| sampled_rows += 1)
+        .map(|row| -> FastExcelResult<DType> {
+            let dtype = get_cell_dtype(data, row, col, null_values, error_as_null)?;
+            if dtype == DType::String {
+                let value = data.get((row, col)).and_then(|cell| cell.get_string());
+                if parse_string_booleans {
+                    non_boolean_string |= value.and_then(string_as_bool).is_none();
+                }
+                if parse_numeric_strings {
+                    non_numeric_string |= value.and_then(string_as_float).is_none();
+                }
+            }
+            Ok(dtype)
+        })
         .collect::<FastExcelResult<HashSet<_>>>()?;
 
+    let mut observed_dtypes: Vec<_> = column_types.iter().copied().collect();
+    observed_dtypes.sort();
+
     // All columns are nullable anyway so we're not taking Null into account here
     column_types.remove(&DType::Null);
 
-    if column_types.is_empty() {
+    let dtype = if column_types.is_empty() {
         // If no type apart from NULL was found, fallback to string except if the column is empty
-        if start_row == end_row {
-            Ok(DType::Null)
+        if sampled_rows == 0 {
+            DType::Null
         } else {
             warn!("Could not determine dtype for column {col}, falling back to string");
-            Ok(DType::String)
+            DType::String
         }
+    } else if matches!(dtype_coercion, &DTypeCoercion::RawString) {
+        // Every cell is read as a string, whatever its type
+        DType::String
+    } else if parse_string_booleans
+        && !non_boolean_string
+        && column_types.contains(&DType::String)
+        && column_types
+            .iter()
+            .all(|dtype| matches!(dtype, DType::String | DType::Bool))
+    {
+        // Every string stands for a boolean, e.g. "Yes" or "FALSE"
+        DType::Bool
+    } else if parse_numeric_strings
+        && !non_numeric_string
+        && column_types.contains(&DType::String)
+        && column_types
+            .iter()
+            .all(|dtype| matches!(dtype, DType::String | DType::Int | DType::Float))
+    {
+        // Every string is a number stored as text, e.g. "1.2E5"
+        DType::Float
+    } else if column_types == HashSet::from([DType::DateTime, DType::Time]) {
+        // Times of day are datetimes at the Excel epoch, e.g. a datetime cell at midnight
+        // on 1900-01-00. Both are datetime cells, so this is not a coercion
+        DType::DateTime
     } else if matches!(dtype_coercion, &DTypeCoercion::Strict) && column_types.len() != 1 {
         // If dtype coercion is strict and we do not have a single dtype, it's an error
-        Err(
+        return Err(
             FastExcelErrorKind::UnsupportedColumnTypeCombination(format!(
-                "type coercion is strict and column contains {column_types:?}"
+                "type coercion is strict and column at index {col} contains {:?}",
+                column_types.iter().collect::<BTreeSet<_>>()
             ))
             .into(),
-        )
+        );
     } else if column_types.len() == 1 {
         // If a single non-null type was found, return it
-        Ok(column_types.into_iter().next().unwrap())
-    } else if column_types.is_subset(int_types()) {
+        column_types.into_iter().next().unwrap()
+    } else if column_types.is_subset(int_types(dtype_coercion)) {
         // If every cell in the column can be converted to an int, return int64
-        Ok(DType::Int)
-    } else if column_types.is_subset(float_types()) {
+        DType::Int
+    } else if column_types.is_subset(float_types(dtype_coercion)) {
         // If every cell in the column can be converted to a float, return Float64
-        Ok(DType::Float)
-    } else if column_types.is_subset(string_types()) {
+        DType::Float
+    } else if column_types.is_subset(string_types(dtype_coercion)) {
         // If every cell in the column can be converted to a string, return Utf8
-        Ok(DType::String)
+        DType::String
     } else {
         // NOTE: Not being too smart about multi-types columns for now
-        Err(
-            FastExcelErrorKind::UnsupportedColumnTypeCombination(format!("{column_types:?}"))
-                .into(),
-        )
-    }
+        return Err(
+            FastExcelErrorKind::UnsupportedColumnTypeCombination(format!(
+                "column at index {col} contains {:?}",
+                column_types.iter().collect::<BTreeSet<_>>()
+            ))
+            .into(),
+        );
+    };
+    Ok((dtype, observed_dtypes))
 }
 
+/// Number of digits retained after the decimal point when converting floats to strings, which
+/// matches what Excel displays in a wide cell
+pub(crate) const DEFAULT_FLOAT_STRING_PRECISION: usize = 9;
+
 /// Convert a float to a nice string to mimic Excel behaviour.
 ///
 /// Excel can store a float like 29.02 set by the user as "29.020000000000003" in the XML.
@@ -335,17 +552,37 @@ pub(crate) fn get_dtype_for_column<DT: CellType + Debug + DataType>(
 /// Excel also trims trailing zeros and the decimal point if there is no fractional part.
 ///
 /// We do not distinguish between wide cells and standard cells here, so we retain at most
-/// nine digits after the decimal point and trim any trailing zeros.
-pub(crate) fn excel_float_to_string(x: f64) -> String {
-    format!("{x:.9}")
-        .trim_end_matches('0')
-        .trim_end_matches('.')
-        .to_string()
+/// `precision` digits after the decimal point and trim any trailing zeros.
+pub(crate) fn excel_float_to_string(x: f64, precision: usize) -> String {
+    let formatted = format!("{x:.precision$}");
+    // Without a decimal point (precision 0), trailing zeros belong to the integral part
+    if formatted.contains('.') {
+        formatted
+            .trim_end_matches('0')
+            .trim_end_matches('.')
+            .to_string()
+    } else {
+        formatted
+    }
+}
+
+/// Formats a float the way Excel displays it with a number format made of `width` zeros (e.g.
+/// "00000"): rounded to an integer and padded with leading zeros, e.g. 1234 as "01234". `None`
+/// for floats which cannot be represented as a 64-bit integer.
+pub(crate) fn excel_float_to_zero_padded_string(x: f64, width: usize) -> Option<String> {
+    // i64::MAX is not representable as a float, i64::MAX as f64 is 2^63
+    let rounded = x.round();
+    if !(i64::MIN as f64..i64::MAX as f64).contains(&rounded) {
+        return None;
+    }
+    let rounded = rounded as i64;
+    let sign = if rounded < 0 { "-" } else { "" };
+    Some(format!("{sign}{:0width$}", rounded.unsigned_abs()))
 }
 
 #[cfg(test)]
 mod tests {
-    use calamine::{Cell, Data as CalData};
+    use calamine::{Cell, Data as CalData, ExcelDateTimeType};
     use rstest::{fixture, rstest};
 
     use super::*;
@@ -403,11 +640,55 @@ mod tests {
         #[case] expected: DType,
     ) {
         assert_eq!(
-            get_dtype_for_column(&range, start_row, end_row, 0, &DTypeCoercion::Coerce).unwrap(),
+            get_dtype_for_column(
+                &range,
+                start_row..end_row,
+                0,
+                &DTypeCoercion::Coerce,
+                &NullValues::Default,
+                false,
+                false,
+                false
+            )
+            .unwrap()
+            .0,
             expected
         );
     }
 
+    #[rstest]
+    // pure bool
+    #[case(0, 2)]
+    // pure int
+    #[case(3, 4)]
+    // pure float
+    #[case(4, 5)]
+    // int + float + bool + null
+    #[case(7, 11)]
+    // null + int + float + string + empty + null
+    #[case(2, 8)]
+    fn get_arrow_column_type_raw_string(
+        range: Range<CalData>,
+        #[case] start_row: usize,
+        #[case] end_row: usize,
+    ) {
+        assert_eq!(
+            get_dtype_for_column(
+                &range,
+                start_row..end_row,
+                0,
+                &DTypeCoercion::RawString,
+                &NullValues::Default,
+                false,
+                false,
+                false
+            )
+            .unwrap()
+            .0,
+            DType::String
+        );
+    }
+
     #[rstest]
     // pure bool
     #[case(0, 2, DType::Bool)]
@@ -426,7 +707,18 @@ mod tests {
         #[case] expected: DType,
     ) {
         assert_eq!(
-            get_dtype_for_column(&range, start_row, end_row, 0, &DTypeCoercion::Strict).unwrap(),
+            get_dtype_for_column(
+                &range,
+                start_row..end_row,
+                0,
+                &DTypeCoercion::Strict,
+                &NullValues::Default,
+                false,
+                false,
+                false
+            )
+            .unwrap()
+            .0,
             expected
         );
     }
@@ -451,18 +743,531 @@ mod tests {
         #[case] start_row: usize,
         #[case] end_row: usize,
     ) {
-        let result = get_dtype_for_column(&range, start_row, end_row, 0, &DTypeCoercion::Strict);
+        let result = get_dtype_for_column(
+            &range,
+            start_row..end_row,
+            0,
+            &DTypeCoercion::Strict,
+            &NullValues::Default,
+            false,
+            false,
+            false,
+        );
+        assert!(matches!(
+            result.unwrap_err().kind,
+            FastExcelErrorKind::UnsupportedColumnTypeCombination(_)
+        ));
+    }
+
+    #[rstest]
+    // int + float + string
+    #[case(
+        3,
+        6,
+        &DTypeCoercion::Strict,
+        "type coercion is strict and column at index 0 contains {Int, Float, String}"
+    )]
+    // int + bool
+    #[case(
+        10,
+        12,
+        &DTypeCoercion::StrictNoBoolNumeric,
+        "column at index 0 contains {Int, Bool}"
+    )]
+    fn get_arrow_column_type_multi_dtype_ko_message(
+        range: Range<CalData>,
+        #[case] start_row: usize,
+        #[case] end_row: usize,
+        #[case] dtype_coercion: &DTypeCoercion,
+        #[case] expected_message: &str,
+    ) {
+        let result = get_dtype_for_column(
+            &range,
+            start_row..end_row,
+            0,
+            dtype_coercion,
+            &NullValues::Default,
+            false,
+            false,
+            false,
+        );
+        match result.unwrap_err().kind {
+            FastExcelErrorKind::UnsupportedColumnTypeCombination(message) => {
+                assert_eq!(message, expected_message)
+            }
+            kind => panic!("unexpected error kind: {kind:?}"),
+        }
+    }
+
+    #[rstest]
+    // pure bool
+    #[case(0, 2, DType::Bool)]
+    // null + int + float
+    #[case(2, 5, DType::Float)]
+    // int + float + string
+    #[case(3, 6, DType::String)]
+    // empty + null + int
+    #[case(6, 9, DType::Int)]
+    fn get_arrow_column_type_multi_dtype_ok_strict_no_bool_numeric(
+        range: Range<CalData>,
+        #[case] start_row: usize,
+        #[case] end_row: usize,
+        #[case] expected: DType,
+    ) {
+        assert_eq!(
+            get_dtype_for_column(
+                &range,
+                start_row..end_row,
+                0,
+                &DTypeCoercion::StrictNoBoolNumeric,
+                &NullValues::Default,
+                false,
+                false,
+                false
+            )
+            .unwrap()
+            .0,
+            expected
+        );
+    }
+
+    #[rstest]
+    // bool + int: Int when coercing, error when booleans must not be folded
+    #[case(10, 12)]
+    // bool + null + int + float
+    #[case(1, 5)]
+    // int + float + bool + null
+    #[case(7, 11)]
+    // bool + string
+    #[case(0, 6)]
+    fn get_arrow_column_type_multi_dtype_ko_strict_no_bool_numeric(
+        range: Range<CalData>,
+        #[case] start_row: usize,
+        #[case] end_row: usize,
+    ) {
+        let result = get_dtype_for_column(
+            &range,
+            start_row..end_row,
+            0,
+            &DTypeCoercion::StrictNoBoolNumeric,
+            &NullValues::Default,
+            false,
+            false,
+            false,
+        );
         assert!(matches!(
             result.unwrap_err().kind,
             FastExcelErrorKind::UnsupportedColumnTypeCombination(_)
         ));
     }
 
+    #[rstest]
+    // head is int, tail is string
+    #[case(vec![3, 8, 11, 5], DType::String)]
+    // head is int, tail is int: the float in between is not sampled
+    #[case(vec![3, 8, 11], DType::Int)]
+    // only nulls
+    #[case(vec![2, 6, 7], DType::String)]
+    // nothing sampled
+    #[case(vec![], DType::Null)]
+    fn get_arrow_column_type_for_rows(
+        range: Range<CalData>,
+        #[case] rows: Vec<usize>,
+        #[case] expected: DType,
+    ) {
+        assert_eq!(
+            get_dtype_for_column(
+                &range,
+                rows,
+                0,
+                &DTypeCoercion::Coerce,
+                &NullValues::Default,
+                false,
+                false,
+                false
+            )
+            .unwrap()
+            .0,
+            expected
+        );
+    }
+
+    #[rstest]
+    // int + float + string
+    #[case(3, 6, DType::String, vec![DType::Int, DType::Float, DType::String])]
+    // null + int + float
+    #[case(2, 5, DType::Float, vec![DType::Null, DType::Int, DType::Float])]
+    // only nulls
+    #[case(6, 8, DType::String, vec![DType::Null])]
+    // nothing sampled
+    #[case(0, 0, DType::Null, vec![])]
+    fn get_arrow_column_type_observed_dtypes(
+        range: Range<CalData>,
+        #[case] start_row: usize,
+        #[case] end_row: usize,
+        #[case] expected_dtype: DType,
+        #[case] expected_observed: Vec<DType>,
+    ) {
+        assert_eq!(
+            get_dtype_for_column(
+                &range,
+                start_row..end_row,
+                0,
+                &DTypeCoercion::Coerce,
+                &NullValues::Default,
+                false,
+                false,
+                false
+            )
+            .unwrap(),
+            (expected_dtype, expected_observed)
+        );
+    }
+
+    #[rstest]
+    // null string + empty
+    #[case(vec![2, 6], true)]
+    // null strings + empty
+    #[case(vec![2, 6, 7], true)]
+    // nothing sampled
+    #[case(vec![], true)]
+    // null + int
+    #[case(vec![2, 3], false)]
+    fn test_is_null_column(
+        range: Range<CalData>,
+        #[case] rows: Vec<usize>,
+        #[case] expected: bool,
+    ) {
+        assert_eq!(
+            is_null_column(&range, rows, 0, &NullValues::Default, false),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(CellErrorType::NA)]
+    #[case(CellErrorType::Value)]
+    #[case(CellErrorType::Null)]
+    #[case(CellErrorType::Ref)]
+    #[case(CellErrorType::Num)]
+    #[case(CellErrorType::GettingData)]
+    fn get_arrow_column_type_null_cell_error(
+        #[case] error: CellErrorType,
+        #[values(false, true)] error_as_null: bool,
+    ) {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Float(1.5)),
+            Cell::new((1, 0), CalData::Error(error)),
+        ]);
+        assert_eq!(
+            get_dtype_for_column(
+                &range,
+                0..2,
+                0,
+                &DTypeCoercion::Coerce,
+                &NullValues::Default,
+                error_as_null,
+                false,
+                false
+            )
+            .unwrap(),
+            (DType::Float, vec![DType::Null, DType::Float])
+        );
+    }
+
+    #[rstest]
+    #[case(CellErrorType::Div0)]
+    #[case(CellErrorType::Name)]
+    fn get_arrow_column_type_cell_error(#[case] error: CellErrorType) {
+        let range = Range::from_sparse(vec![
+            Cell::new((0, 0), CalData::Float(1.5)),
+            Cell::new((1, 0), CalData::Error(error.clone())),
+        ]);
+        let err = get_dtype_for_column(
+            &range,
+            0..2,
+            0,
+            &DTypeCoercion::Coerce,
+            &NullValues::Default,
+            false,
+            false,
+            false,
+        )
+        .unwrap_err();
+        assert!(matches!(err.kind, FastExcelErrorKind::CalamineCellError(e) if e == error));
+
+        // Any error is null if errors are read as null
+        assert_eq!(
+            get_dtype_for_column(
+                &range,
+                0..2,
+                0,
+                &DTypeCoercion::Coerce,
+                &NullValues::Default,
+                true,
+                false,
+                false
+            )
+            .unwrap(),
+            (DType::Float, vec![DType::Null, DType::Float])
+        );
+        assert!(is_null_column(&range, 1..2, 0, &NullValues::Default, true));
+        assert!(!is_null_column(
+            &range,
+            1..2,
+            0,
+            &NullValues::Default,
+            false
+        ));
+    }
+
+    #[rstest]
+    // "NULL" is a string again, and "#N/A" is still null
+    #[case(vec!["#N/A".to_string()], vec![2, 3, 7], DType::String)]
+    // no null strings at all
+    #[case(vec![], vec![3, 7], DType::String)]
+    // "hello" becomes null
+    #[case(vec!["hello".to_string()], vec![3, 5], DType::Int)]
+    fn get_arrow_column_type_custom_null_values(
+        range: Range<CalData>,
+        #[case] null_values: Vec<String>,
+        #[case] rows: Vec<usize>,
+        #[case] expected: DType,
+    ) {
+        assert_eq!(
+            get_dtype_for_column(
+                &range,
+                rows,
+                0,
+                &DTypeCoercion::Coerce,
+                &NullValues::Custom(null_values),
+                false,
+                false,
+                false
+            )
+            .unwrap()
+            .0,
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(vec![CalData::String("Yes".to_string()), CalData::String("no".to_string())], DType::Bool)]
+    #[case(vec![CalData::String(" TRUE".to_string()), CalData::Bool(false), CalData::Empty], DType::Bool)]
+    // Unknown strings keep the column a string column
+    #[case(vec![CalData::String("Yes".to_string()), CalData::String("maybe".to_string())], DType::String)]
+    // Numbers and booleans strings are not mixed
+    #[case(vec![CalData::String("Yes".to_string()), CalData::Float(1.0)], DType::String)]
+    fn get_arrow_column_type_string_booleans(#[case] cells: Vec<CalData>, #[case] expected: DType) {
+        let range = Range::from_sparse(
+            cells
+                .into_iter()
+                .enumerate()
+                .map(|(row, cell)| Cell::new((row as u32, 0), cell))
+                .collect(),
+        );
+        let rows = 0..range.height();
+        let dtype_for_column = |parse_string_booleans| {
+            get_dtype_for_column(
+                &range,
+                rows.clone(),
+                0,
+                &DTypeCoercion::Coerce,
+                &NullValues::Default,
+                false,
+                parse_string_booleans,
+                false,
+            )
+            .unwrap()
+            .0
+        };
+
+        assert_eq!(dtype_for_column(true), expected);
+        // Strings are strings unless they are parsed
+        assert_ne!(dtype_for_column(false), DType::Bool);
+    }
+
+    #[rstest]
+    #[case(vec![CalData::String("1.2E5".to_string()), CalData::String("-3".to_string())], DType::Float)]
+    #[case(vec![CalData::String("1.2e-3".to_string()), CalData::Float(1.5), CalData::Int(2), CalData::Empty], DType::Float)]
+    // Strings which are not numbers keep the column a string column
+    #[case(vec![CalData::String("1.2E5".to_string()), CalData::String("abc".to_string())], DType::String)]
+    // Numbers and booleans are not mixed
+    #[case(vec![CalData::String("1.2E5".to_string()), CalData::Bool(true)], DType::String)]
+    fn get_arrow_column_type_numeric_strings(#[case] cells: Vec<CalData>, #[case] expected: DType) {
+        let range = Range::from_sparse(
+            cells
+                .into_iter()
+                .enumerate()
+                .map(|(row, cell)| Cell::new((row as u32, 0), cell))
+                .collect(),
+        );
+        let rows = 0..range.height();
+        let dtype_for_column = |parse_numeric_strings| {
+            get_dtype_for_column(
+                &range,
+                rows.clone(),
+                0,
+                &DTypeCoercion::Coerce,
+                &NullValues::Default,
+                false,
+                false,
+                parse_numeric_strings,
+            )
+            .unwrap()
+            .0
+        };
+
+        assert_eq!(dtype_for_column(true), expected);
+        // Strings are strings unless they are parsed
+        assert_eq!(dtype_for_column(false), DType::String);
+    }
+
+    #[rstest]
+    fn get_arrow_column_type_time_and_datetime(
+        #[values(
+            DTypeCoercion::Coerce,
+            DTypeCoercion::Strict,
+            DTypeCoercion::StrictNoBoolNumeric
+        )]
+        dtype_coercion: DTypeCoercion,
+    ) {
+        // 2023-03-15 at 06:00, then 13:45
+        let range = Range::from_sparse(
+            [45000.25, 0.5729166666666666]
+                .into_iter()
+                .enumerate()
+                .map(|(row, value)| {
+                    Cell::new(
+                        (row as u32, 0),
+                        CalData::DateTime(ExcelDateTime::new(
+                            value,
+                            ExcelDateTimeType::DateTime,
+                            false,
+                        )),
+                    )
+                })
+                .collect(),
+        );
+        let (dtype, observed_dtypes) = get_dtype_for_column(
+            &range,
+            0..2,
+            0,
+            &dtype_coercion,
+            &NullValues::Default,
+            false,
+            false,
+            false,
+        )
+        .unwrap();
+        assert_eq!(dtype, DType::DateTime);
+        assert_eq!(observed_dtypes, vec![DType::DateTime, DType::Time]);
+    }
+
+    #[rstest]
+    // 13:45
+    #[case(0.5729166666666666, ExcelDateTimeType::DateTime, DType::Time)]
+    // midnight
+    #[case(0.0, ExcelDateTimeType::DateTime, DType::Time)]
+    // 2023-03-15 at midnight
+    #[case(45000.0, ExcelDateTimeType::DateTime, DType::DateTime)]
+    // 2023-03-15 at 06:00
+    #[case(45000.25, ExcelDateTimeType::DateTime, DType::DateTime)]
+    // 13:45 elapsed
+    #[case(0.5729166666666666, ExcelDateTimeType::TimeDelta, DType::Duration)]
+    fn get_arrow_column_type_datetime(
+        #[case] value: f64,
+        #[case] datetime_type: ExcelDateTimeType,
+        #[case] expected: DType,
+    ) {
+        let range = Range::from_sparse(vec![Cell::new(
+            (0, 0),
+            CalData::DateTime(ExcelDateTime::new(value, datetime_type, false)),
+        )]);
+        assert_eq!(
+            get_dtype_for_column(
+                &range,
+                0..1,
+                0,
+                &DTypeCoercion::Coerce,
+                &NullValues::Default,
+                false,
+                false,
+                false
+            )
+            .unwrap()
+            .0,
+            expected
+        );
+    }
+
     #[rstest]
     #[case(29.020000000000003, "29.02")]
     #[case(10000_f64, "10000")]
     #[case(23.0, "23")]
+    #[case(1000_f64, "1000")]
+    #[case(-0.5, "-0.5")]
     fn test_excel_float_to_string(#[case] x: f64, #[case] expected: &str) {
-        assert_eq!(excel_float_to_string(x), expected.to_string());
+        assert_eq!(
+            excel_float_to_string(x, DEFAULT_FLOAT_STRING_PRECISION),
+            expected.to_string()
+        );
+    }
+
+    #[rstest]
+    #[case(29.020000000000003, 2, "29.02")]
+    #[case(29.020000000000003, 15, "29.020000000000003")]
+    #[case(1.123456789123, 12, "1.123456789123")]
+    #[case(1.123456789123, 9, "1.123456789")]
+    #[case(1.125, 0, "1")]
+    #[case(1000_f64, 0, "1000")]
+    #[case(1000_f64, 2, "1000")]
+    fn test_excel_float_to_string_with_precision(
+        #[case] x: f64,
+        #[case] precision: usize,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(excel_float_to_string(x, precision), expected.to_string());
+    }
+
+    #[rstest]
+    #[case(1234.0, 5, Some("01234"))]
+    #[case(12345.0, 5, Some("12345"))]
+    #[case(123456.0, 5, Some("123456"))]
+    #[case(12.5, 3, Some("013"))]
+    #[case(-42.0, 4, Some("-0042"))]
+    #[case(f64::NAN, 5, None)]
+    #[case(1e20, 5, None)]
+    fn test_excel_float_to_zero_padded_string(
+        #[case] x: f64,
+        #[case] width: usize,
+        #[case] expected: Option<&str>,
+    ) {
+        assert_eq!(
+            excel_float_to_zero_padded_string(x, width).as_deref(),
+            expected
+        );
+    }
+
+    #[rstest]
+    #[case(DType::Null)]
+    #[case(DType::Int)]
+    #[case(DType::Float)]
+    #[case(DType::String)]
+    #[case(DType::Bool)]
+    #[case(DType::DateTime)]
+    #[case(DType::Date)]
+    #[case(DType::Duration)]
+    #[case(DType::Time)]
+    fn dtype_from_arrow_data_type(#[case] dtype: DType) {
+        assert_eq!(
+            DType::try_from(&ArrowDataType::from(&dtype)).unwrap(),
+            dtype
+        );
+    }
+
+    #[test]
+    fn dtype_from_unsupported_arrow_data_type() {
+        assert!(DType::try_from(&ArrowDataType::Int32).is_err());
     }
 }
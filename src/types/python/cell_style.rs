@@ -0,0 +1,43 @@
+use pyo3::{pyclass, pymethods};
+
+use crate::utils::xlsx;
+
+/// This class provides the formatting of a cell, as returned by `ExcelReader.cell_styles`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[pyclass(name = "CellStyle")]
+pub(crate) struct CellStyle {
+    /// `str | None`. The ARGB hex color of the cell's fill (e.g. `"FFFF0000"` for red), if it
+    /// is defined as an RGB value
+    #[pyo3(get)]
+    fill_color: Option<String>,
+    /// `bool`. Whether the cell's font is bold
+    #[pyo3(get)]
+    bold: bool,
+}
+
+impl From<xlsx::CellStyle> for CellStyle {
+    fn from(style: xlsx::CellStyle) -> Self {
+        Self {
+            fill_color: style.fill_color,
+            bold: style.bold,
+        }
+    }
+}
+
+#[pymethods]
+impl CellStyle {
+    pub fn __repr__(&self) -> String {
+        format!(
+            "CellStyle(fill_color={fill_color}, bold={bold})",
+            fill_color = self
+                .fill_color
+                .as_ref()
+                .map_or_else(|| "None".to_owned(), |color| format!("\"{color}\"")),
+            bold = self.bold,
+        )
+    }
+
+    pub fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+}
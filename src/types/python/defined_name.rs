@@ -0,0 +1,47 @@
+use pyo3::{pyclass, pymethods};
+
+/// This class provides a defined name of a workbook along with its scope, as returned by
+/// `ExcelReader.defined_names_with_scope`
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[pyclass(name = "DefinedName")]
+pub(crate) struct DefinedName {
+    /// `str`. The name
+    #[pyo3(get)]
+    name: String,
+    /// `str`. The formula the name refers to, as is (e.g. `"Sheet1!$A$1:$B$10"`)
+    #[pyo3(get)]
+    formula: String,
+    /// `str | None`. The name of the sheet the name is local to, or `None` if the name belongs to
+    /// the whole workbook. Sheet-local names can be defined once per sheet
+    #[pyo3(get)]
+    scope: Option<String>,
+}
+
+impl DefinedName {
+    pub(crate) fn new(name: String, formula: String, scope: Option<String>) -> Self {
+        Self {
+            name,
+            formula,
+            scope,
+        }
+    }
+}
+
+#[pymethods]
+impl DefinedName {
+    pub fn __repr__(&self) -> String {
+        format!(
+            "DefinedName(name=\"{name}\", formula=\"{formula}\", scope={scope})",
+            name = self.name,
+            formula = self.formula,
+            scope = self
+                .scope
+                .as_ref()
+                .map_or_else(|| "None".to_owned(), |scope| format!("\"{scope}\"")),
+        )
+    }
+
+    pub fn __eq__(&self, other: &Self) -> bool {
+        self == other
+    }
+}
@@ -1,47 +1,192 @@
 use std::{
+    collections::VecDeque,
+    fmt::Display,
     fs::File,
-    io::{BufReader, Cursor},
+    io::{BufReader, Cursor, Read, Seek},
+    str::FromStr,
+    sync::{Arc, OnceLock},
 };
 
 use arrow::{pyarrow::ToPyArrow, record_batch::RecordBatch};
-use pyo3::{prelude::PyObject, pyclass, pymethods, Bound, IntoPy, PyAny, PyResult, Python};
+use log::warn;
+use pyo3::{
+    prelude::{PyAnyMethods, PyObject},
+    pyclass, pymethods, Bound, FromPyObject, IntoPy, PyAny, PyResult, Python, ToPyObject,
+};
 
 use calamine::{
-    open_workbook_auto, open_workbook_auto_from_rs, Data, DataRef, HeaderRow, Range, Reader,
-    ReaderRef, Sheet as CalamineSheet, Sheets, Table,
+    open_workbook, open_workbook_auto, open_workbook_auto_from_rs, open_workbook_from_rs, CellType,
+    Data, DataRef, DataType, HeaderRow, Range, Reader, ReaderRef, Sheet as CalamineSheet,
+    SheetVisible as CalamineSheetVisible, Sheets, Table,
 };
 
 use crate::{
-    data::{record_batch_from_data_and_columns, ExcelSheetData},
+    data::{pad_numbers_with_zeros, record_batch_from_data_and_columns, ExcelSheetData},
     error::{
         py_errors::IntoPyResult, ErrorContext, FastExcelError, FastExcelErrorKind, FastExcelResult,
     },
     types::{
-        dtype::{DTypeCoercion, DTypes},
         idx_or_name::IdxOrName,
-        python::excelsheet::table::{extract_table_names, extract_table_range},
+        python::{
+            cell_style::CellStyle,
+            defined_name::DefinedName,
+            excelsheet::table::{extract_table_names, extract_table_range},
+            table::TableInfo,
+            workbook_metadata::WorkbookMetadata,
+        },
+    },
+    utils::{
+        encryption::{is_encrypted_workbook, is_encrypted_workbook_at},
+        xlsx,
     },
-    utils::schema::get_schema_sample_rows,
 };
 
-use pyo3::types::PyString;
+use pyo3::types::{PyDict, PyString};
 
 use super::excelsheet::{
-    column_info::{build_available_columns, build_available_columns_info},
-    ExcelSheet, Header, Pagination, SelectedColumns,
+    column_info::{
+        available_column_names, finalize_column_info, multi_row_header_name,
+        DuplicateColumnStrategy, DEFAULT_UNNAMED_COLUMN_PREFIX,
+    },
+    concat_sheets,
+    excel_range::{ExcelRange, SheetReference},
+    read_hidden_columns, ExcelSheet, Header, LoadSheetOrTableOptions, Pagination, SelectedColumns,
+    SelectedHeaderRow, SheetVisible,
 };
 use super::table::ExcelTable;
 
+/// Makes absolute column indices relative to the first column of a range, dropping the columns
+/// which are outside of it
+fn columns_relative_to<DT: CellType>(columns: Vec<usize>, range: &Range<DT>) -> Vec<usize> {
+    let start_col = range.start().map_or(0, |(_, col)| col as usize);
+    let width = range.width();
+    columns
+        .into_iter()
+        .filter_map(|col| col.checked_sub(start_col))
+        .filter(|col| *col < width)
+        .collect()
+}
+
+/// The workbook formats supported by calamine. Allows to bypass format auto-detection, for
+/// example when a file has the wrong extension
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum WorkbookFormat {
+    Xlsx,
+    Xls,
+    Xlsb,
+    Ods,
+}
+
+impl WorkbookFormat {
+    fn open_workbook(&self, path: &str) -> Result<Sheets<BufReader<File>>, calamine::Error> {
+        Ok(match self {
+            Self::Xlsx => Sheets::Xlsx(open_workbook(path)?),
+            Self::Xls => Sheets::Xls(open_workbook(path)?),
+            Self::Xlsb => Sheets::Xlsb(open_workbook(path)?),
+            Self::Ods => Sheets::Ods(open_workbook(path)?),
+        })
+    }
+
+    fn open_workbook_from_rs<RS: Read + Seek>(
+        &self,
+        reader: RS,
+    ) -> Result<Sheets<RS>, calamine::Error> {
+        Ok(match self {
+            Self::Xlsx => Sheets::Xlsx(open_workbook_from_rs(reader)?),
+            Self::Xls => Sheets::Xls(open_workbook_from_rs(reader)?),
+            Self::Xlsb => Sheets::Xlsb(open_workbook_from_rs(reader)?),
+            Self::Ods => Sheets::Ods(open_workbook_from_rs(reader)?),
+        })
+    }
+}
+
+impl FromStr for WorkbookFormat {
+    type Err = FastExcelError;
+
+    fn from_str(raw_format: &str) -> FastExcelResult<Self> {
+        match raw_format {
+            "xlsx" => Ok(Self::Xlsx),
+            "xls" => Ok(Self::Xls),
+            "xlsb" => Ok(Self::Xlsb),
+            "ods" => Ok(Self::Ods),
+            _ => Err(FastExcelErrorKind::InvalidParameters(format!(
+                "unsupported workbook format: \"{raw_format}\""
+            ))
+            .into()),
+        }
+    }
+}
+
+impl Display for WorkbookFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            WorkbookFormat::Xlsx => "xlsx",
+            WorkbookFormat::Xls => "xls",
+            WorkbookFormat::Xlsb => "xlsb",
+            WorkbookFormat::Ods => "ods",
+        })
+    }
+}
+
+impl FromPyObject<'_> for WorkbookFormat {
+    fn extract_bound(py_format: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(format_pystr) = py_format.extract::<String>() {
+            format_pystr.parse()
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "{py_format:?} cannot be converted to str"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
+/// A seekable source a workbook can be read from. It must be cloneable, as calamine clones the
+/// source for every format it tries when guessing the format of a workbook
+pub(crate) trait ReadSeek: Read + Seek + Send {
+    fn clone_box(&self) -> Box<dyn ReadSeek>;
+}
+
+impl<T: Read + Seek + Send + Clone + 'static> ReadSeek for T {
+    fn clone_box(&self) -> Box<dyn ReadSeek> {
+        Box::new(self.clone())
+    }
+}
+
+impl Clone for Box<dyn ReadSeek> {
+    fn clone(&self) -> Self {
+        // Dereferencing, as the box itself implements ReadSeek
+        (**self).clone_box()
+    }
+}
+
 enum ExcelSheets {
     File(Sheets<BufReader<File>>),
-    Bytes(Sheets<Cursor<Vec<u8>>>),
+    Bytes(Sheets<Cursor<Arc<[u8]>>>),
+    Reader(Sheets<Box<dyn ReadSeek>>),
+    /// The reader was closed, which released its file handle or buffer
+    Closed,
 }
 
 impl ExcelSheets {
+    fn closed_error() -> FastExcelError {
+        FastExcelErrorKind::InvalidParameters("reader is closed".to_string()).into()
+    }
+
+    fn ensure_open(&self) -> FastExcelResult<()> {
+        match self {
+            Self::Closed => Err(Self::closed_error()),
+            _ => Ok(()),
+        }
+    }
+
     fn worksheet_range(&mut self, name: &str) -> FastExcelResult<Range<Data>> {
         match self {
             Self::File(sheets) => sheets.worksheet_range(name),
             Self::Bytes(sheets) => sheets.worksheet_range(name),
+            Self::Reader(sheets) => sheets.worksheet_range(name),
+            Self::Closed => return Err(Self::closed_error()),
         }
         .map_err(|err| FastExcelErrorKind::CalamineError(err).into())
         .with_context(|| format!("Error while loading sheet {name}"))
@@ -52,6 +197,8 @@ impl ExcelSheets {
         match self {
             ExcelSheets::File(sheets) => sheets.sheets_metadata(),
             ExcelSheets::Bytes(sheets) => sheets.sheets_metadata(),
+            ExcelSheets::Reader(sheets) => sheets.sheets_metadata(),
+            ExcelSheets::Closed => &[],
         }
     }
 
@@ -63,13 +210,23 @@ impl ExcelSheets {
             Self::Bytes(sheets) => {
                 extract_table_names(sheets, sheet_name)?.map(|v| v.into_iter().cloned().collect())
             }
+            Self::Reader(sheets) => {
+                extract_table_names(sheets, sheet_name)?.map(|v| v.into_iter().cloned().collect())
+            }
+            Self::Closed => Err(Self::closed_error()),
         }
     }
 
     fn supports_by_ref(&self) -> bool {
+        self.is_xlsx()
+    }
+
+    fn is_xlsx(&self) -> bool {
         matches!(
             self,
-            Self::File(Sheets::Xlsx(_)) | Self::Bytes(Sheets::Xlsx(_))
+            Self::File(Sheets::Xlsx(_))
+                | Self::Bytes(Sheets::Xlsx(_))
+                | Self::Reader(Sheets::Xlsx(_))
         )
     }
 
@@ -83,6 +240,11 @@ impl ExcelSheets {
                 sheets.with_header_row(header_row);
                 self
             }
+            Self::Reader(ref mut sheets) => {
+                sheets.with_header_row(header_row);
+                self
+            }
+            Self::Closed => self,
         }
     }
 
@@ -90,6 +252,8 @@ impl ExcelSheets {
         match self {
             ExcelSheets::File(Sheets::Xlsx(sheets)) => Ok(sheets.worksheet_range_ref(name)?),
             ExcelSheets::Bytes(Sheets::Xlsx(sheets)) => Ok(sheets.worksheet_range_ref(name)?),
+            ExcelSheets::Reader(Sheets::Xlsx(sheets)) => Ok(sheets.worksheet_range_ref(name)?),
+            ExcelSheets::Closed => Err(Self::closed_error()),
             _ => Err(FastExcelErrorKind::Internal(
                 "sheets do not support worksheet_range_ref".to_string(),
             )
@@ -98,11 +262,87 @@ impl ExcelSheets {
         .with_context(|| format!("Error while loading sheet {name}"))
     }
 
+    fn worksheet_formula(&mut self, name: &str) -> FastExcelResult<Range<String>> {
+        match self {
+            Self::File(sheets) => sheets.worksheet_formula(name),
+            Self::Bytes(sheets) => sheets.worksheet_formula(name),
+            Self::Reader(sheets) => sheets.worksheet_formula(name),
+            Self::Closed => return Err(Self::closed_error()),
+        }
+        .map_err(|err| FastExcelErrorKind::CalamineError(err).into())
+        .with_context(|| format!("Error while loading formulas of sheet {name}"))
+    }
+
+    fn defined_names(&self) -> FastExcelResult<&[(String, String)]> {
+        match self {
+            Self::File(sheets) => Ok(sheets.defined_names()),
+            Self::Bytes(sheets) => Ok(sheets.defined_names()),
+            Self::Reader(sheets) => Ok(sheets.defined_names()),
+            Self::Closed => Err(Self::closed_error()),
+        }
+    }
+
     fn get_table(&mut self, name: &str) -> FastExcelResult<Table<Data>> {
         match self {
             Self::File(sheets) => extract_table_range(name, sheets)?,
             Self::Bytes(sheets) => extract_table_range(name, sheets)?,
+            Self::Reader(sheets) => extract_table_range(name, sheets)?,
+            Self::Closed => Err(Self::closed_error()),
+        }
+    }
+}
+
+/// Sheet name and header row of a cached range
+type RangeCacheKey = (String, Option<u32>);
+
+/// The most recently read worksheet ranges, keyed by sheet name and header row, so that loading a
+/// sheet again (e.g. with different options) does not parse it again. Ranges are shared with the
+/// sheets loaded from them.
+///
+/// Every cached range holds all the cells of a sheet, which is why the cache is disabled unless a
+/// capacity is passed to `read_excel`.
+#[derive(Default)]
+struct RangeCache {
+    capacity: usize,
+    entries: VecDeque<(RangeCacheKey, Arc<Range<Data>>)>,
+}
+
+impl RangeCache {
+    /// `HeaderRow` cannot be compared, and only these two variants are used
+    fn key(sheet_name: &str, header_row: HeaderRow) -> RangeCacheKey {
+        let row = match header_row {
+            HeaderRow::Row(row) => Some(row),
+            _ => None,
+        };
+        (sheet_name.to_owned(), row)
+    }
+
+    fn get(&mut self, sheet_name: &str, header_row: HeaderRow) -> Option<Arc<Range<Data>>> {
+        let key = Self::key(sheet_name, header_row);
+        let pos = self
+            .entries
+            .iter()
+            .position(|(entry_key, _)| entry_key == &key)?;
+        // Moved to the back, as the most recently used entry
+        let entry = self.entries.remove(pos)?;
+        let range = Arc::clone(&entry.1);
+        self.entries.push_back(entry);
+        Some(range)
+    }
+
+    fn insert(&mut self, sheet_name: &str, header_row: HeaderRow, range: Arc<Range<Data>>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
         }
+        self.entries
+            .push_back((Self::key(sheet_name, header_row), range));
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
     }
 }
 
@@ -111,41 +351,230 @@ pub(crate) struct ExcelReader {
     sheets: ExcelSheets,
     sheet_metadata: Vec<CalamineSheet>,
     source: String,
+    /// The workbook's content, if it was read from bytes. Shared with `sheets`
+    content: Option<Arc<[u8]>>,
+    range_cache: RangeCache,
+    /// The (sheet name, code name) of the sheets which have a code name, read once
+    code_names: OnceLock<Vec<(String, String)>>,
+}
+
+/// calamine fails opaquely on encrypted workbooks, so its error is replaced with a dedicated one
+/// when the workbook turns out to be encrypted
+fn open_workbook_error(
+    err: calamine::Error,
+    is_encrypted: impl FnOnce() -> bool,
+) -> FastExcelError {
+    if is_encrypted() {
+        FastExcelErrorKind::EncryptedWorkbook.into()
+    } else {
+        FastExcelErrorKind::CalamineError(err).into()
+    }
 }
 
 impl ExcelReader {
     fn build_selected_columns(
         use_columns: Option<&Bound<'_, PyAny>>,
+        exclude_columns: Option<Vec<IdxOrName>>,
     ) -> FastExcelResult<SelectedColumns> {
-        use_columns.try_into().with_context(|| format!("expected selected columns to be list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None, got {use_columns:?}"))
+        match exclude_columns {
+            None => use_columns.try_into().with_context(|| format!("expected selected columns to be list[str] | list[int] | str | Callable[[ColumnInfo], bool] | None, got {use_columns:?}")),
+            Some(_) if use_columns.is_some() => Err(FastExcelErrorKind::InvalidParameters(
+                "use_columns and exclude_columns cannot be used together".to_string(),
+            )
+            .into()),
+            Some(excluded) => Ok(SelectedColumns::Exclude(excluded)),
+        }
     }
 
     // NOTE: Not implementing TryFrom here, because we're aren't building the file from the passed
     // string, but rather from the file pointed by it. Semantically, try_from_path is clearer
     pub(crate) fn try_from_path(path: &str) -> FastExcelResult<Self> {
         let sheets = open_workbook_auto(path)
-            .map_err(|err| FastExcelErrorKind::CalamineError(err).into())
+            .map_err(|err| open_workbook_error(err, || is_encrypted_workbook_at(path)))
             .with_context(|| format!("Could not open workbook at {path}"))?;
         let sheet_metadata = sheets.sheets_metadata().to_owned();
         Ok(Self {
             sheets: ExcelSheets::File(sheets),
             sheet_metadata,
             source: path.to_owned(),
+            content: None,
+            range_cache: RangeCache::default(),
+            code_names: OnceLock::new(),
+        })
+    }
+
+    fn sheet_name_not_found(&self, name: String) -> FastExcelError {
+        let available_sheets = self
+            .sheet_metadata
+            .iter()
+            .map(|s| format!("\"{}\"", s.name))
+            .collect::<Vec<_>>()
+            .join(", ");
+        FastExcelError::from(FastExcelErrorKind::SheetNotFound(IdxOrName::Name(
+            name.clone(),
+        )))
+        .with_context(|| {
+            format!("Sheet \"{name}\" not found in file. Available sheets: {available_sheets}.")
+        })
+    }
+
+    /// Looks up the metadata of a sheet by index or by name.
+    ///
+    /// A name is either the name of the sheet or its code name, which does not change when the
+    /// sheet is renamed. If `case_insensitive` is true and no name matches exactly, falls back on
+    /// the only sheet whose name matches case-insensitively. It is an error for several sheets to
+    /// match.
+    fn find_sheet_meta(
+        &self,
+        idx_or_name: IdxOrName,
+        case_insensitive: bool,
+    ) -> FastExcelResult<&CalamineSheet> {
+        match idx_or_name {
+            IdxOrName::Name(name) => {
+                if let Some(sheet) = self.sheet_metadata.iter().find(|s| s.name == name) {
+                    return Ok(sheet);
+                }
+                if let Some(sheet_name) = self.sheet_with_code_name(&name) {
+                    if let Some(sheet) = self.sheet_metadata.iter().find(|s| s.name == sheet_name) {
+                        return Ok(sheet);
+                    }
+                }
+                if !case_insensitive {
+                    return Err(self.sheet_name_not_found(name));
+                }
+                let lowercase_name = name.to_lowercase();
+                let candidates = self
+                    .sheet_metadata
+                    .iter()
+                    .filter(|s| s.name.to_lowercase() == lowercase_name)
+                    .collect::<Vec<_>>();
+                match candidates.as_slice() {
+                    [] => Err(self.sheet_name_not_found(name)),
+                    [sheet] => Ok(*sheet),
+                    _ => {
+                        let candidate_names = candidates
+                            .iter()
+                            .map(|s| format!("\"{}\"", s.name))
+                            .collect::<Vec<_>>()
+                            .join(", ");
+                        Err(FastExcelErrorKind::InvalidParameters(format!(
+                            "sheet name \"{name}\" is ambiguous, several sheets match it case-insensitively: {candidate_names}"
+                        ))
+                        .into())
+                    }
+                }
+            }
+            IdxOrName::Idx(idx) => self
+                .sheet_metadata
+                .get(idx)
+                .ok_or_else(|| FastExcelErrorKind::SheetNotFound(IdxOrName::Idx(idx)).into())
+                .with_context(|| {
+                    format!(
+                        "Sheet index {idx} is out of range. File has {} sheets.",
+                        self.sheet_metadata.len()
+                    )
+                }),
+        }
+    }
+
+    /// Opens the workbook at `path` with the calamine reader matching `format`, rather than
+    /// guessing the format from the file's extension
+    pub(crate) fn try_from_path_as(path: &str, format: WorkbookFormat) -> FastExcelResult<Self> {
+        let sheets = format
+            .open_workbook(path)
+            .map_err(|err| open_workbook_error(err, || is_encrypted_workbook_at(path)))
+            .with_context(|| format!("Could not open workbook at {path} as {format}"))?;
+        let sheet_metadata = sheets.sheets_metadata().to_owned();
+        Ok(Self {
+            sheets: ExcelSheets::File(sheets),
+            sheet_metadata,
+            source: path.to_owned(),
+            content: None,
+            range_cache: RangeCache::default(),
+            code_names: OnceLock::new(),
+        })
+    }
+
+    /// Same as `try_from_path_as`, but for a workbook's content
+    pub(crate) fn try_from_bytes_as(
+        content: Arc<[u8]>,
+        format: WorkbookFormat,
+    ) -> FastExcelResult<Self> {
+        let cursor = Cursor::new(Arc::clone(&content));
+        let sheets = format
+            .open_workbook_from_rs(cursor)
+            .map_err(|err| open_workbook_error(err, || is_encrypted_workbook(&content)))
+            .with_context(|| format!("Could not open workbook from bytes as {format}"))?;
+        let sheet_metadata = sheets.sheets_metadata().to_owned();
+        Ok(Self {
+            sheets: ExcelSheets::Bytes(sheets),
+            sheet_metadata,
+            source: "bytes".to_owned(),
+            content: Some(content),
+            range_cache: RangeCache::default(),
+            code_names: OnceLock::new(),
         })
     }
 
+    /// Opens a workbook from an arbitrary seekable source, guessing its format from its content.
+    /// The reader is kept until the `ExcelReader` is dropped, as sheets are read lazily
+    pub(crate) fn try_from_reader<R: Read + Seek + Send + Clone + 'static>(
+        reader: R,
+    ) -> FastExcelResult<Self> {
+        let reader: Box<dyn ReadSeek> = Box::new(reader);
+        let sheets = open_workbook_auto_from_rs(reader)
+            .map_err(|err| FastExcelErrorKind::CalamineError(err).into())
+            .with_context(|| "Could not open workbook from reader")?;
+        Ok(Self::from_reader_sheets(sheets))
+    }
+
+    /// Same as `try_from_reader`, but with the calamine reader matching `format`
+    pub(crate) fn try_from_reader_as<R: Read + Seek + Send + Clone + 'static>(
+        reader: R,
+        format: WorkbookFormat,
+    ) -> FastExcelResult<Self> {
+        let reader: Box<dyn ReadSeek> = Box::new(reader);
+        let sheets = format
+            .open_workbook_from_rs(reader)
+            .map_err(|err| FastExcelErrorKind::CalamineError(err).into())
+            .with_context(|| format!("Could not open workbook from reader as {format}"))?;
+        Ok(Self::from_reader_sheets(sheets))
+    }
+
+    fn from_reader_sheets(sheets: Sheets<Box<dyn ReadSeek>>) -> Self {
+        let sheet_metadata = sheets.sheets_metadata().to_owned();
+        Self {
+            sheets: ExcelSheets::Reader(sheets),
+            sheet_metadata,
+            source: "reader".to_owned(),
+            content: None,
+            range_cache: RangeCache::default(),
+            code_names: OnceLock::new(),
+        }
+    }
+
+    /// Keeps up to `size` worksheet ranges in memory, to avoid parsing a sheet again when it is
+    /// loaded several times
+    pub(crate) fn with_range_cache_size(mut self, size: usize) -> Self {
+        self.range_cache.capacity = size;
+        self
+    }
+
     fn load_sheet_eager(
         data: &ExcelSheetData,
         pagination: Pagination,
         header: Header,
-        sample_rows: Option<usize>,
         selected_columns: &SelectedColumns,
-        dtypes: Option<&DTypes>,
-        dtype_coercion: &DTypeCoercion,
+        hidden_columns: &[usize],
+        options: &LoadSheetOrTableOptions,
     ) -> FastExcelResult<RecordBatch> {
         let offset = header.offset() + pagination.offset();
         let limit = {
-            let upper_bound = data.height();
+            let upper_bound = options
+                .stop_at_empty_row
+                .then(|| data.first_empty_row(offset))
+                .flatten()
+                .unwrap_or_else(|| data.height());
             if let Some(n_rows) = pagination.n_rows() {
                 // minimum value between (offset+n_rows) and the data's height
                 std::cmp::min(offset + n_rows, upper_bound)
@@ -154,90 +583,208 @@ impl ExcelReader {
             }
         };
 
-        let sample_rows_limit = get_schema_sample_rows(sample_rows, offset, limit);
-        let available_columns_info = build_available_columns_info(data, selected_columns, &header)?;
-
-        let available_columns = build_available_columns(
-            available_columns_info,
+        let sample_rows = options.schema_sample_row_ranges(offset, limit);
+        let (_, final_columns) = finalize_column_info(
             data,
-            offset,
-            sample_rows_limit,
-            dtypes,
-            dtype_coercion,
+            &header,
+            selected_columns,
+            &sample_rows,
+            offset..limit,
+            hidden_columns,
+            options,
         )?;
 
-        let final_columns = selected_columns.select_columns(&available_columns)?;
+        record_batch_from_data_and_columns(&final_columns, data, offset, offset, limit, options)
+    }
+
+    /// calamine `header_row` is the first row of the range to be read.
+    /// For us `header_row` can be `None` (meaning there is no header and we should start reading
+    /// the data at the beginning)
+    fn calamine_header_row(
+        header_row: Option<SelectedHeaderRow>,
+        skip_rows: Option<usize>,
+    ) -> HeaderRow {
+        match (header_row, skip_rows) {
+            (None, None)
+            | (Some(SelectedHeaderRow::Row(0)), None)
+            | (Some(SelectedHeaderRow::Rows { start: 0, .. }), None)
+            | (Some(SelectedHeaderRow::Auto), _) => HeaderRow::FirstNonEmptyRow,
+            (None, Some(_)) => HeaderRow::Row(0),
+            (Some(SelectedHeaderRow::Row(row)), _)
+            | (Some(SelectedHeaderRow::Rows { start: row, .. }), _) => HeaderRow::Row(row as u32),
+        }
+    }
+
+    /// Reads the range of a sheet with the given header row, reusing it if it was read recently
+    fn worksheet_range(
+        &mut self,
+        name: &str,
+        header_row: HeaderRow,
+    ) -> FastExcelResult<Arc<Range<Data>>> {
+        if let Some(range) = self.range_cache.get(name, header_row) {
+            return Ok(range);
+        }
+        let range = Arc::new(
+            self.sheets
+                .with_header_row(header_row)
+                .worksheet_range(name)?,
+        );
+        self.range_cache
+            .insert(name, header_row, Arc::clone(&range));
+        Ok(range)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build_lazy_sheet(
+        &mut self,
+        sheet_meta: CalamineSheet,
+        calamine_header_row: HeaderRow,
+        header_row: Option<SelectedHeaderRow>,
+        column_names: Option<Vec<String>>,
+        skip_rows: Option<usize>,
+        n_rows: Option<usize>,
+        selected_columns: SelectedColumns,
+        options: LoadSheetOrTableOptions,
+    ) -> FastExcelResult<ExcelSheet> {
+        let hidden_columns = self.hidden_columns_if_skipped(&sheet_meta.name, &options)?;
+        let zero_padded_cells = self.zero_padded_cells(&sheet_meta.name, &options)?;
+        let mut range = self.worksheet_range(&sheet_meta.name, calamine_header_row)?;
+        if !zero_padded_cells.is_empty() {
+            // Only copies the range if it is cached
+            pad_numbers_with_zeros(Arc::make_mut(&mut range), &zero_padded_cells, Data::String);
+        }
+        let pagination = Pagination::new(skip_rows.unwrap_or(0), n_rows, &range)?;
+        let header = Header::for_sheet(header_row, column_names, &range);
+        let hidden_columns = hidden_columns.map(|columns| columns_relative_to(columns, &range));
+        ExcelSheet::try_new(
+            sheet_meta,
+            range.into(),
+            header,
+            pagination,
+            selected_columns,
+            hidden_columns,
+            options,
+        )
+        .map(|sheet| sheet.with_xlsx_source(self.xlsx_source()))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build_lazy_sheets(
+        &mut self,
+        idxs_or_names: Vec<IdxOrName>,
+        case_insensitive_sheet_name: bool,
+        header_row: Option<SelectedHeaderRow>,
+        column_names: Option<Vec<String>>,
+        skip_rows: Option<usize>,
+        n_rows: Option<usize>,
+        use_columns: Option<&Bound<'_, PyAny>>,
+        exclude_columns: Option<Vec<IdxOrName>>,
+        options: &LoadSheetOrTableOptions,
+    ) -> FastExcelResult<Vec<ExcelSheet>> {
+        // All sheets are looked up before any of them is loaded
+        let sheet_metas = idxs_or_names
+            .into_iter()
+            .map(|idx_or_name| {
+                self.find_sheet_meta(idx_or_name, case_insensitive_sheet_name)
+                    .cloned()
+            })
+            .collect::<FastExcelResult<Vec<_>>>()?;
 
-        record_batch_from_data_and_columns(&final_columns, data, offset, limit)
+        let calamine_header_row = Self::calamine_header_row(header_row, skip_rows);
+        sheet_metas
+            .into_iter()
+            .map(|sheet_meta| {
+                let sheet_name = sheet_meta.name.clone();
+                Self::build_selected_columns(use_columns, exclude_columns.clone())
+                    .and_then(|selected_columns| {
+                        options.validate(
+                            header_row.is_some(),
+                            column_names.as_deref(),
+                            &selected_columns,
+                        )?;
+                        self.build_lazy_sheet(
+                            sheet_meta,
+                            calamine_header_row,
+                            header_row,
+                            column_names.clone(),
+                            skip_rows,
+                            n_rows,
+                            selected_columns,
+                            options.clone(),
+                        )
+                    })
+                    .with_context(|| format!("could not load sheet \"{sheet_name}\""))
+            })
+            .collect()
     }
 
     #[allow(clippy::too_many_arguments)]
     fn build_sheet(
         &mut self,
         sheet_meta: CalamineSheet,
-        header_row: Option<usize>,
+        header_row: Option<SelectedHeaderRow>,
         column_names: Option<Vec<String>>,
         skip_rows: Option<usize>,
         n_rows: Option<usize>,
-        schema_sample_rows: Option<usize>,
-        dtype_coercion: DTypeCoercion,
         use_columns: Option<&Bound<'_, PyAny>>,
-        dtypes: Option<DTypes>,
+        exclude_columns: Option<Vec<IdxOrName>>,
+        options: LoadSheetOrTableOptions,
         eager: bool,
         py: Python<'_>,
     ) -> PyResult<PyObject> {
-        // calamine `header_row` is the first row of the range to be read.
-        // For us `header_row` can be `None` (meaning there is no header and we should start reading
-        // the data at the beginning)
-        let calamine_header_row = match (header_row, skip_rows) {
-            (None, None) | (Some(0), None) => HeaderRow::FirstNonEmptyRow,
-            (None, Some(_)) => HeaderRow::Row(0),
-            (Some(row), _) => HeaderRow::Row(row as u32),
-        };
-        // And our header row is simply the first row of the data if defined.
-        let data_header_row = header_row.and(Some(0));
-
-        let header = Header::new(data_header_row, column_names);
-        let selected_columns = Self::build_selected_columns(use_columns).into_pyresult()?;
+        let calamine_header_row = Self::calamine_header_row(header_row, skip_rows);
+        let selected_columns =
+            Self::build_selected_columns(use_columns, exclude_columns).into_pyresult()?;
+        options
+            .validate(
+                header_row.is_some(),
+                column_names.as_deref(),
+                &selected_columns,
+            )
+            .into_pyresult()?;
 
         if eager && self.sheets.supports_by_ref() {
-            let range = self
+            let hidden_columns = self
+                .hidden_columns_if_skipped(&sheet_meta.name, &options)
+                .into_pyresult()?;
+            let zero_padded_cells = self
+                .zero_padded_cells(&sheet_meta.name, &options)
+                .into_pyresult()?;
+            let mut range = self
                 .sheets
                 .with_header_row(calamine_header_row)
                 .worksheet_range_ref(&sheet_meta.name)
                 .into_pyresult()?;
+            pad_numbers_with_zeros(&mut range, &zero_padded_cells, DataRef::String);
             let pagination =
                 Pagination::new(skip_rows.unwrap_or(0), n_rows, &range).into_pyresult()?;
+            let header = Header::for_sheet(header_row, column_names, &range);
+            let hidden_columns = hidden_columns
+                .map(|columns| columns_relative_to(columns, &range))
+                .unwrap_or_default();
             Self::load_sheet_eager(
                 &range.into(),
                 pagination,
                 header,
-                schema_sample_rows,
                 &selected_columns,
-                dtypes.as_ref(),
-                &dtype_coercion,
+                &hidden_columns,
+                &options,
             )
             .into_pyresult()
             .and_then(|rb| rb.to_pyarrow(py))
         } else {
-            let range = self
-                .sheets
-                .with_header_row(calamine_header_row)
-                .worksheet_range(&sheet_meta.name)
+            let sheet = self
+                .build_lazy_sheet(
+                    sheet_meta,
+                    calamine_header_row,
+                    header_row,
+                    column_names,
+                    skip_rows,
+                    n_rows,
+                    selected_columns,
+                    options,
+                )
                 .into_pyresult()?;
-            let pagination =
-                Pagination::new(skip_rows.unwrap_or(0), n_rows, &range).into_pyresult()?;
-            let sheet = ExcelSheet::try_new(
-                sheet_meta,
-                range.into(),
-                header,
-                pagination,
-                schema_sample_rows,
-                dtype_coercion,
-                selected_columns,
-                dtypes,
-            )
-            .into_pyresult()?;
 
             if eager {
                 sheet.to_arrow(py)
@@ -247,143 +794,508 @@ impl ExcelReader {
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn build_table(
-        &mut self,
-        name: String,
-        header_row: Option<usize>,
-        column_names: Option<Vec<String>>,
-        skip_rows: usize,
-        n_rows: Option<usize>,
-        schema_sample_rows: Option<usize>,
-        dtype_coercion: DTypeCoercion,
-        use_columns: Option<&Bound<'_, PyAny>>,
-        dtypes: Option<DTypes>,
-        eager: bool,
-        py: Python<'_>,
-    ) -> PyResult<PyObject> {
-        let selected_columns = Self::build_selected_columns(use_columns).into_pyresult()?;
-
-        let table = self.sheets.get_table(&name).into_pyresult()?;
-        let header = {
-            match (column_names, header_row) {
-                (None, None) => Header::With(table.columns().into()),
-                (None, Some(row)) => Header::At(row),
-                (Some(column_names), _) => Header::With(column_names),
+    /// Where the workbook can be opened a second time from, to read what calamine does not
+    /// expose. Only available for XLSX workbooks opened from a path or bytes.
+    fn xlsx_source(&self) -> Option<xlsx::XlsxSource> {
+        if !self.sheets.is_xlsx() {
+            return None;
+        }
+        match (&self.sheets, &self.content) {
+            (ExcelSheets::File(_), _) => Some(xlsx::XlsxSource::Path(self.source.clone())),
+            (ExcelSheets::Bytes(_), Some(content)) => {
+                Some(xlsx::XlsxSource::Bytes(Arc::clone(content)))
             }
-        };
+            // Readers are owned by calamine, so they cannot be read a second time
+            _ => None,
+        }
+    }
 
-        let pagination = Pagination::new(skip_rows, n_rows, table.data()).into_pyresult()?;
+    /// Opens the content of the workbook a second time, to read what calamine does not expose.
+    /// Only available for XLSX workbooks opened from a path or bytes.
+    fn xlsx_content(&self) -> FastExcelResult<Option<xlsx::XlsxContent>> {
+        self.xlsx_source().map(|source| source.open()).transpose()
+    }
 
-        let excel_table = ExcelTable::try_new(
-            table,
-            header,
-            pagination,
-            schema_sample_rows,
-            dtype_coercion,
-            selected_columns,
-            dtypes,
-        )
-        .into_pyresult()?;
+    /// Returns the absolute indices of the hidden columns of a sheet if they are needed to select
+    /// its columns, i.e. if `skip_hidden_columns` is set. Otherwise, they are only read if
+    /// `ExcelSheet.hidden_columns` is accessed.
+    fn hidden_columns_if_skipped(
+        &self,
+        sheet_name: &str,
+        options: &LoadSheetOrTableOptions,
+    ) -> FastExcelResult<Option<Vec<usize>>> {
+        if !options.skip_hidden_columns {
+            return Ok(None);
+        }
+        read_hidden_columns(self.xlsx_source().as_ref(), sheet_name).map(Some)
+    }
 
-        if eager {
-            excel_table.to_arrow(py)
-        } else {
-            Ok(excel_table.into_py(py))
+    /// Returns the (row, column, width) of the cells of a sheet whose number format pads numbers
+    /// with leading zeros, if `keep_leading_zeros` is set. Number formats are only read from XLSX
+    /// workbooks opened from a path or bytes.
+    fn zero_padded_cells(
+        &self,
+        sheet_name: &str,
+        options: &LoadSheetOrTableOptions,
+    ) -> FastExcelResult<Vec<(usize, usize, usize)>> {
+        if !options.keep_leading_zeros {
+            return Ok(Vec::new());
         }
+        self.xlsx_content()
+            .and_then(|content| match content {
+                Some(content) => xlsx::zero_padded_cells(content, sheet_name),
+                None => Ok(Vec::new()),
+            })
+            .with_context(|| format!("could not read the number formats of sheet \"{sheet_name}\""))
     }
-}
 
-impl TryFrom<&[u8]> for ExcelReader {
-    type Error = FastExcelError;
+    /// Returns the name of the sheet with the given code name. Code names are only read from XLSX
+    /// workbooks opened from a path or bytes, once per reader. If they cannot be read, no sheet
+    /// is considered to have a code name, so that the error does not hide the sheet not being found
+    fn sheet_with_code_name(&self, code_name: &str) -> Option<&str> {
+        self.code_names
+            .get_or_init(|| {
+                self.xlsx_content()
+                    .and_then(|content| match content {
+                        Some(content) => xlsx::sheet_code_names(content),
+                        None => Ok(Vec::new()),
+                    })
+                    .unwrap_or_else(|err| {
+                        warn!("could not read the code names of the sheets: {err}");
+                        Vec::new()
+                    })
+            })
+            .iter()
+            .find(|(_, sheet_code_name)| sheet_code_name == code_name)
+            .map(|(sheet_name, _)| sheet_name.as_str())
+    }
 
-    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
-        let cursor = Cursor::new(bytes.to_vec());
-        let sheets = open_workbook_auto_from_rs(cursor)
-            .map_err(|err| FastExcelErrorKind::CalamineError(err).into())
-            .with_context(|| "Could not open workbook from bytes")?;
-        let sheet_metadata = sheets.sheets_metadata().to_owned();
-        Ok(Self {
-            sheets: ExcelSheets::Bytes(sheets),
-            sheet_metadata,
-            source: "bytes".to_owned(),
-        })
+    /// Returns the A1-style reference of the used range of a sheet, as recorded in the workbook.
+    /// Only available for XLSX workbooks opened from a path or bytes.
+    fn recorded_dimension(&self, sheet_name: &str) -> FastExcelResult<Option<String>> {
+        self.xlsx_content()
+            .and_then(|content| match content {
+                Some(content) => xlsx::dimension(content, sheet_name),
+                None => Ok(None),
+            })
+            .with_context(|| format!("could not read the dimension of sheet \"{sheet_name}\""))
     }
-}
 
-#[pymethods]
-impl ExcelReader {
-    pub fn __repr__(&self) -> String {
-        format!("ExcelReader<{}>", &self.source)
+    /// Returns the scope of every defined name, in the order of `ExcelSheets::defined_names`.
+    /// Scopes are only read from XLSX workbooks opened from a path or bytes, all names of other
+    /// workbooks are considered to belong to the whole workbook.
+    fn defined_name_scopes(&self) -> FastExcelResult<Vec<Option<String>>> {
+        self.xlsx_content()
+            .and_then(|content| match content {
+                Some(content) => xlsx::defined_name_scopes(content),
+                None => Ok(Vec::new()),
+            })
+            .with_context(|| "could not read the scopes of the defined names")
     }
 
-    #[pyo3(signature = (sheet_name = None))]
-    pub fn table_names(&mut self, sheet_name: Option<&str>) -> PyResult<Vec<String>> {
-        self.sheets.table_names(sheet_name).into_pyresult()
+    /// Returns the index of the sheet which is active when the workbook is opened. Only read from
+    /// XLSX workbooks opened from a path or bytes.
+    fn active_sheet_idx(&self) -> FastExcelResult<Option<usize>> {
+        self.xlsx_content()
+            .and_then(|content| match content {
+                Some(content) => xlsx::active_sheet(content),
+                None => Ok(None),
+            })
+            .with_context(|| "could not read the active sheet of the workbook")
     }
 
-    #[pyo3(signature = (
-        idx_or_name,
-        *,
-        header_row = 0,
-        column_names = None,
-        skip_rows = None,
-        n_rows = None,
-        schema_sample_rows = 1_000,
-        dtype_coercion = DTypeCoercion::Coerce,
-        use_columns = None,
-        dtypes = None,
+    /// Reads the core properties of the workbook. Only available for XLSX workbooks opened from a
+    /// path or bytes, other workbooks have none.
+    fn core_properties(&self) -> FastExcelResult<xlsx::CoreProperties> {
+        self.sheets.ensure_open()?;
+        self.xlsx_content()
+            .and_then(|content| match content {
+                Some(content) => xlsx::core_properties(content),
+                None => Ok(xlsx::CoreProperties::default()),
+            })
+            .with_context(|| "could not read the properties of the workbook")
+    }
+
+    /// Reads the definition of a table rather than its data. Only available for XLSX workbooks
+    /// opened from a path or bytes.
+    fn table_definition(&self, name: &str) -> FastExcelResult<xlsx::TableDefinition> {
+        self.sheets.ensure_open()?;
+        if !self.sheets.is_xlsx() {
+            return Err(FastExcelErrorKind::Internal(
+                "Currently only XLSX files are supported for tables".to_string(),
+            )
+            .into());
+        }
+        let Some(content) = self.xlsx_content()? else {
+            return Err(FastExcelErrorKind::InvalidParameters(
+                "table metadata cannot be read from workbooks opened from a file-like object"
+                    .to_string(),
+            )
+            .into());
+        };
+        xlsx::table_definition(content, name)
+            .and_then(|definition| {
+                definition.ok_or_else(|| {
+                    FastExcelErrorKind::InvalidParameters(format!("table \"{name}\" not found"))
+                        .into()
+                })
+            })
+            .with_context(|| format!("could not read the metadata of table \"{name}\""))
+    }
+
+    /// Returns the metadata of a table, read from the table's definition rather than its data
+    pub(crate) fn table_info_for(&self, name: &str) -> FastExcelResult<TableInfo> {
+        self.table_definition(name).map(TableInfo::from)
+    }
+
+    /// Returns the (height, width) of the used range of a sheet. The dimension recorded in XLSX
+    /// workbooks is used if possible, otherwise the sheet's data has to be loaded.
+    pub(crate) fn sheet_dimensions_for(
+        &mut self,
+        idx_or_name: IdxOrName,
+    ) -> FastExcelResult<(usize, usize)> {
+        let sheet_name = self.find_sheet_meta(idx_or_name, false)?.name.clone();
+        // A recorded dimension of a single cell is ambiguous, as it is also used for empty sheets
+        if let Some(range) = self
+            .recorded_dimension(&sheet_name)?
+            .and_then(|dimension| ExcelRange::parse(&dimension).ok())
+        {
+            return Ok(range.size());
+        }
+        // The header row of a previous load would crop the range
+        Ok(self
+            .worksheet_range(&sheet_name, HeaderRow::FirstNonEmptyRow)?
+            .get_size())
+    }
+
+    /// Returns the cells of the header row of a sheet as strings, as they are written in the
+    /// sheet: empty cells are empty strings, and neither names are generated nor deduplicated.
+    /// The cells of a header spanning several rows are joined as in `load_sheet`
+    fn sheet_header_for(
+        &mut self,
+        idx_or_name: IdxOrName,
+        header_row: SelectedHeaderRow,
+    ) -> FastExcelResult<Vec<String>> {
+        let sheet_name = self.find_sheet_meta(idx_or_name, false)?.name.clone();
+        let range = self.worksheet_range(
+            &sheet_name,
+            Self::calamine_header_row(Some(header_row), None),
+        )?;
+        let row_idx = match header_row {
+            SelectedHeaderRow::Row(_) => 0,
+            SelectedHeaderRow::Rows { start, end } => {
+                return Ok((0..range.width())
+                    .map(|col_idx| {
+                        multi_row_header_name(&*range, 0..end - start, col_idx).unwrap_or_default()
+                    })
+                    .collect());
+            }
+            SelectedHeaderRow::Auto => Header::detect_row(&range),
+        };
+        Ok((0..range.width())
+            .map(|col_idx| {
+                range
+                    .get((row_idx, col_idx))
+                    .and_then(|cell| cell.as_string())
+                    .unwrap_or_default()
+            })
+            .collect())
+    }
+
+    /// Returns the names of the available columns of a sheet, without determining their dtypes
+    fn available_column_names_for(
+        &mut self,
+        idx_or_name: IdxOrName,
+        header_row: Option<SelectedHeaderRow>,
+        column_names: Option<Vec<String>>,
+        unnamed_column_prefix: &str,
+        duplicate_column_strategy: DuplicateColumnStrategy,
+        case_insensitive_sheet_name: bool,
+    ) -> FastExcelResult<Vec<String>> {
+        let sheet_name = self
+            .find_sheet_meta(idx_or_name, case_insensitive_sheet_name)?
+            .name
+            .clone();
+        let range =
+            self.worksheet_range(&sheet_name, Self::calamine_header_row(header_row, None))?;
+        let header = Header::for_sheet(header_row, column_names, &range);
+        available_column_names(
+            &*range,
+            &header,
+            unnamed_column_prefix,
+            duplicate_column_strategy,
+        )
+        .with_context(|| format!("could not read the column names of sheet \"{sheet_name}\""))
+    }
+
+    /// Returns the (row, column, formula) of every cell containing a formula. Coordinates are
+    /// 0-based and absolute within the sheet.
+    pub(crate) fn sheet_formulas_for(
+        &mut self,
+        idx_or_name: IdxOrName,
+    ) -> FastExcelResult<Vec<(usize, usize, String)>> {
+        let sheet_name = self.find_sheet_meta(idx_or_name, false)?.name.clone();
+        let formulas = self.sheets.worksheet_formula(&sheet_name)?;
+        let (start_row, start_col) = formulas.start().unwrap_or((0, 0));
+
+        Ok(formulas
+            .used_cells()
+            .filter(|(_, _, formula)| !formula.is_empty())
+            .map(|(row, col, formula)| {
+                (
+                    start_row as usize + row,
+                    start_col as usize + col,
+                    formula.to_owned(),
+                )
+            })
+            .collect())
+    }
+
+    /// Returns the (row, column, style) of every cell with a fill color or a bold font.
+    /// Coordinates are 0-based and absolute within the sheet. Styles are only read from XLSX
+    /// workbooks opened from a path or bytes, other workbooks have none.
+    pub(crate) fn cell_styles_for(
+        &mut self,
+        idx_or_name: IdxOrName,
+    ) -> FastExcelResult<Vec<(usize, usize, xlsx::CellStyle)>> {
+        self.sheets.ensure_open()?;
+        let sheet_name = self.find_sheet_meta(idx_or_name, false)?.name.clone();
+        self.xlsx_content()
+            .and_then(|content| match content {
+                Some(content) => xlsx::cell_styles(content, &sheet_name),
+                None => Ok(Vec::new()),
+            })
+            .with_context(|| format!("could not read the cell styles of sheet \"{sheet_name}\""))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build_range(
+        &mut self,
+        sheet_meta: CalamineSheet,
+        a1_range: &str,
+        header_row: Option<usize>,
+        column_names: Option<Vec<String>>,
+        skip_rows: usize,
+        n_rows: Option<usize>,
+        use_columns: Option<&Bound<'_, PyAny>>,
+        exclude_columns: Option<Vec<IdxOrName>>,
+        options: LoadSheetOrTableOptions,
+    ) -> FastExcelResult<ExcelSheet> {
+        let selected_columns = Self::build_selected_columns(use_columns, exclude_columns)?;
+        options.validate(
+            header_row.is_some(),
+            column_names.as_deref(),
+            &selected_columns,
+        )?;
+        let hidden_columns = self.hidden_columns_if_skipped(&sheet_meta.name, &options)?;
+        // Loading the whole sheet: its range has absolute coordinates, so it can be cropped with
+        // the absolute coordinates of the A1 range
+        let range = self.worksheet_range(&sheet_meta.name, HeaderRow::FirstNonEmptyRow)?;
+        let excel_range = ExcelRange::from_a1(&range, a1_range)
+            .with_context(|| format!("invalid range for sheet \"{}\"", sheet_meta.name))?;
+        let mut range = range.range(excel_range.start(), excel_range.end());
+        let zero_padded_cells = self.zero_padded_cells(&sheet_meta.name, &options)?;
+        pad_numbers_with_zeros(&mut range, &zero_padded_cells, Data::String);
+
+        // Header, skip_rows and n_rows are relative to the cropped region
+        let header = Header::new(header_row, column_names);
+        let pagination = Pagination::new(skip_rows, n_rows, &range)?;
+        let hidden_columns = hidden_columns.map(|columns| columns_relative_to(columns, &range));
+
+        ExcelSheet::try_new(
+            sheet_meta,
+            range.into(),
+            header,
+            pagination,
+            selected_columns,
+            hidden_columns,
+            options,
+        )
+        .map(|sheet| sheet.with_xlsx_source(self.xlsx_source()))
+    }
+
+    /// Resolves a defined name to the sheet region it refers to
+    fn defined_name_reference(&self, name: &str) -> FastExcelResult<SheetReference> {
+        let (_, formula) = self
+            .sheets
+            .defined_names()?
+            .iter()
+            .find(|(defined_name, _)| defined_name == name)
+            .ok_or_else(|| {
+                FastExcelErrorKind::InvalidParameters(format!("defined name \"{name}\" not found"))
+            })?;
+        SheetReference::parse(formula)
+            .with_context(|| format!("defined name \"{name}\" cannot be loaded as a range"))
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn build_table(
+        &mut self,
+        name: String,
+        header_row: Option<usize>,
+        column_names: Option<Vec<String>>,
+        skip_rows: usize,
+        n_rows: Option<usize>,
+        use_columns: Option<&Bound<'_, PyAny>>,
+        exclude_columns: Option<Vec<IdxOrName>>,
+        options: LoadSheetOrTableOptions,
+        eager: bool,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        let selected_columns =
+            Self::build_selected_columns(use_columns, exclude_columns).into_pyresult()?;
+        // The names of a table's columns are always looked up, from its definition by default
+        options
+            .validate(true, column_names.as_deref(), &selected_columns)
+            .into_pyresult()?;
+
+        let table = self.sheets.get_table(&name).into_pyresult()?;
+        let hidden_columns = self
+            .hidden_columns_if_skipped(table.sheet_name(), &options)
+            .into_pyresult()?
+            .map(|columns| columns_relative_to(columns, table.data()))
+            .unwrap_or_default();
+        let zero_padded_cells = self
+            .zero_padded_cells(table.sheet_name(), &options)
+            .into_pyresult()?;
+        let header = {
+            match (column_names, header_row) {
+                (None, None) => Header::With(table.columns().into()),
+                (None, Some(row)) => Header::At(row),
+                (Some(column_names), _) => Header::With(column_names),
+            }
+        };
+
+        let pagination = Pagination::new(skip_rows, n_rows, table.data()).into_pyresult()?;
+
+        // calamine's tables span their whole reference, totals row included
+        let totals_row_count = if options.exclude_totals_row {
+            self.table_definition(&name)
+                .into_pyresult()?
+                .totals_row_count
+        } else {
+            0
+        };
+
+        let excel_table = ExcelTable::try_new(
+            table,
+            header,
+            pagination,
+            selected_columns,
+            &hidden_columns,
+            &zero_padded_cells,
+            options,
+            totals_row_count,
+        )
+        .into_pyresult()?;
+
+        if eager {
+            excel_table.to_arrow(py)
+        } else {
+            Ok(excel_table.into_py(py))
+        }
+    }
+}
+
+impl TryFrom<&[u8]> for ExcelReader {
+    type Error = FastExcelError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::try_from(Arc::<[u8]>::from(bytes))
+    }
+}
+
+/// Opens a workbook from owned bytes, which are moved to a shared buffer once
+impl TryFrom<Vec<u8>> for ExcelReader {
+    type Error = FastExcelError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::try_from(Arc::<[u8]>::from(bytes))
+    }
+}
+
+/// Opens a workbook from bytes which are already owned, without copying them
+impl TryFrom<Arc<[u8]>> for ExcelReader {
+    type Error = FastExcelError;
+
+    fn try_from(content: Arc<[u8]>) -> Result<Self, Self::Error> {
+        let cursor = Cursor::new(Arc::clone(&content));
+        let sheets = open_workbook_auto_from_rs(cursor)
+            .map_err(|err| open_workbook_error(err, || is_encrypted_workbook(&content)))
+            .with_context(|| "Could not open workbook from bytes")?;
+        let sheet_metadata = sheets.sheets_metadata().to_owned();
+        Ok(Self {
+            sheets: ExcelSheets::Bytes(sheets),
+            sheet_metadata,
+            source: "bytes".to_owned(),
+            content: Some(content),
+            range_cache: RangeCache::default(),
+            code_names: OnceLock::new(),
+        })
+    }
+}
+
+#[pymethods]
+impl ExcelReader {
+    pub fn __repr__(&self) -> String {
+        format!("ExcelReader<{}>", &self.source)
+    }
+
+    /// Releases the file handle or buffer held by the reader. Loading data afterwards is an error,
+    /// sheets and tables which were already loaded remain usable
+    pub fn close(&mut self) {
+        self.sheets = ExcelSheets::Closed;
+        self.content = None;
+        self.range_cache.clear();
+    }
+
+    #[pyo3(signature = (sheet_name = None))]
+    pub fn table_names(&mut self, sheet_name: Option<&str>) -> PyResult<Vec<String>> {
+        self.sheets.ensure_open().into_pyresult()?;
+        self.sheets.table_names(sheet_name).into_pyresult()
+    }
+
+    pub fn table_info(&self, name: &str) -> PyResult<TableInfo> {
+        self.sheets.ensure_open().into_pyresult()?;
+        self.table_info_for(name).into_pyresult()
+    }
+
+    #[pyo3(signature = (
+        idx_or_name,
+        *,
+        header_row = SelectedHeaderRow::Row(0),
+        column_names = None,
+        skip_rows = None,
+        n_rows = None,
+        use_columns = None,
+        exclude_columns = None,
         eager = false,
+        case_insensitive_sheet_name = false,
+        **options,
     ))]
     #[allow(clippy::too_many_arguments)]
     pub fn load_sheet(
         &mut self,
         idx_or_name: &Bound<'_, PyAny>,
-        header_row: Option<usize>,
+        header_row: Option<SelectedHeaderRow>,
         column_names: Option<Vec<String>>,
         skip_rows: Option<usize>,
         n_rows: Option<usize>,
-        schema_sample_rows: Option<usize>,
-        dtype_coercion: DTypeCoercion,
         use_columns: Option<&Bound<'_, PyAny>>,
-        dtypes: Option<DTypes>,
+        exclude_columns: Option<Vec<IdxOrName>>,
         eager: bool,
+        case_insensitive_sheet_name: bool,
+        options: Option<&Bound<'_, PyDict>>,
         py: Python<'_>,
     ) -> PyResult<PyObject> {
-        // Cannot use NonZeroUsize in the parameters, as it is not supported by pyo3
-        if let Some(0) = schema_sample_rows {
-            return Err(FastExcelErrorKind::InvalidParameters(
-                "schema_sample_rows cannot be 0, as it would prevent dtype inferring".to_string(),
-            )
-            .into())
-            .into_pyresult();
-        }
+        self.sheets.ensure_open().into_pyresult()?;
+        let options = LoadSheetOrTableOptions::from_kwargs(options)?;
+
         let sheet = idx_or_name
             .try_into()
-            .and_then(|idx_or_name| match idx_or_name {
-                IdxOrName::Name(name) => {
-                    if let Some(sheet) = self.sheet_metadata.iter().find(|s| s.name == name) {
-                        Ok(sheet)
-                    } else {
-                        Err(FastExcelErrorKind::SheetNotFound(IdxOrName::Name(name.clone())).into()).with_context(||  {
-                            let available_sheets = self.sheet_metadata.iter().map(|s| format!("\"{}\"", s.name)).collect::<Vec<_>>().join(", ");
-                            format!(
-                                "Sheet \"{name}\" not found in file. Available sheets: {available_sheets}."
-                            )
-                        })
-                    }
-                }
-                IdxOrName::Idx(idx) => self
-                    .sheet_metadata
-                    .get(idx)
-                    .ok_or_else(|| FastExcelErrorKind::SheetNotFound(IdxOrName::Idx(idx)).into())
-                    .with_context(|| format!(
-                        "Sheet index {idx} is out of range. File has {} sheets.",
-                        self.sheet_metadata.len()
-                    )
-                ),
-            })
-            .into_pyresult()?.to_owned();
+            .and_then(|idx_or_name| self.find_sheet_meta(idx_or_name, case_insensitive_sheet_name))
+            .into_pyresult()?
+            .to_owned();
 
         self.build_sheet(
             sheet,
@@ -391,15 +1303,103 @@ impl ExcelReader {
             column_names,
             skip_rows,
             n_rows,
-            schema_sample_rows,
-            dtype_coercion,
             use_columns,
-            dtypes,
+            exclude_columns,
+            options,
             eager,
             py,
         )
     }
 
+    #[pyo3(signature = (
+        idxs_or_names,
+        *,
+        header_row = SelectedHeaderRow::Row(0),
+        column_names = None,
+        skip_rows = None,
+        n_rows = None,
+        use_columns = None,
+        exclude_columns = None,
+        case_insensitive_sheet_name = false,
+        **options,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn load_sheets(
+        &mut self,
+        idxs_or_names: Vec<IdxOrName>,
+        header_row: Option<SelectedHeaderRow>,
+        column_names: Option<Vec<String>>,
+        skip_rows: Option<usize>,
+        n_rows: Option<usize>,
+        use_columns: Option<&Bound<'_, PyAny>>,
+        exclude_columns: Option<Vec<IdxOrName>>,
+        case_insensitive_sheet_name: bool,
+        options: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Vec<ExcelSheet>> {
+        self.sheets.ensure_open().into_pyresult()?;
+        let options = LoadSheetOrTableOptions::from_kwargs(options)?;
+
+        self.build_lazy_sheets(
+            idxs_or_names,
+            case_insensitive_sheet_name,
+            header_row,
+            column_names,
+            skip_rows,
+            n_rows,
+            use_columns,
+            exclude_columns,
+            &options,
+        )
+        .into_pyresult()
+    }
+
+    #[pyo3(signature = (
+        idxs_or_names,
+        *,
+        header_row = SelectedHeaderRow::Row(0),
+        column_names = None,
+        skip_rows = None,
+        n_rows = None,
+        use_columns = None,
+        exclude_columns = None,
+        add_sheet_name_column = false,
+        case_insensitive_sheet_name = false,
+        **options,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn load_sheets_union(
+        &mut self,
+        idxs_or_names: Vec<IdxOrName>,
+        header_row: Option<SelectedHeaderRow>,
+        column_names: Option<Vec<String>>,
+        skip_rows: Option<usize>,
+        n_rows: Option<usize>,
+        use_columns: Option<&Bound<'_, PyAny>>,
+        exclude_columns: Option<Vec<IdxOrName>>,
+        add_sheet_name_column: bool,
+        case_insensitive_sheet_name: bool,
+        options: Option<&Bound<'_, PyDict>>,
+        py: Python<'_>,
+    ) -> PyResult<PyObject> {
+        self.sheets.ensure_open().into_pyresult()?;
+        let options = LoadSheetOrTableOptions::from_kwargs(options)?;
+
+        self.build_lazy_sheets(
+            idxs_or_names,
+            case_insensitive_sheet_name,
+            header_row,
+            column_names,
+            skip_rows,
+            n_rows,
+            use_columns,
+            exclude_columns,
+            &options,
+        )
+        .and_then(|sheets| concat_sheets(&sheets, add_sheet_name_column))
+        .into_pyresult()
+        .and_then(|rb| rb.to_pyarrow(py))
+    }
+
     #[pyo3(signature = (
         name,
         *,
@@ -407,11 +1407,11 @@ impl ExcelReader {
         column_names = None,
         skip_rows = 0,
         n_rows = None,
-        schema_sample_rows = 1_000,
-        dtype_coercion = DTypeCoercion::Coerce,
         use_columns = None,
-        dtypes = None,
+        exclude_columns = None,
+        exclude_totals_row = false,
         eager = false,
+        **options,
     ))]
     #[allow(clippy::too_many_arguments)]
     pub fn load_table(
@@ -421,36 +1421,272 @@ impl ExcelReader {
         column_names: Option<Vec<String>>,
         skip_rows: usize,
         n_rows: Option<usize>,
-        schema_sample_rows: Option<usize>,
-        dtype_coercion: DTypeCoercion,
         use_columns: Option<&Bound<'_, PyAny>>,
-        dtypes: Option<DTypes>,
+        exclude_columns: Option<Vec<IdxOrName>>,
+        exclude_totals_row: bool,
         eager: bool,
+        options: Option<&Bound<'_, PyDict>>,
         py: Python<'_>,
     ) -> PyResult<PyObject> {
-        // Cannot use NonZeroUsize in the parameters, as it is not supported by pyo3
-        if let Some(0) = schema_sample_rows {
-            return Err(FastExcelErrorKind::InvalidParameters(
-                "schema_sample_rows cannot be 0, as it would prevent dtype inferring".to_string(),
-            )
-            .into())
-            .into_pyresult();
-        }
+        self.sheets.ensure_open().into_pyresult()?;
+        let options = LoadSheetOrTableOptions {
+            exclude_totals_row,
+            ..LoadSheetOrTableOptions::from_kwargs(options)?
+        };
+
         self.build_table(
             name.to_string(),
             header_row,
             column_names,
             skip_rows,
             n_rows,
-            schema_sample_rows,
-            dtype_coercion,
             use_columns,
-            dtypes,
+            exclude_columns,
+            options,
             eager,
             py,
         )
     }
 
+    #[pyo3(signature = (
+        idx_or_name,
+        a1_range,
+        *,
+        header_row = 0,
+        column_names = None,
+        skip_rows = 0,
+        n_rows = None,
+        use_columns = None,
+        exclude_columns = None,
+        case_insensitive_sheet_name = false,
+        **options,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn load_range(
+        &mut self,
+        idx_or_name: &Bound<'_, PyAny>,
+        a1_range: &str,
+        header_row: Option<usize>,
+        column_names: Option<Vec<String>>,
+        skip_rows: usize,
+        n_rows: Option<usize>,
+        use_columns: Option<&Bound<'_, PyAny>>,
+        exclude_columns: Option<Vec<IdxOrName>>,
+        case_insensitive_sheet_name: bool,
+        options: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<ExcelSheet> {
+        self.sheets.ensure_open().into_pyresult()?;
+        let options = LoadSheetOrTableOptions::from_kwargs(options)?;
+
+        let sheet = idx_or_name
+            .try_into()
+            .and_then(|idx_or_name| self.find_sheet_meta(idx_or_name, case_insensitive_sheet_name))
+            .into_pyresult()?
+            .to_owned();
+
+        self.build_range(
+            sheet,
+            a1_range,
+            header_row,
+            column_names,
+            skip_rows,
+            n_rows,
+            use_columns,
+            exclude_columns,
+            options,
+        )
+        .into_pyresult()
+    }
+
+    #[pyo3(signature = (
+        name,
+        *,
+        header_row = 0,
+        column_names = None,
+        skip_rows = 0,
+        n_rows = None,
+        use_columns = None,
+        exclude_columns = None,
+        case_insensitive_sheet_name = false,
+        **options,
+    ))]
+    #[allow(clippy::too_many_arguments)]
+    pub fn load_defined_name(
+        &mut self,
+        name: &str,
+        header_row: Option<usize>,
+        column_names: Option<Vec<String>>,
+        skip_rows: usize,
+        n_rows: Option<usize>,
+        use_columns: Option<&Bound<'_, PyAny>>,
+        exclude_columns: Option<Vec<IdxOrName>>,
+        case_insensitive_sheet_name: bool,
+        options: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<ExcelSheet> {
+        self.sheets.ensure_open().into_pyresult()?;
+        let options = LoadSheetOrTableOptions::from_kwargs(options)?;
+
+        let reference = self.defined_name_reference(name).into_pyresult()?;
+        let sheet = self
+            .find_sheet_meta(
+                IdxOrName::Name(reference.sheet_name),
+                case_insensitive_sheet_name,
+            )
+            .into_pyresult()?
+            .to_owned();
+
+        self.build_range(
+            sheet,
+            &reference.range.to_string(),
+            header_row,
+            column_names,
+            skip_rows,
+            n_rows,
+            use_columns,
+            exclude_columns,
+            options,
+        )
+        .with_context(|| format!("could not load defined name \"{name}\""))
+        .into_pyresult()
+    }
+
+    pub fn sheet_dimensions(&mut self, idx_or_name: &Bound<'_, PyAny>) -> PyResult<(usize, usize)> {
+        self.sheets.ensure_open().into_pyresult()?;
+        idx_or_name
+            .try_into()
+            .and_then(|idx_or_name| self.sheet_dimensions_for(idx_or_name))
+            .into_pyresult()
+    }
+
+    #[pyo3(signature = (
+        idx_or_name,
+        *,
+        header_row = SelectedHeaderRow::Row(0),
+        column_names = None,
+        unnamed_column_prefix = DEFAULT_UNNAMED_COLUMN_PREFIX.to_owned(),
+        duplicate_column_strategy = DuplicateColumnStrategy::Suffix,
+        case_insensitive_sheet_name = false,
+    ))]
+    pub fn available_column_names(
+        &mut self,
+        idx_or_name: &Bound<'_, PyAny>,
+        header_row: Option<SelectedHeaderRow>,
+        column_names: Option<Vec<String>>,
+        unnamed_column_prefix: String,
+        duplicate_column_strategy: DuplicateColumnStrategy,
+        case_insensitive_sheet_name: bool,
+    ) -> PyResult<Vec<String>> {
+        self.sheets.ensure_open().into_pyresult()?;
+        idx_or_name
+            .try_into()
+            .and_then(|idx_or_name| {
+                self.available_column_names_for(
+                    idx_or_name,
+                    header_row,
+                    column_names,
+                    &unnamed_column_prefix,
+                    duplicate_column_strategy,
+                    case_insensitive_sheet_name,
+                )
+            })
+            .into_pyresult()
+    }
+
+    pub fn cell_styles(
+        &mut self,
+        idx_or_name: &Bound<'_, PyAny>,
+    ) -> PyResult<Vec<(usize, usize, CellStyle)>> {
+        self.sheets.ensure_open().into_pyresult()?;
+        idx_or_name
+            .try_into()
+            .and_then(|idx_or_name| self.cell_styles_for(idx_or_name))
+            .map(|styles| {
+                styles
+                    .into_iter()
+                    .map(|(row, col, style)| (row, col, style.into()))
+                    .collect()
+            })
+            .into_pyresult()
+    }
+
+    #[pyo3(signature = (idx_or_name, *, header_row = SelectedHeaderRow::Row(0)))]
+    pub fn sheet_header(
+        &mut self,
+        idx_or_name: &Bound<'_, PyAny>,
+        header_row: SelectedHeaderRow,
+    ) -> PyResult<Vec<String>> {
+        self.sheets.ensure_open().into_pyresult()?;
+        idx_or_name
+            .try_into()
+            .and_then(|idx_or_name| self.sheet_header_for(idx_or_name, header_row))
+            .into_pyresult()
+    }
+
+    pub fn sheet_formulas(
+        &mut self,
+        idx_or_name: &Bound<'_, PyAny>,
+    ) -> PyResult<Vec<(usize, usize, String)>> {
+        self.sheets.ensure_open().into_pyresult()?;
+        idx_or_name
+            .try_into()
+            .and_then(|idx_or_name| self.sheet_formulas_for(idx_or_name))
+            .into_pyresult()
+    }
+
+    #[getter]
+    pub fn defined_names(&self) -> PyResult<Vec<(String, String)>> {
+        self.sheets.ensure_open().into_pyresult()?;
+        self.sheets
+            .defined_names()
+            .map(<[_]>::to_vec)
+            .into_pyresult()
+    }
+
+    #[getter]
+    pub fn defined_names_with_scope(&self) -> PyResult<Vec<DefinedName>> {
+        self.sheets.ensure_open().into_pyresult()?;
+        let defined_names = self.sheets.defined_names().into_pyresult()?;
+        let mut scopes = self.defined_name_scopes().into_pyresult()?.into_iter();
+        Ok(defined_names
+            .iter()
+            .map(|(name, formula)| {
+                DefinedName::new(name.clone(), formula.clone(), scopes.next().flatten())
+            })
+            .collect())
+    }
+
+    pub fn defined_names_for_sheet(
+        &self,
+        sheet_name: &str,
+    ) -> PyResult<Vec<(String, String, String)>> {
+        self.sheets.ensure_open().into_pyresult()?;
+        // The sheet may be referred to by its code name
+        let sheet_name = self
+            .find_sheet_meta(IdxOrName::Name(sheet_name.to_owned()), false)
+            .into_pyresult()?
+            .name
+            .clone();
+        Ok(self
+            .sheets
+            .defined_names()
+            .into_pyresult()?
+            .iter()
+            // Defined names that are not a reference to a single area are skipped
+            .filter_map(|(name, formula)| {
+                SheetReference::parse(formula)
+                    .ok()
+                    .filter(|reference| reference.sheet_name == sheet_name)
+                    .map(|reference| {
+                        (
+                            name.clone(),
+                            reference.sheet_name,
+                            reference.range.to_string(),
+                        )
+                    })
+            })
+            .collect())
+    }
+
     #[getter]
     pub fn sheet_names(&self) -> Vec<&str> {
         self.sheet_metadata
@@ -458,4 +1694,153 @@ impl ExcelReader {
             .map(|s| s.name.as_str())
             .collect()
     }
+
+    /// The (index, name) pairs of the sheets, the index being the one `load_sheet` expects
+    #[getter]
+    pub fn sheet_names_with_indices(&self) -> Vec<(usize, &str)> {
+        self.sheet_metadata
+            .iter()
+            .enumerate()
+            .map(|(idx, s)| (idx, s.name.as_str()))
+            .collect()
+    }
+
+    pub fn metadata(&self) -> PyResult<WorkbookMetadata> {
+        self.sheets.ensure_open().into_pyresult()?;
+        self.core_properties().map(Into::into).into_pyresult()
+    }
+
+    /// The index of the sheet which is active when the workbook is opened, if recorded
+    #[getter]
+    pub fn active_sheet(&self) -> PyResult<Option<usize>> {
+        self.sheets.ensure_open().into_pyresult()?;
+        self.active_sheet_idx()
+            // A view referring to a sheet that does not exist is ignored
+            .map(|idx| idx.filter(|idx| *idx < self.sheet_metadata.len()))
+            .into_pyresult()
+    }
+
+    #[getter]
+    pub fn sheet_count(&self) -> usize {
+        self.sheet_metadata.len()
+    }
+
+    /// Whether the workbook has a sheet at the given index or with the given name
+    pub fn has_sheet(&self, idx_or_name: &Bound<'_, PyAny>) -> PyResult<bool> {
+        self.sheets.ensure_open().into_pyresult()?;
+        IdxOrName::try_from(idx_or_name)
+            .map(|idx_or_name| self.find_sheet_meta(idx_or_name, false).is_ok())
+            .into_pyresult()
+    }
+
+    /// The names of the visible sheets, in the workbook's order
+    #[getter]
+    pub fn visible_sheet_names(&self) -> Vec<&str> {
+        self.sheet_metadata
+            .iter()
+            .filter(|s| matches!(s.visible, CalamineSheetVisible::Visible))
+            .map(|s| s.name.as_str())
+            .collect()
+    }
+
+    /// The ARGB hex color of the tab of every sheet, in the workbook's order
+    #[getter]
+    pub fn sheet_tab_colors(&self) -> PyResult<Vec<(String, Option<String>)>> {
+        self.sheets.ensure_open().into_pyresult()?;
+        let tab_colors = self
+            .xlsx_content()
+            .and_then(|content| match content {
+                Some(content) => xlsx::tab_colors(content),
+                None => Ok(Vec::new()),
+            })
+            .with_context(|| "could not read the tab colors of the sheets")
+            .into_pyresult()?;
+        // Sheets which are not worksheets (e.g. chartsheets) have no tab color
+        Ok(self
+            .sheet_metadata
+            .iter()
+            .map(|sheet| {
+                let tab_color = tab_colors
+                    .iter()
+                    .find(|(name, _)| name == &sheet.name)
+                    .and_then(|(_, tab_color)| tab_color.clone());
+                (sheet.name.clone(), tab_color)
+            })
+            .collect())
+    }
+
+    #[getter]
+    pub fn sheet_visibilities(&self, py: Python<'_>) -> Vec<(String, PyObject)> {
+        self.sheet_metadata
+            .iter()
+            .map(|s| {
+                let visible: SheetVisible = s.visible.into();
+                (s.name.clone(), (&visible).to_object(py))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn fixture_bytes(name: &str) -> Vec<u8> {
+        std::fs::read(
+            Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("python/tests/fixtures")
+                .join(name),
+        )
+        .unwrap()
+    }
+
+    /// Loads every sheet of the reader with the default options
+    fn load_all_sheets(reader: &mut ExcelReader) -> Vec<RecordBatch> {
+        let names: Vec<String> = reader
+            .sheet_names()
+            .into_iter()
+            .map(str::to_owned)
+            .collect();
+        names
+            .iter()
+            .map(|name| {
+                let range = reader
+                    .worksheet_range(name, HeaderRow::FirstNonEmptyRow)
+                    .unwrap();
+                let pagination = Pagination::new(0, None, range.as_ref()).unwrap();
+                ExcelReader::load_sheet_eager(
+                    &range.into(),
+                    pagination,
+                    Header::At(0),
+                    &SelectedColumns::All,
+                    &[],
+                    &LoadSheetOrTableOptions::default(),
+                )
+                .unwrap()
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case("fixture-multi-sheet.xlsx")]
+    #[case("fixture-single-sheet-with-types.xlsx")]
+    #[case("dates.ods")]
+    fn open_from_borrowed_and_owned_bytes(#[case] fixture: &str) {
+        let bytes = fixture_bytes(fixture);
+        let mut from_slice = ExcelReader::try_from(bytes.as_slice()).unwrap();
+        let mut from_arc = ExcelReader::try_from(Arc::<[u8]>::from(bytes.as_slice())).unwrap();
+        let mut from_vec = ExcelReader::try_from(bytes).unwrap();
+
+        assert_eq!(from_arc.sheet_names(), from_slice.sheet_names());
+        assert_eq!(from_vec.sheet_names(), from_slice.sheet_names());
+
+        let batches = load_all_sheets(&mut from_slice);
+        assert_eq!(load_all_sheets(&mut from_arc), batches);
+        assert_eq!(load_all_sheets(&mut from_vec), batches);
+    }
 }
@@ -1,8 +1,9 @@
-use std::{fmt::Display, str::FromStr};
+use std::{collections::HashMap, fmt::Display, ops::Range, str::FromStr};
 
 use arrow::datatypes::Field;
 use calamine::DataType;
-use pyo3::{pyclass, pymethods, PyResult};
+use log::warn;
+use pyo3::{prelude::PyAnyMethods, pyclass, pymethods, Bound, FromPyObject, PyAny, PyResult};
 
 use crate::{
     data::ExcelSheetData,
@@ -10,12 +11,12 @@ use crate::{
         py_errors::IntoPyResult, ErrorContext, FastExcelError, FastExcelErrorKind, FastExcelResult,
     },
     types::{
-        dtype::{get_dtype_for_column, DType, DTypeCoercion, DTypes},
+        dtype::{get_dtype_for_column, is_null_column, DType, DTypes, NullValues},
         idx_or_name::IdxOrName,
     },
 };
 
-use super::{Header, SelectedColumns};
+use super::{Header, LoadSheetOrTableOptions, SelectedColumns, MULTI_ROW_HEADER_SEPARATOR};
 
 #[derive(Debug, Clone, PartialEq)]
 pub(crate) enum ColumnNameFrom {
@@ -50,12 +51,14 @@ impl Display for ColumnNameFrom {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub(crate) enum DTypeFrom {
     ProvidedForAll,
     ProvidedByIndex,
     ProvidedByName,
     Guessed,
+    /// Guessed as an integer, and read as a float because of `int_as_float`
+    IntAsFloat,
 }
 
 impl Display for DTypeFrom {
@@ -65,6 +68,7 @@ impl Display for DTypeFrom {
             DTypeFrom::ProvidedByIndex => "provided_by_index",
             DTypeFrom::ProvidedByName => "provided_by_name",
             DTypeFrom::Guessed => "guessed",
+            DTypeFrom::IntAsFloat => "int_as_float",
         })
     }
 }
@@ -78,6 +82,7 @@ impl FromStr for DTypeFrom {
             "provided_by_index" => Ok(Self::ProvidedByIndex),
             "provided_by_name" => Ok(Self::ProvidedByName),
             "guessed" => Ok(Self::Guessed),
+            "int_as_float" => Ok(Self::IntAsFloat),
             _ => Err(
                 FastExcelErrorKind::InvalidParameters(format!("invalid DTypesFrom: {s}")).into(),
             ),
@@ -85,10 +90,52 @@ impl FromStr for DTypeFrom {
     }
 }
 
+/// How columns sharing the same name are told apart
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum DuplicateColumnStrategy {
+    /// `name`, `name_1`, `name_2`...
+    #[default]
+    Suffix,
+    /// `name`, `name.1`, `name.2`..., like pandas
+    Pandas,
+    /// Duplicate names are an error
+    Error,
+}
+
+impl FromStr for DuplicateColumnStrategy {
+    type Err = FastExcelError;
+
+    fn from_str(s: &str) -> FastExcelResult<Self> {
+        match s {
+            "suffix" => Ok(Self::Suffix),
+            "pandas" => Ok(Self::Pandas),
+            "error" => Ok(Self::Error),
+            _ => Err(FastExcelErrorKind::InvalidParameters(format!(
+                "unsupported duplicate_column_strategy: \"{s}\""
+            ))
+            .into()),
+        }
+    }
+}
+
+impl FromPyObject<'_> for DuplicateColumnStrategy {
+    fn extract_bound(py_strategy: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(strategy_pystr) = py_strategy.extract::<String>() {
+            strategy_pystr.parse()
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "{py_strategy:?} cannot be converted to str"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
 // NOTE: The types for properties unfortunately do not appear in the docs for this class, so we had
 // to specify them via docstrings
 /// This class provides information about a single column in a sheet
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone)]
 #[pyclass(name = "ColumnInfo")]
 pub(crate) struct ColumnInfo {
     /// `str`. The name of the column
@@ -100,6 +147,19 @@ pub(crate) struct ColumnInfo {
     dtype: DType,
     column_name_from: ColumnNameFrom,
     dtype_from: DTypeFrom,
+    observed_dtypes: Option<Vec<DType>>,
+}
+
+// The observed dtypes are debugging information, and are not known for instances created from
+// python, so they are not taken into account when comparing columns
+impl PartialEq for ColumnInfo {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name
+            && self.index == other.index
+            && self.dtype == other.dtype
+            && self.column_name_from == other.column_name_from
+            && self.dtype_from == other.dtype_from
+    }
 }
 
 impl ColumnInfo {
@@ -116,9 +176,15 @@ impl ColumnInfo {
             dtype,
             column_name_from,
             dtype_from,
+            observed_dtypes: None,
         }
     }
 
+    pub(crate) fn with_observed_dtypes(mut self, observed_dtypes: Option<Vec<DType>>) -> Self {
+        self.observed_dtypes = observed_dtypes;
+        self
+    }
+
     pub(crate) fn name(&self) -> &str {
         &self.name
     }
@@ -130,6 +196,37 @@ impl ColumnInfo {
     pub(crate) fn dtype(&self) -> &DType {
         &self.dtype
     }
+
+    /// Whether the dtype of the column was guessed from rows which only contain null values.
+    /// Such columns fall back to the string dtype, unless no row was sampled at all
+    fn guessed_from_nulls(&self) -> bool {
+        self.dtype_from == DTypeFrom::Guessed
+            && self
+                .observed_dtypes
+                .as_ref()
+                .is_some_and(|observed_dtypes| {
+                    observed_dtypes.iter().all(|dtype| *dtype == DType::Null)
+                })
+    }
+
+    /// Returns the same column with a dtype provided by its name, e.g. from a schema
+    pub(crate) fn with_dtype(&self, dtype: DType) -> Self {
+        Self::new(
+            self.name.clone(),
+            self.index,
+            self.column_name_from.clone(),
+            dtype,
+            DTypeFrom::ProvidedByName,
+        )
+    }
+
+    /// Whether the column is the one referred to by its index or name
+    pub(crate) fn matches(&self, idx_or_name: &IdxOrName) -> bool {
+        match idx_or_name {
+            IdxOrName::Idx(index) => self.index == *index,
+            IdxOrName::Name(name) => &self.name == name,
+        }
+    }
 }
 
 impl From<&ColumnInfo> for Field {
@@ -183,15 +280,29 @@ impl ColumnInfo {
 
     /// `fastexcel.DTypeFrom`. How the dtype of the column was determined.
     ///
-    /// One of three possible values:
+    /// One of five possible values:
+    /// - `"provided_for_all"`: The dtype was specified for all columns
     /// - `"provided_by_index"`: The dtype was specified via the column index
     /// - `"provided_by_name"`: The dtype was specified via the column name
     /// - `"guessed"`: The dtype was determined from the content of the column
+    /// - `"int_as_float"`: The column was guessed as `int`, and is read as `float` because of
+    ///                     `int_as_float`
     #[getter(dtype_from)]
     fn get_dtype_from(&self) -> String {
         self.dtype_from.to_string()
     }
 
+    /// `list[fastexcel.DType] | None`. The dtypes of the cells found in the rows sampled to guess
+    /// the dtype of the column, sorted. `"null"` is included if null cells were found.
+    ///
+    /// `None` if the dtype of the column was not guessed.
+    #[getter(observed_dtypes)]
+    fn get_observed_dtypes(&self) -> Option<Vec<String>> {
+        self.observed_dtypes
+            .as_ref()
+            .map(|dtypes| dtypes.iter().map(ToString::to_string).collect())
+    }
+
     pub fn __repr__(&self) -> String {
         format!("ColumnInfo(name=\"{name}\", index={index}, dtype=\"{dtype}\", dtype_from=\"{dtype_from}\", column_name_from=\"{column_name_from}\" )", name=self.name, index=self.index, dtype=self.dtype, dtype_from=self.dtype_from, column_name_from=self.column_name_from)
     }
@@ -201,7 +312,33 @@ impl ColumnInfo {
     }
 }
 
-#[derive(Debug)]
+/// A column which can be selected by its index or name
+pub(crate) trait SelectableColumn: Clone + std::fmt::Debug {
+    fn name(&self) -> &str;
+    fn index(&self) -> usize;
+}
+
+impl SelectableColumn for ColumnInfo {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl SelectableColumn for ColumnInfoBuilder {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn index(&self) -> usize {
+        self.index
+    }
+}
+
+#[derive(Debug, Clone)]
 pub(crate) struct ColumnInfoBuilder {
     name: String,
     index: usize,
@@ -239,24 +376,36 @@ impl ColumnInfoBuilder {
     fn dtype_info<D: CalamineDataProvider>(
         &self,
         data: &D,
-        start_row: usize,
-        end_row: usize,
-        specified_dtypes: Option<&DTypes>,
-        dtype_coercion: &DTypeCoercion,
-    ) -> FastExcelResult<(DType, DTypeFrom)> {
-        specified_dtypes
+        sample_rows: &[Range<usize>],
+        options: &LoadSheetOrTableOptions,
+    ) -> FastExcelResult<(DType, DTypeFrom, Option<Vec<DType>>)> {
+        options
+            .dtypes
+            .as_ref()
             .and_then(|dtypes| {
                 match dtypes {
-                    DTypes::All(dtype) => Some((*dtype, DTypeFrom::ProvidedForAll)),
+                    DTypes::All(dtype) => Some((*dtype, DTypeFrom::ProvidedForAll, None)),
                     DTypes::Map(dtypes) => {
                         // if we have dtypes, look the dtype up by index, and fall back on a lookup by name
                         // (done in this order because copying an usize is cheaper than cloning a string)
                         if let Some(dtype) = dtypes.get(&self.index.into()) {
-                            Some((*dtype, DTypeFrom::ProvidedByIndex))
+                            Some((*dtype, DTypeFrom::ProvidedByIndex, None))
+                        } else if let Some(dtype) = dtypes.get(&self.name.clone().into()) {
+                            Some((*dtype, DTypeFrom::ProvidedByName, None))
                         } else {
+                            // Names which are column letters (e.g. "B") also refer to the column
+                            // at that position, unless it has a dtype of its own
                             dtypes
-                                .get(&self.name.clone().into())
-                                .map(|dtype| (*dtype, DTypeFrom::ProvidedByName))
+                                .iter()
+                                .find_map(|(idx_or_name, dtype)| match idx_or_name {
+                                    IdxOrName::Name(name)
+                                        if SelectedColumns::col_idx_for_col_as_letter(name)
+                                            .is_ok_and(|idx| idx == self.index) =>
+                                    {
+                                        Some((*dtype, DTypeFrom::ProvidedByIndex, None))
+                                    }
+                                    _ => None,
+                                })
                         }
                     }
                 }
@@ -264,29 +413,45 @@ impl ColumnInfoBuilder {
             .map(FastExcelResult::Ok)
             // If we could not look up a dtype, guess it from the data
             .unwrap_or_else(|| {
-                data.dtype_for_column(start_row, end_row, self.index, dtype_coercion)
-                    .map(|dtype| (dtype, DTypeFrom::Guessed))
+                data.dtype_for_column(sample_rows, self.index, options).map(
+                    |(dtype, observed_dtypes)| {
+                        let (dtype, dtype_from) = guessed_dtype(dtype, options);
+                        (dtype, dtype_from, Some(observed_dtypes))
+                    },
+                )
             })
     }
 
     pub(super) fn finish<D: CalamineDataProvider>(
         self,
         data: &D,
-        start_row: usize,
-        end_row: usize,
-        specified_dtypes: Option<&DTypes>,
-        dtype_coercion: &DTypeCoercion,
+        sample_rows: &[Range<usize>],
+        options: &LoadSheetOrTableOptions,
     ) -> FastExcelResult<ColumnInfo> {
-        let (dtype, dtype_from) = self
-            .dtype_info(data, start_row, end_row, specified_dtypes, dtype_coercion)
-            .with_context(|| format!("could not determine dtype for column {}", self.name))?;
+        let (dtype, dtype_from, observed_dtypes) = self
+            .dtype_info(data, sample_rows, options)
+            .with_context(|| {
+                format!(
+                    "could not determine dtype for column '{}' (index {})",
+                    self.name, self.index
+                )
+            })?;
         Ok(ColumnInfo::new(
             self.name,
             self.index,
             self.column_name_from,
             dtype,
             dtype_from,
-        ))
+        )
+        .with_observed_dtypes(observed_dtypes))
+    }
+}
+
+/// Applies `int_as_float` to the dtype guessed for a column
+fn guessed_dtype(dtype: DType, options: &LoadSheetOrTableOptions) -> (DType, DTypeFrom) {
+    match dtype {
+        DType::Int if options.int_as_float => (DType::Float, DTypeFrom::IntAsFloat),
+        _ => (dtype, DTypeFrom::Guessed),
     }
 }
 
@@ -295,11 +460,17 @@ pub(crate) trait CalamineDataProvider {
     fn get_as_string(&self, pos: (usize, usize)) -> Option<String>;
     fn dtype_for_column(
         &self,
-        start_row: usize,
-        end_row: usize,
+        sample_rows: &[Range<usize>],
+        col: usize,
+        options: &LoadSheetOrTableOptions,
+    ) -> FastExcelResult<(DType, Vec<DType>)>;
+    fn is_null_column(
+        &self,
+        sample_rows: &[Range<usize>],
         col: usize,
-        dtype_coercion: &DTypeCoercion,
-    ) -> FastExcelResult<DType>;
+        null_values: &NullValues,
+        error_as_null: bool,
+    ) -> bool;
 }
 
 impl CalamineDataProvider for ExcelSheetData<'_> {
@@ -313,12 +484,21 @@ impl CalamineDataProvider for ExcelSheetData<'_> {
 
     fn dtype_for_column(
         &self,
-        start_row: usize,
-        end_row: usize,
+        sample_rows: &[Range<usize>],
+        col: usize,
+        options: &LoadSheetOrTableOptions,
+    ) -> FastExcelResult<(DType, Vec<DType>)> {
+        self.dtype_for_column(sample_rows, col, options)
+    }
+
+    fn is_null_column(
+        &self,
+        sample_rows: &[Range<usize>],
         col: usize,
-        dtype_coercion: &DTypeCoercion,
-    ) -> FastExcelResult<DType> {
-        self.dtype_for_column(start_row, end_row, col, dtype_coercion)
+        null_values: &NullValues,
+        error_as_null: bool,
+    ) -> bool {
+        self.is_null_column(sample_rows, col, null_values, error_as_null)
     }
 }
 
@@ -333,26 +513,91 @@ impl CalamineDataProvider for calamine::Range<calamine::Data> {
 
     fn dtype_for_column(
         &self,
-        start_row: usize,
-        end_row: usize,
+        sample_rows: &[Range<usize>],
         col: usize,
-        dtype_coercion: &DTypeCoercion,
-    ) -> FastExcelResult<DType> {
-        get_dtype_for_column(self, start_row, end_row, col, dtype_coercion)
+        options: &LoadSheetOrTableOptions,
+    ) -> FastExcelResult<(DType, Vec<DType>)> {
+        get_dtype_for_column(
+            self,
+            sample_rows.iter().cloned().flatten(),
+            col,
+            &options.dtype_coercion,
+            &options.null_values,
+            options.error_as_null,
+            options.parse_string_booleans,
+            options.parse_numeric_strings,
+        )
+    }
+
+    fn is_null_column(
+        &self,
+        sample_rows: &[Range<usize>],
+        col: usize,
+        null_values: &NullValues,
+        error_as_null: bool,
+    ) -> bool {
+        is_null_column(
+            self,
+            sample_rows.iter().cloned().flatten(),
+            col,
+            null_values,
+            error_as_null,
+        )
+    }
+}
+
+/// Default prefix of the names generated for columns without a name
+pub(crate) const DEFAULT_UNNAMED_COLUMN_PREFIX: &str = "__UNNAMED__";
+
+/// Returns the indices of the columns selected along with `column_names`, which are named in
+/// the order of the selection
+pub(crate) fn named_column_indices(
+    names: &[String],
+    column_selection: &[IdxOrName],
+) -> FastExcelResult<Vec<usize>> {
+    if column_selection.len() != names.len() {
+        return Err(FastExcelErrorKind::InvalidParameters(
+            "column_names and use_columns must have the same length".to_string(),
+        )
+        .into());
     }
+    column_selection
+        .iter()
+        .map(|idx_or_name| match idx_or_name {
+            IdxOrName::Idx(idx) => Ok(*idx),
+            IdxOrName::Name(name) => Err(FastExcelErrorKind::InvalidParameters(format!(
+                "use_columns can only contain integers when used with columns_names, got \"{name}\""
+            ))
+            .into()),
+        })
+        .collect()
+}
+
+/// Joins the cells of a column's header rows, skipping empty ones. `None` if they are all empty
+pub(crate) fn multi_row_header_name<D: CalamineDataProvider>(
+    data: &D,
+    rows: Range<usize>,
+    col_idx: usize,
+) -> Option<String> {
+    let names = rows
+        .filter_map(|row_idx| data.get_as_string((row_idx, col_idx)))
+        .filter(|name| !name.is_empty())
+        .collect::<Vec<_>>();
+    (!names.is_empty()).then(|| names.join(MULTI_ROW_HEADER_SEPARATOR))
 }
 
 pub(crate) fn build_available_columns_info<D: CalamineDataProvider>(
     data: &D,
     selected_columns: &SelectedColumns,
     header: &Header,
+    unnamed_column_prefix: &str,
 ) -> FastExcelResult<Vec<ColumnInfoBuilder>> {
     let width = data.width();
     match header {
         Header::None => Ok((0..width)
             .map(|col_idx| {
                 ColumnInfoBuilder::new(
-                    format!("__UNNAMED__{col_idx}"),
+                    format!("{unnamed_column_prefix}{col_idx}"),
                     col_idx,
                     ColumnNameFrom::Generated,
                 )
@@ -366,7 +611,22 @@ pub(crate) fn build_available_columns_info<D: CalamineDataProvider>(
                     })
                     .unwrap_or_else(|| {
                         ColumnInfoBuilder::new(
-                            format!("__UNNAMED__{col_idx}"),
+                            format!("{unnamed_column_prefix}{col_idx}"),
+                            col_idx,
+                            ColumnNameFrom::Generated,
+                        )
+                    })
+            })
+            .collect()),
+        Header::AtMultiple(rows) => Ok((0..width)
+            .map(|col_idx| {
+                multi_row_header_name(data, rows.clone(), col_idx)
+                    .map(|col_name| {
+                        ColumnInfoBuilder::new(col_name, col_idx, ColumnNameFrom::LookedUp)
+                    })
+                    .unwrap_or_else(|| {
+                        ColumnInfoBuilder::new(
+                            format!("{unnamed_column_prefix}{col_idx}"),
                             col_idx,
                             ColumnNameFrom::Generated,
                         )
@@ -375,24 +635,7 @@ pub(crate) fn build_available_columns_info<D: CalamineDataProvider>(
             .collect()),
         Header::With(names) => {
             if let SelectedColumns::Selection(column_selection) = selected_columns {
-                if column_selection.len() != names.len() {
-                    return Err(FastExcelErrorKind::InvalidParameters(
-                        "column_names and use_columns must have the same length".to_string(),
-                    )
-                    .into());
-                }
-                let selected_indices = column_selection
-                        .iter()
-                        .map(|idx_or_name| {
-                            match idx_or_name {
-                        IdxOrName::Idx(idx) => Ok(*idx),
-                        IdxOrName::Name(name) => Err(FastExcelErrorKind::InvalidParameters(
-                            format!("use_columns can only contain integers when used with columns_names, got \"{name}\"")
-                        )
-                        .into()),
-                    }
-                        })
-                        .collect::<FastExcelResult<Vec<_>>>()?;
+                let selected_indices = named_column_indices(names, column_selection)?;
 
                 Ok((0..width)
                     .map(|col_idx| {
@@ -411,7 +654,7 @@ pub(crate) fn build_available_columns_info<D: CalamineDataProvider>(
                                 ColumnNameFrom::Provided,
                             ),
                             None => ColumnInfoBuilder::new(
-                                format!("__UNNAMED__{col_idx}"),
+                                format!("{unnamed_column_prefix}{col_idx}"),
                                 col_idx,
                                 ColumnNameFrom::Generated,
                             ),
@@ -428,7 +671,7 @@ pub(crate) fn build_available_columns_info<D: CalamineDataProvider>(
                     })
                     .chain((nameless_start_idx..width).map(|col_idx| {
                         ColumnInfoBuilder::new(
-                            format!("__UNNAMED__{col_idx}"),
+                            format!("{unnamed_column_prefix}{col_idx}"),
                             col_idx,
                             ColumnNameFrom::Generated,
                         )
@@ -439,47 +682,337 @@ pub(crate) fn build_available_columns_info<D: CalamineDataProvider>(
     }
 }
 
-fn alias_for_name(name: &str, existing_names: &[String]) -> String {
+fn alias_for_name(
+    name: &str,
+    existing_names: &[String],
+    duplicate_column_strategy: DuplicateColumnStrategy,
+) -> FastExcelResult<String> {
     #[inline]
-    fn rec(name: &str, existing_names: &[String], depth: usize) -> String {
+    fn rec(name: &str, existing_names: &[String], separator: char, depth: usize) -> String {
         let alias = if depth == 0 {
             name.to_owned()
         } else {
-            format!("{name}_{depth}")
+            format!("{name}{separator}{depth}")
         };
         match existing_names
             .iter()
             .any(|existing_name| existing_name == &alias)
         {
-            true => rec(name, existing_names, depth + 1),
+            true => rec(name, existing_names, separator, depth + 1),
             false => alias,
         }
     }
 
-    rec(name, existing_names, 0)
+    match duplicate_column_strategy {
+        DuplicateColumnStrategy::Suffix => Ok(rec(name, existing_names, '_', 0)),
+        DuplicateColumnStrategy::Pandas => Ok(rec(name, existing_names, '.', 0)),
+        DuplicateColumnStrategy::Error => {
+            if existing_names
+                .iter()
+                .any(|existing_name| existing_name == name)
+            {
+                Err(FastExcelErrorKind::InvalidParameters(format!(
+                    "duplicate column name \"{name}\""
+                ))
+                .into())
+            } else {
+                Ok(name.to_owned())
+            }
+        }
+    }
 }
 
-pub(crate) fn build_available_columns<D: CalamineDataProvider>(
+/// Gives their new name to the columns referred to by `rename_columns`, by index or by their
+/// name before renaming. A column referred to both ways takes the name given for its index
+fn rename_available_columns_info(
     available_columns_info: Vec<ColumnInfoBuilder>,
-    data: &D,
-    start_row: usize,
-    end_row: usize,
-    specified_dtypes: Option<&DTypes>,
-    dtype_coercion: &DTypeCoercion,
-) -> FastExcelResult<Vec<ColumnInfo>> {
+    rename_columns: &HashMap<IdxOrName, String>,
+) -> FastExcelResult<Vec<ColumnInfoBuilder>> {
+    if let Some(missing_column) = rename_columns.keys().find(|idx_or_name| {
+        !available_columns_info
+            .iter()
+            .any(|column_info| column_info == *idx_or_name)
+    }) {
+        return Err(FastExcelErrorKind::ColumnNotFound(missing_column.clone()).into())
+            .with_context(|| "could not find a column to rename");
+    }
+
+    Ok(available_columns_info
+        .into_iter()
+        .map(|column_info_builder| {
+            match rename_columns
+                .get(&IdxOrName::Idx(column_info_builder.index))
+                .or_else(|| rename_columns.get(&IdxOrName::Name(column_info_builder.name.clone())))
+            {
+                Some(new_name) => ColumnInfoBuilder::new(
+                    new_name.clone(),
+                    column_info_builder.index,
+                    ColumnNameFrom::Provided,
+                ),
+                None => column_info_builder,
+            }
+        })
+        .collect())
+}
+
+/// Sets the right alias for every column, so that column names are unique
+fn alias_available_columns_info(
+    available_columns_info: Vec<ColumnInfoBuilder>,
+    duplicate_column_strategy: DuplicateColumnStrategy,
+) -> FastExcelResult<Vec<ColumnInfoBuilder>> {
     let mut aliased_available_columns = Vec::with_capacity(available_columns_info.len());
 
     available_columns_info
         .into_iter()
         .map(|mut column_info_builder| {
-            // Setting the right alias for every column
-            let alias = alias_for_name(column_info_builder.name(), &aliased_available_columns);
+            let alias = alias_for_name(
+                column_info_builder.name(),
+                &aliased_available_columns,
+                duplicate_column_strategy,
+            )?;
             if alias != column_info_builder.name() {
                 column_info_builder = column_info_builder.with_name(alias.clone());
             }
             aliased_available_columns.push(alias);
+            Ok(column_info_builder)
+        })
+        .collect()
+}
+
+/// Determines the dtype of every column, expanding the sample of columns guessed as null if
+/// needed
+fn finish_columns<D: CalamineDataProvider>(
+    columns_info: Vec<ColumnInfoBuilder>,
+    data: &D,
+    sample_rows: &[Range<usize>],
+    data_rows: Range<usize>,
+    options: &LoadSheetOrTableOptions,
+) -> FastExcelResult<Vec<ColumnInfo>> {
+    let columns = columns_info
+        .into_iter()
+        .map(|column_info_builder| {
             // Setting the dtype info
-            column_info_builder.finish(data, start_row, end_row, specified_dtypes, dtype_coercion)
+            column_info_builder.finish(data, sample_rows, options)
+        })
+        .collect::<FastExcelResult<Vec<_>>>()?;
+    expand_sample_on_null_columns(columns, data, sample_rows, data_rows, options)
+}
+
+/// The available columns of a sheet or table.
+///
+/// When the selected columns are known upfront, only their dtypes are needed to load the data, so
+/// the dtypes of the available columns are only determined once they are requested.
+#[derive(Debug)]
+pub(crate) enum AvailableColumns {
+    Pending(Vec<ColumnInfoBuilder>),
+    Finalized(Vec<ColumnInfo>),
+}
+
+impl AvailableColumns {
+    /// Determines the dtypes of the available columns if it was not done yet
+    pub(crate) fn finalize<D: CalamineDataProvider>(
+        &mut self,
+        data: &D,
+        sample_rows: &[Range<usize>],
+        data_rows: Range<usize>,
+        options: &LoadSheetOrTableOptions,
+    ) -> FastExcelResult<&[ColumnInfo]> {
+        if let Self::Pending(columns_info) = self {
+            let columns = finish_columns(
+                std::mem::take(columns_info),
+                data,
+                sample_rows,
+                data_rows,
+                options,
+            )?;
+            *self = Self::Finalized(columns);
+        }
+        match self {
+            Self::Finalized(columns) => Ok(columns.as_slice()),
+            Self::Pending(_) => unreachable!("available columns were just finalized"),
+        }
+    }
+
+    /// Returns the available column with the given name, with a dtype provided by its name, e.g.
+    /// from a schema
+    pub(crate) fn column_with_dtype(&self, name: &str, dtype: DType) -> Option<ColumnInfo> {
+        match self {
+            Self::Pending(columns_info) => columns_info
+                .iter()
+                .find(|column_info| column_info.name == name)
+                .map(|column_info| {
+                    ColumnInfo::new(
+                        column_info.name.clone(),
+                        column_info.index,
+                        column_info.column_name_from.clone(),
+                        dtype,
+                        DTypeFrom::ProvidedByName,
+                    )
+                }),
+            Self::Finalized(columns) => columns
+                .iter()
+                .find(|column| column.name() == name)
+                .map(|column| column.with_dtype(dtype)),
+        }
+    }
+}
+
+/// Returns the names of the available columns, as in `finalize_column_info`, without
+/// determining their dtypes. No cell apart from the header's is read.
+pub(crate) fn available_column_names<D: CalamineDataProvider>(
+    data: &D,
+    header: &Header,
+    unnamed_column_prefix: &str,
+    duplicate_column_strategy: DuplicateColumnStrategy,
+) -> FastExcelResult<Vec<String>> {
+    let available_columns_info =
+        build_available_columns_info(data, &SelectedColumns::All, header, unnamed_column_prefix)?;
+    Ok(
+        alias_available_columns_info(available_columns_info, duplicate_column_strategy)?
+            .into_iter()
+            .map(|column_info_builder| column_info_builder.name)
+            .collect(),
+    )
+}
+
+/// Columns whose sampled rows are all null may contain values in the rows which were not sampled,
+/// which would silently be loaded as nulls. Such columns either get their dtype determined from
+/// all the data rows, or a warning is emitted.
+fn expand_sample_on_null_columns<D: CalamineDataProvider>(
+    available_columns: Vec<ColumnInfo>,
+    data: &D,
+    sample_rows: &[Range<usize>],
+    data_rows: Range<usize>,
+    options: &LoadSheetOrTableOptions,
+) -> FastExcelResult<Vec<ColumnInfo>> {
+    let sampled_row_count: usize = sample_rows.iter().map(ExactSizeIterator::len).sum();
+    if sampled_row_count >= data_rows.len() {
+        return Ok(available_columns);
+    }
+
+    let data_rows = [data_rows];
+    available_columns
+        .into_iter()
+        .map(|mut column| {
+            if !column.guessed_from_nulls()
+                || data.is_null_column(
+                    &data_rows,
+                    column.index,
+                    &options.null_values,
+                    options.error_as_null,
+                )
+            {
+                return Ok(column);
+            }
+            if options.expand_sample_on_null_columns {
+                let (dtype, observed_dtypes) = data
+                    .dtype_for_column(&data_rows, column.index, options)
+                    .with_context(|| {
+                        format!(
+                            "could not determine dtype for column '{}' (index {})",
+                            column.name, column.index
+                        )
+                    })?;
+                (column.dtype, column.dtype_from) = guessed_dtype(dtype, options);
+                column.observed_dtypes = Some(observed_dtypes);
+            } else {
+                warn!(
+                    "column '{}' (index {}) only contains null values within the {sampled_row_count} sampled rows, but not in the whole data: it will be loaded as nulls. Use `expand_sample_on_null_columns` or a larger `schema_sample_rows`",
+                    column.name, column.index
+                );
+            }
+            Ok(column)
         })
         .collect()
 }
+
+/// Builds the info of every available column, and selects the columns that should be loaded.
+///
+/// Returns the available columns and the selected columns.
+pub(crate) fn finalize_column_info<D: CalamineDataProvider>(
+    data: &D,
+    header: &Header,
+    selected_columns: &SelectedColumns,
+    sample_rows: &[Range<usize>],
+    data_rows: Range<usize>,
+    hidden_columns: &[usize],
+    options: &LoadSheetOrTableOptions,
+) -> FastExcelResult<(AvailableColumns, Vec<ColumnInfo>)> {
+    let available_columns_info = alias_available_columns_info(
+        rename_available_columns_info(
+            build_available_columns_info(
+                data,
+                selected_columns,
+                header,
+                &options.unnamed_column_prefix,
+            )?,
+            &options.rename_columns,
+        )?,
+        options.duplicate_column_strategy,
+    )?;
+
+    // Columns to fill forward have to exist, whether they are selected or not
+    if let Some(missing_column) = options.fill_forward_columns.iter().find(|idx_or_name| {
+        !available_columns_info
+            .iter()
+            .any(|column_info| column_info == *idx_or_name)
+    }) {
+        return Err(FastExcelErrorKind::ColumnNotFound(missing_column.clone()).into())
+            .with_context(|| "could not find a column to fill forward");
+    }
+
+    // A static selection does not depend on dtypes, so only the selected columns are inspected.
+    // This matters for wide sheets of which only a few columns are loaded
+    let static_selection = match selected_columns {
+        SelectedColumns::Selection(selection) => Some(SelectedColumns::select_from(
+            selection,
+            &available_columns_info,
+        )?),
+        SelectedColumns::Pattern(regex) => Some(SelectedColumns::select_matching(
+            regex,
+            &available_columns_info,
+        )),
+        SelectedColumns::Exclude(excluded) => Some(SelectedColumns::select_excluding(
+            excluded,
+            &available_columns_info,
+        )?),
+        SelectedColumns::All | SelectedColumns::DynamicSelection(_) => None,
+    };
+    let (available_columns, mut final_columns) = match static_selection {
+        Some(selected_columns_info) => (
+            AvailableColumns::Pending(available_columns_info),
+            finish_columns(selected_columns_info, data, sample_rows, data_rows, options)?,
+        ),
+        None => {
+            let available_columns = finish_columns(
+                available_columns_info,
+                data,
+                sample_rows,
+                data_rows,
+                options,
+            )?;
+            let final_columns = selected_columns.select_columns(&available_columns)?;
+            (
+                AvailableColumns::Finalized(available_columns),
+                final_columns,
+            )
+        }
+    };
+
+    // Empty columns are only dropped if they were not explicitly selected. Column indices are kept
+    // as is, so data extraction is not affected
+    let auto_selected = matches!(
+        selected_columns,
+        SelectedColumns::All | SelectedColumns::Exclude(_)
+    );
+    if options.skip_empty_columns && auto_selected {
+        // Columns whose sample was expanded are only dropped if all their rows are null
+        final_columns.retain(|column| !column.guessed_from_nulls());
+    }
+    // Same goes for hidden columns
+    if options.skip_hidden_columns && auto_selected {
+        final_columns.retain(|column| !hidden_columns.contains(&column.index));
+    }
+
+    Ok((available_columns, final_columns))
+}
@@ -0,0 +1,452 @@
+use std::fmt::Display;
+
+use arrow::record_batch::RecordBatch;
+use calamine::{CellType, Range};
+
+use crate::{
+    data::{record_batch_from_data_and_columns, ExcelSheetData},
+    error::{ErrorContext, FastExcelErrorKind, FastExcelResult},
+    types::idx_or_name::IdxOrName,
+};
+
+use super::{
+    column_info::{finalize_column_info, ColumnInfo},
+    Header, LoadSheetOrTableOptions, SelectedColumns,
+};
+
+/// A rectangular region of a sheet. Coordinates are 0-based (row, column) positions, absolute
+/// within the sheet. Both `start` and `end` are included in the region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct ExcelRange {
+    start: (u32, u32),
+    end: (u32, u32),
+}
+
+impl ExcelRange {
+    /// Parses a single A1-style cell address ("B2" or "$B$2") into a 0-based (row, column)
+    /// position
+    fn parse_cell(cell: &str) -> FastExcelResult<(u32, u32)> {
+        use FastExcelErrorKind::InvalidParameters;
+
+        let cell = cell.trim().replace('$', "").to_uppercase();
+        let (col, row) = cell.split_at(
+            cell.find(|chr: char| !chr.is_ascii_alphabetic())
+                .unwrap_or(cell.len()),
+        );
+        if col.is_empty() {
+            return Err(InvalidParameters(format!(
+                "cell \"{cell}\" should start with a column letter"
+            ))
+            .into());
+        }
+        if row.is_empty() {
+            return Err(
+                InvalidParameters(format!("cell \"{cell}\" should end with a row number")).into(),
+            );
+        }
+
+        let col_idx = SelectedColumns::col_idx_for_col_as_letter(col)?;
+        let row_number = row.parse::<u32>().map_err(|_| {
+            InvalidParameters(format!(
+                "\"{row}\" is not a valid row number in cell \"{cell}\""
+            ))
+        })?;
+        if row_number == 0 {
+            return Err(InvalidParameters(format!(
+                "row numbers start at 1, got 0 in cell \"{cell}\""
+            ))
+            .into());
+        }
+        let col_idx = u32::try_from(col_idx).map_err(|_| {
+            InvalidParameters(format!(
+                "column \"{col}\" is out of bounds in cell \"{cell}\""
+            ))
+        })?;
+
+        Ok((row_number - 1, col_idx))
+    }
+
+    /// Parses an A1-style range ("B2:D10"), without validating it against a sheet
+    pub(crate) fn parse(a1: &str) -> FastExcelResult<Self> {
+        use FastExcelErrorKind::InvalidParameters;
+
+        match a1.split(':').collect::<Vec<_>>()[..] {
+            [start, end] => {
+                let start = Self::parse_cell(start)
+                    .with_context(|| format!("invalid start cell for range \"{a1}\""))?;
+                let end = Self::parse_cell(end)
+                    .with_context(|| format!("invalid end cell for range \"{a1}\""))?;
+                if end.0 < start.0 || end.1 < start.1 {
+                    return Err(InvalidParameters(format!(
+                        "end of range is before its start: \"{a1}\""
+                    ))
+                    .into());
+                }
+                Ok(Self { start, end })
+            }
+            _ => Err(InvalidParameters(format!(
+                "expected a range of the form \"B2:D10\", got \"{a1}\""
+            ))
+            .into()),
+        }
+    }
+
+    /// Parses an A1-style range ("B2:D10") and validates it against the bounds of `range`
+    pub(crate) fn from_a1<CT: CellType>(range: &Range<CT>, a1: &str) -> FastExcelResult<Self> {
+        use FastExcelErrorKind::InvalidParameters;
+
+        let excel_range = Self::parse(a1)?;
+        match (range.start(), range.end()) {
+            (Some(data_start), Some(data_end)) => {
+                let data_range = Self {
+                    start: data_start,
+                    end: data_end,
+                };
+                if excel_range.overlaps(&data_range) {
+                    Ok(excel_range)
+                } else {
+                    Err(InvalidParameters(format!(
+                        "range {excel_range} is out of the sheet's bounds ({data_range})"
+                    ))
+                    .into())
+                }
+            }
+            _ => Err(InvalidParameters(format!(
+                "cannot select range {excel_range}: the sheet is empty"
+            ))
+            .into()),
+        }
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        self.start.0 <= other.end.0
+            && other.start.0 <= self.end.0
+            && self.start.1 <= other.end.1
+            && other.start.1 <= self.end.1
+    }
+
+    pub(crate) fn start(&self) -> (u32, u32) {
+        self.start
+    }
+
+    pub(crate) fn end(&self) -> (u32, u32) {
+        self.end
+    }
+
+    /// The (height, width) of the region
+    pub(crate) fn size(&self) -> (usize, usize) {
+        (
+            (self.end.0 - self.start.0) as usize + 1,
+            (self.end.1 - self.start.1) as usize + 1,
+        )
+    }
+
+    /// The rows and columns of the region within `data`, relative to the start of `data` and
+    /// clipped to its bounds
+    fn bounds_within(
+        &self,
+        data: &ExcelSheetData,
+    ) -> (std::ops::Range<usize>, std::ops::Range<usize>) {
+        let relative = |(start, end): (u32, u32), data_start: usize, data_len: usize| {
+            let start = (start as usize).saturating_sub(data_start).min(data_len);
+            let end = (end as usize + 1).saturating_sub(data_start).min(data_len);
+            start..end.max(start)
+        };
+        (
+            relative((self.start.0, self.end.0), data.start_row(), data.height()),
+            relative((self.start.1, self.end.1), data.start_col(), data.width()),
+        )
+    }
+
+    /// Builds the info of the columns of the region within `data`. The region has no header, so
+    /// its columns get generated names, after their index within `data`
+    #[allow(dead_code)]
+    pub(crate) fn to_columns(
+        self,
+        data: &ExcelSheetData,
+        options: &LoadSheetOrTableOptions,
+    ) -> FastExcelResult<Vec<ColumnInfo>> {
+        let (rows, columns) = self.bounds_within(data);
+        let selected_columns = SelectedColumns::Selection(columns.map(IdxOrName::Idx).collect());
+        let sample_rows = options.schema_sample_row_ranges(rows.start, rows.end);
+        finalize_column_info(
+            data,
+            &Header::None,
+            &selected_columns,
+            &sample_rows,
+            rows,
+            &[],
+            options,
+        )
+        .map(|(_, columns)| columns)
+    }
+
+    /// Loads the region of `data` as a record batch, with the columns of `to_columns`
+    #[allow(dead_code)]
+    pub(crate) fn to_arrow(
+        self,
+        data: &ExcelSheetData,
+        options: &LoadSheetOrTableOptions,
+    ) -> FastExcelResult<RecordBatch> {
+        let (rows, _) = self.bounds_within(data);
+        let columns = self.to_columns(data, options)?;
+        record_batch_from_data_and_columns(
+            &columns, data, rows.start, rows.start, rows.end, options,
+        )
+        .with_context(|| format!("could not load range {self}"))
+    }
+}
+
+/// A reference to a rectangular region of a given sheet, as found in defined names
+/// (e.g. `'My Sheet'!$A$1:$B$10`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct SheetReference {
+    pub(crate) sheet_name: String,
+    pub(crate) range: ExcelRange,
+}
+
+impl SheetReference {
+    /// Parses a reference to a single area of a sheet. References to a single cell are
+    /// considered as a range of one cell.
+    pub(crate) fn parse(reference: &str) -> FastExcelResult<Self> {
+        use FastExcelErrorKind::InvalidParameters;
+
+        let reference = reference.trim().trim_start_matches('=');
+        let Some((sheet_name, area)) = reference.rsplit_once('!') else {
+            return Err(InvalidParameters(format!(
+                "\"{reference}\" is not a reference to a range of a sheet"
+            ))
+            .into());
+        };
+        if area.contains(',') {
+            return Err(
+                InvalidParameters(format!("\"{reference}\" refers to several areas")).into(),
+            );
+        }
+
+        let sheet_name = match sheet_name
+            .strip_prefix('\'')
+            .and_then(|name| name.strip_suffix('\''))
+        {
+            Some(quoted_name) => quoted_name.replace("''", "'"),
+            None if sheet_name.contains('!') => {
+                return Err(
+                    InvalidParameters(format!("\"{reference}\" refers to several areas")).into(),
+                )
+            }
+            None => sheet_name.to_string(),
+        };
+
+        let range = if area.contains(':') {
+            ExcelRange::parse(area)
+        } else {
+            ExcelRange::parse_cell(area).map(|cell| ExcelRange {
+                start: cell,
+                end: cell,
+            })
+        }
+        .with_context(|| format!("\"{reference}\" is not a reference to a range of a sheet"))?;
+
+        Ok(Self { sheet_name, range })
+    }
+}
+
+/// Converts a 0-based column index to its letters, e.g. 27 becomes "AB"
+fn col_as_letters(col_idx: u32) -> String {
+    let mut letters = Vec::new();
+    let mut remaining = col_idx as usize + 1;
+    while remaining > 0 {
+        let rem = (remaining - 1) % 26;
+        letters.push(SelectedColumns::ALPHABET[rem]);
+        remaining = (remaining - 1) / 26;
+    }
+    letters.iter().rev().collect()
+}
+
+impl Display for ExcelRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}{}:{}{}",
+            col_as_letters(self.start.1),
+            self.start.0 + 1,
+            col_as_letters(self.end.1),
+            self.end.0 + 1
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use calamine::{Cell, Data};
+    use pretty_assertions::assert_eq;
+    use rstest::{fixture, rstest};
+
+    use super::*;
+
+    #[fixture]
+    fn range() -> Range<Data> {
+        // Data spanning B2:E20
+        Range::from_sparse(vec![
+            Cell::new((1, 1), Data::String("top left".to_string())),
+            Cell::new((19, 4), Data::String("bottom right".to_string())),
+        ])
+    }
+
+    #[rstest]
+    #[case("B2:D10", (1, 1), (9, 3))]
+    #[case("b2:e20", (1, 1), (19, 4))]
+    #[case("A1:B2", (0, 0), (1, 1))]
+    #[case("C5:C5", (4, 2), (4, 2))]
+    #[case("$B$2:$D$10", (1, 1), (9, 3))]
+    // Beyond the data is fine as long as the range overlaps it
+    #[case("D10:AA100", (9, 3), (99, 26))]
+    fn excel_range_from_valid_a1(
+        range: Range<Data>,
+        #[case] a1: &str,
+        #[case] expected_start: (u32, u32),
+        #[case] expected_end: (u32, u32),
+    ) {
+        let excel_range = ExcelRange::from_a1(&range, a1).unwrap();
+        assert_eq!(excel_range.start(), expected_start);
+        assert_eq!(excel_range.end(), expected_end);
+    }
+
+    #[rstest]
+    #[case("B2:D", "should end with a row number")]
+    #[case("2:10", "should start with a column letter")]
+    #[case("B2", "expected a range of the form")]
+    #[case("B2:C3:D4", "expected a range of the form")]
+    #[case("B0:C3", "row numbers start at 1")]
+    #[case("B2:C3x", "is not a valid row number")]
+    #[case("D10:B2", "end of range is before its start")]
+    #[case("F1:G30", "is out of the sheet's bounds (B2:E20)")]
+    #[case("A21:E30", "is out of the sheet's bounds (B2:E20)")]
+    fn excel_range_from_invalid_a1(
+        range: Range<Data>,
+        #[case] a1: &str,
+        #[case] expected_message: &str,
+    ) {
+        let error = ExcelRange::from_a1(&range, a1).unwrap_err();
+        assert!(matches!(
+            error.kind,
+            FastExcelErrorKind::InvalidParameters(_)
+        ));
+        assert!(
+            error.to_string().contains(expected_message),
+            "{error} does not contain {expected_message}"
+        );
+    }
+
+    #[test]
+    fn excel_range_from_a1_empty_sheet() {
+        let range: Range<Data> = Range::empty();
+        let error = ExcelRange::from_a1(&range, "A1:B2").unwrap_err();
+        assert!(error.to_string().contains("the sheet is empty"));
+    }
+
+    #[rstest]
+    #[case("A1:D10", (10, 4))]
+    #[case("C5:C5", (1, 1))]
+    #[case("B2:AA3", (2, 26))]
+    fn excel_range_size(#[case] a1: &str, #[case] expected_size: (usize, usize)) {
+        assert_eq!(ExcelRange::parse(a1).unwrap().size(), expected_size);
+    }
+
+    #[fixture]
+    fn data() -> ExcelSheetData<'static> {
+        // Data spanning B2:D4, without a header
+        Range::from_sparse(vec![
+            Cell::new((1, 1), Data::String("a".to_string())),
+            Cell::new((1, 2), Data::Float(1.0)),
+            Cell::new((1, 3), Data::Bool(true)),
+            Cell::new((2, 1), Data::String("b".to_string())),
+            Cell::new((2, 2), Data::Float(2.0)),
+            Cell::new((2, 3), Data::Bool(false)),
+            Cell::new((3, 1), Data::String("c".to_string())),
+            Cell::new((3, 2), Data::Float(3.0)),
+        ])
+        .into()
+    }
+
+    #[rstest]
+    #[case("C2:D3", &["__UNNAMED__1", "__UNNAMED__2"], 2)]
+    #[case("B4:B4", &["__UNNAMED__0"], 1)]
+    // Clipped to the data
+    #[case("A1:C10", &["__UNNAMED__0", "__UNNAMED__1"], 3)]
+    fn excel_range_to_arrow(
+        data: ExcelSheetData<'static>,
+        #[case] a1: &str,
+        #[case] expected_names: &[&str],
+        #[case] expected_rows: usize,
+    ) {
+        let excel_range = ExcelRange::parse(a1).unwrap();
+        let options = LoadSheetOrTableOptions::default();
+
+        let columns = excel_range.to_columns(&data, &options).unwrap();
+        let names: Vec<&str> = columns.iter().map(|column| column.name.as_str()).collect();
+        assert_eq!(names, expected_names);
+
+        let record_batch = excel_range.to_arrow(&data, &options).unwrap();
+        let schema = record_batch.schema();
+        let batch_names: Vec<&str> = schema.fields().iter().map(|f| f.name().as_str()).collect();
+        assert_eq!(batch_names, expected_names);
+        assert_eq!(record_batch.num_rows(), expected_rows);
+    }
+
+    #[test]
+    fn excel_range_to_arrow_values() {
+        let record_batch = ExcelRange::parse("C3:D4")
+            .unwrap()
+            .to_arrow(&data(), &LoadSheetOrTableOptions::default())
+            .unwrap();
+        assert_eq!(
+            record_batch.column(0).as_ref(),
+            &arrow::array::Float64Array::from(vec![2.0, 3.0]) as &dyn arrow::array::Array
+        );
+        assert_eq!(
+            record_batch.column(1).as_ref(),
+            &arrow::array::BooleanArray::from(vec![Some(false), None]) as &dyn arrow::array::Array
+        );
+    }
+
+    #[rstest]
+    #[case("Sheet1!$A$1:$B$10", "Sheet1", "A1:B10")]
+    #[case("=Sheet1!B2:C3", "Sheet1", "B2:C3")]
+    #[case("'My Sheet'!$C$5", "My Sheet", "C5:C5")]
+    #[case("'It''s mine'!$A$1:$A$2", "It's mine", "A1:A2")]
+    #[case("'Sales, 2024'!$A$1:$A$2", "Sales, 2024", "A1:A2")]
+    fn sheet_reference_valid(
+        #[case] reference: &str,
+        #[case] expected_sheet_name: &str,
+        #[case] expected_range: &str,
+    ) {
+        let sheet_reference = SheetReference::parse(reference).unwrap();
+        assert_eq!(sheet_reference.sheet_name, expected_sheet_name);
+        assert_eq!(sheet_reference.range.to_string(), expected_range);
+    }
+
+    #[rstest]
+    #[case("42", "is not a reference to a range of a sheet")]
+    #[case("Sheet1!$A:$A", "is not a reference to a range of a sheet")]
+    #[case("SUM(Sheet1!$A$1:$A$2)", "is not a reference to a range of a sheet")]
+    #[case("Sheet1!$A$1:$A$2,Sheet1!$C$1:$C$2", "refers to several areas")]
+    #[case("Sheet1!$A$1,$C$1", "refers to several areas")]
+    fn sheet_reference_invalid(#[case] reference: &str, #[case] expected_message: &str) {
+        let error = SheetReference::parse(reference).unwrap_err();
+        assert!(
+            error.to_string().contains(expected_message),
+            "{error} does not contain {expected_message}"
+        );
+    }
+
+    #[rstest]
+    #[case(0, "A")]
+    #[case(25, "Z")]
+    #[case(26, "AA")]
+    #[case(27, "AB")]
+    #[case(2810, "DDC")]
+    fn column_index_as_letters(#[case] col_idx: u32, #[case] expected: &str) {
+        assert_eq!(col_as_letters(col_idx), expected);
+    }
+}
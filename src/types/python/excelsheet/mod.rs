@@ -1,32 +1,65 @@
 pub(crate) mod column_info;
+pub(crate) mod excel_range;
+pub(crate) mod stream;
 pub(crate) mod table;
 
-use calamine::{CellType, Range, Sheet as CalamineSheet, SheetVisible as CalamineSheetVisible};
-use std::{cmp, collections::HashSet, fmt::Debug, str::FromStr};
+use calamine::{
+    CellType, DataType, Range, Sheet as CalamineSheet, SheetVisible as CalamineSheetVisible,
+};
+use std::{
+    cmp,
+    collections::{HashMap, HashSet},
+    fmt::Debug,
+    fs::File,
+    io::{BufWriter, Write},
+    ops::Range as RowRange,
+    str::FromStr,
+    sync::Arc,
+};
 
-use arrow::{pyarrow::ToPyArrow, record_batch::RecordBatch};
+use arrow::{
+    array::{Array, StringArray},
+    compute::concat_batches,
+    datatypes::{DataType as ArrowDataType, Field, Schema},
+    pyarrow::{PyArrowType, ToPyArrow},
+    record_batch::RecordBatch,
+};
 
 use pyo3::{
-    prelude::{pyclass, pymethods, PyAnyMethods, Python},
-    types::PyList,
-    Bound, PyAny, PyObject, PyResult, ToPyObject,
+    exceptions::PyTypeError,
+    prelude::{pyclass, pymethods, PyAnyMethods, PyModule, Python},
+    types::{PyDict, PyList},
+    Bound, FromPyObject, PyAny, PyObject, PyResult, ToPyObject,
 };
+use regex::Regex;
 
 use crate::{
-    data::{record_batch_from_data_and_columns, ExcelSheetData},
+    data::{record_batch_from_data_and_columns, selected_columns_to_schema, ExcelSheetData},
     error::{
         py_errors::IntoPyResult, ErrorContext, FastExcelError, FastExcelErrorKind, FastExcelResult,
     },
     types::{dtype::DTypes, idx_or_name::IdxOrName},
 };
-use crate::{types::dtype::DTypeCoercion, utils::schema::get_schema_sample_rows};
+use crate::{
+    types::dtype::{DType, DTypeCoercion, NullValues, DEFAULT_FLOAT_STRING_PRECISION},
+    utils::{
+        schema::{get_schema_sample_row_ranges, SchemaSampleStrategy, DEFAULT_SCHEMA_SAMPLE_ROWS},
+        xlsx::{self, XlsxSource},
+    },
+};
 
-use self::column_info::{build_available_columns, build_available_columns_info, ColumnInfo};
+use self::column_info::{
+    finalize_column_info, named_column_indices, AvailableColumns, ColumnInfo,
+    DuplicateColumnStrategy, SelectableColumn, DEFAULT_UNNAMED_COLUMN_PREFIX,
+};
+use self::stream::{write_batches, OutputFormat};
 
 #[derive(Debug)]
 pub(crate) enum Header {
     None,
     At(usize),
+    /// Several consecutive rows, whose cells are joined to name the columns
+    AtMultiple(RowRange<usize>),
     With(Vec<String>),
 }
 
@@ -44,12 +77,121 @@ impl Header {
     pub(crate) fn offset(&self) -> usize {
         match self {
             Header::At(index) => index + 1,
+            Header::AtMultiple(rows) => rows.end,
             Header::None => 0,
             Header::With(_) => 0,
         }
     }
 }
 
+/// Maximum number of rows scanned when detecting the header row of a sheet
+const HEADER_ROW_DETECTION_ROWS: usize = 20;
+
+impl Header {
+    /// Builds the header of a sheet from its `header_row` and `column_names` parameters. The
+    /// range of the sheet is expected to start at its header row, unless it should be detected.
+    pub(crate) fn for_sheet<DT: CellType + DataType>(
+        header_row: Option<SelectedHeaderRow>,
+        column_names: Option<Vec<String>>,
+        range: &Range<DT>,
+    ) -> Self {
+        if let (Some(SelectedHeaderRow::Rows { start, end }), None) = (header_row, &column_names) {
+            return Header::AtMultiple(0..end - start);
+        }
+        let data_header_row = header_row.map(|header_row| match header_row {
+            SelectedHeaderRow::Row(_) | SelectedHeaderRow::Rows { .. } => 0,
+            SelectedHeaderRow::Auto => Self::detect_row(range),
+        });
+        Self::new(data_header_row, column_names)
+    }
+
+    /// Detects the header row of a range: the first row whose non-empty cells are all strings,
+    /// followed by a row that looks like data, i.e. containing at least one non-string value.
+    ///
+    /// Only the first rows of the range are scanned, and the first row is used if no such row
+    /// is found.
+    pub(crate) fn detect_row<DT: CellType + DataType>(range: &Range<DT>) -> usize {
+        let rows: Vec<_> = range.rows().take(HEADER_ROW_DETECTION_ROWS + 1).collect();
+        rows.windows(2)
+            .position(|rows| {
+                let (header, data) = (rows[0], rows[1]);
+                header.iter().any(|cell| !cell.is_empty())
+                    && header
+                        .iter()
+                        .all(|cell| cell.is_empty() || cell.is_string())
+                    && data
+                        .iter()
+                        .any(|cell| !cell.is_empty() && !cell.is_string())
+            })
+            .unwrap_or(0)
+    }
+}
+
+/// Separator of the cells of a column's header rows, when the header spans several rows
+pub(crate) const MULTI_ROW_HEADER_SEPARATOR: &str = " / ";
+
+/// The `header_row` parameter of a sheet: either the index of a row, a range of rows whose cells
+/// are joined, or `"auto"` to detect it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SelectedHeaderRow {
+    Row(usize),
+    /// Rows `start..end`, `end` being excluded
+    Rows {
+        start: usize,
+        end: usize,
+    },
+    Auto,
+}
+
+impl SelectedHeaderRow {
+    /// Builds the header rows from a python `range` of consecutive rows
+    fn from_py_range(py_range: &Bound<'_, PyAny>) -> FastExcelResult<Self> {
+        let attr = |name: &str| {
+            py_range
+                .getattr(name)
+                .and_then(|value| value.extract::<usize>())
+                .ok()
+        };
+        match (attr("start"), attr("stop"), attr("step")) {
+            (Some(start), Some(end), Some(1)) if start + 1 == end => Ok(Self::Row(start)),
+            (Some(start), Some(end), Some(1)) if start < end => Ok(Self::Rows { start, end }),
+            _ => Err(FastExcelErrorKind::InvalidParameters(format!(
+                "header_row must be a non-empty range of consecutive rows, got {py_range:?}"
+            ))
+            .into()),
+        }
+    }
+}
+
+/// Whether a Python object is a `range`
+fn is_py_range(py_any: &Bound<'_, PyAny>) -> bool {
+    PyModule::import_bound(py_any.py(), "builtins")
+        .and_then(|builtins| builtins.getattr("range"))
+        .and_then(|range_type| py_any.is_instance(&range_type))
+        .unwrap_or(false)
+}
+
+impl FromPyObject<'_> for SelectedHeaderRow {
+    fn extract_bound(py_header_row: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(row) = py_header_row.extract::<usize>() {
+            Ok(Self::Row(row))
+        } else if is_py_range(py_header_row) {
+            Self::from_py_range(py_header_row)
+        } else if py_header_row
+            .extract::<String>()
+            .is_ok_and(|header_row| header_row == "auto")
+        {
+            Ok(Self::Auto)
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "header_row must be an int, a range, \"auto\" or None, got {py_header_row:?}"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
 pub(crate) struct Pagination {
     skip_rows: usize,
     n_rows: Option<usize>,
@@ -81,6 +223,255 @@ impl Pagination {
     }
 }
 
+/// Options driving how the columns of a sheet or a table are built
+#[derive(Clone)]
+pub(crate) struct LoadSheetOrTableOptions {
+    pub(crate) schema_sample_rows: Option<usize>,
+    pub(crate) schema_sample_strategy: SchemaSampleStrategy,
+    /// Whether columns which only contain null values within the sample should have their dtype
+    /// determined from all their rows
+    pub(crate) expand_sample_on_null_columns: bool,
+    pub(crate) dtype_coercion: DTypeCoercion,
+    pub(crate) dtypes: Option<DTypes>,
+    /// Whether auto-selected columns containing only null values should be dropped
+    pub(crate) skip_empty_columns: bool,
+    /// Whether auto-selected hidden columns should be dropped
+    pub(crate) skip_hidden_columns: bool,
+    /// Whether the data stops at its first row whose cells are all empty
+    pub(crate) stop_at_empty_row: bool,
+    pub(crate) null_values: NullValues,
+    /// Whether cells containing any error (e.g. `#DIV/0!`) are read as null, rather than only
+    /// the errors commonly used as missing values
+    pub(crate) error_as_null: bool,
+    /// Whether columns of "true"/"false"/"yes"/"no" strings are read as booleans
+    pub(crate) parse_string_booleans: bool,
+    /// Whether columns of numbers stored as text (e.g. "1.2E5") are read as floats
+    pub(crate) parse_numeric_strings: bool,
+    /// Whether columns guessed as integers are read as floats
+    pub(crate) int_as_float: bool,
+    /// Whether leading and trailing whitespace is removed from the strings of string columns
+    pub(crate) trim_strings: bool,
+    /// Maximum number of digits after the decimal point of floats converted to strings
+    pub(crate) float_string_precision: usize,
+    /// Whether numbers formatted with leading zeros (e.g. "00000") are read as the text Excel
+    /// displays, e.g. 1234 as "01234"
+    pub(crate) keep_leading_zeros: bool,
+    /// Timezone the datetime columns are tagged with. Values are not converted
+    pub(crate) datetime_tz: Option<String>,
+    /// Prefix of the names generated for columns without a name, followed by the column's index
+    pub(crate) unnamed_column_prefix: String,
+    /// How columns sharing the same name are told apart
+    pub(crate) duplicate_column_strategy: DuplicateColumnStrategy,
+    /// Whether the totals row of a table should be left out of its data. Ignored for sheets
+    pub(crate) exclude_totals_row: bool,
+    /// Columns whose null values are replaced with the last non-null value above them
+    pub(crate) fill_forward_columns: Vec<IdxOrName>,
+    /// New names of columns, applied before names are deduplicated
+    pub(crate) rename_columns: HashMap<IdxOrName, String>,
+}
+
+impl Default for LoadSheetOrTableOptions {
+    fn default() -> Self {
+        Self {
+            schema_sample_rows: Some(DEFAULT_SCHEMA_SAMPLE_ROWS),
+            schema_sample_strategy: SchemaSampleStrategy::Head,
+            expand_sample_on_null_columns: false,
+            dtype_coercion: DTypeCoercion::Coerce,
+            dtypes: None,
+            skip_empty_columns: false,
+            skip_hidden_columns: false,
+            stop_at_empty_row: false,
+            null_values: NullValues::default(),
+            error_as_null: false,
+            parse_string_booleans: false,
+            parse_numeric_strings: false,
+            int_as_float: false,
+            trim_strings: false,
+            float_string_precision: DEFAULT_FLOAT_STRING_PRECISION,
+            keep_leading_zeros: false,
+            datetime_tz: None,
+            unnamed_column_prefix: DEFAULT_UNNAMED_COLUMN_PREFIX.to_owned(),
+            duplicate_column_strategy: DuplicateColumnStrategy::Suffix,
+            exclude_totals_row: false,
+            fill_forward_columns: Vec::new(),
+            rename_columns: HashMap::new(),
+        }
+    }
+}
+
+/// Extracts the value of a keyword argument, naming the argument in type errors as pyo3 does
+fn extract_kwarg<'py, T: FromPyObject<'py>>(name: &str, value: &Bound<'py, PyAny>) -> PyResult<T> {
+    value.extract().map_err(|err| {
+        if err.is_instance_of::<PyTypeError>(value.py()) {
+            PyTypeError::new_err(format!(
+                "argument '{name}': {}",
+                err.value_bound(value.py())
+            ))
+        } else {
+            err
+        }
+    })
+}
+
+impl LoadSheetOrTableOptions {
+    /// Builds the options from the keyword arguments shared by the `load_*` methods, the options
+    /// which are not passed keeping their default value. This is where the options which are
+    /// invalid on their own are rejected
+    pub(crate) fn from_kwargs(kwargs: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let mut options = Self::default();
+        for (key, value) in kwargs.into_iter().flatten() {
+            let name: String = key.extract()?;
+            match name.as_str() {
+                "schema_sample_rows" => options.schema_sample_rows = extract_kwarg(&name, &value)?,
+                "schema_sample_strategy" => {
+                    options.schema_sample_strategy = extract_kwarg(&name, &value)?
+                }
+                "expand_sample_on_null_columns" => {
+                    options.expand_sample_on_null_columns = extract_kwarg(&name, &value)?
+                }
+                "dtype_coercion" => options.dtype_coercion = extract_kwarg(&name, &value)?,
+                "dtypes" => options.dtypes = extract_kwarg(&name, &value)?,
+                "skip_empty_columns" => options.skip_empty_columns = extract_kwarg(&name, &value)?,
+                "skip_hidden_columns" => {
+                    options.skip_hidden_columns = extract_kwarg(&name, &value)?
+                }
+                "stop_at_empty_row" => options.stop_at_empty_row = extract_kwarg(&name, &value)?,
+                "null_values" => {
+                    options.null_values =
+                        extract_kwarg::<Option<Vec<String>>>(&name, &value)?.into()
+                }
+                "error_as_null" => options.error_as_null = extract_kwarg(&name, &value)?,
+                "parse_string_booleans" => {
+                    options.parse_string_booleans = extract_kwarg(&name, &value)?
+                }
+                "parse_numeric_strings" => {
+                    options.parse_numeric_strings = extract_kwarg(&name, &value)?
+                }
+                "int_as_float" => options.int_as_float = extract_kwarg(&name, &value)?,
+                "trim_strings" => options.trim_strings = extract_kwarg(&name, &value)?,
+                "float_string_precision" => {
+                    options.float_string_precision = extract_kwarg(&name, &value)?
+                }
+                "keep_leading_zeros" => options.keep_leading_zeros = extract_kwarg(&name, &value)?,
+                "datetime_tz" => options.datetime_tz = extract_kwarg(&name, &value)?,
+                "unnamed_column_prefix" => {
+                    options.unnamed_column_prefix = extract_kwarg(&name, &value)?
+                }
+                "duplicate_column_strategy" => {
+                    options.duplicate_column_strategy = extract_kwarg(&name, &value)?
+                }
+                "fill_forward_columns" => {
+                    options.fill_forward_columns =
+                        extract_kwarg::<Option<_>>(&name, &value)?.unwrap_or_default()
+                }
+                "rename_columns" => {
+                    options.rename_columns =
+                        extract_kwarg::<Option<_>>(&name, &value)?.unwrap_or_default()
+                }
+                _ => {
+                    return Err(PyTypeError::new_err(format!(
+                        "got an unexpected keyword argument '{name}'"
+                    )))
+                }
+            }
+        }
+
+        if let Some("") = options.datetime_tz.as_deref() {
+            return Err(FastExcelErrorKind::InvalidParameters(
+                "datetime_tz cannot be an empty string".to_string(),
+            )
+            .into())
+            .into_pyresult();
+        }
+        Ok(options)
+    }
+
+    /// Whether the null values of a column should be filled forward
+    pub(crate) fn fills_forward(&self, column: &ColumnInfo) -> bool {
+        self.fill_forward_columns
+            .iter()
+            .any(|idx_or_name| column.matches(idx_or_name))
+    }
+
+    /// Runs the consistency checks which do not require any data, so that contradictory options
+    /// fail before the sheet or table is read. `names_looked_up` tells whether the names of the
+    /// columns are read from the data, i.e. from a header row or the table's definition
+    pub(crate) fn validate(
+        &self,
+        names_looked_up: bool,
+        column_names: Option<&[String]>,
+        selected_columns: &SelectedColumns,
+    ) -> FastExcelResult<()> {
+        if let (Some(names), SelectedColumns::Selection(column_selection)) =
+            (column_names, selected_columns)
+        {
+            named_column_indices(names, column_selection)?;
+        }
+        if let Some(names) = column_names {
+            if self.duplicate_column_strategy == DuplicateColumnStrategy::Error {
+                let mut seen_names = HashSet::with_capacity(names.len());
+                if let Some(name) = names.iter().find(|name| !seen_names.insert(name.as_str())) {
+                    return Err(FastExcelErrorKind::InvalidParameters(format!(
+                        "duplicate column name \"{name}\" in column_names"
+                    ))
+                    .into());
+                }
+            }
+        }
+        if !names_looked_up && column_names.is_none() {
+            // Without a header row nor column names, all names are generated
+            let referenced_columns = match selected_columns {
+                SelectedColumns::Selection(selection) | SelectedColumns::Exclude(selection) => {
+                    selection.as_slice()
+                }
+                _ => &[],
+            };
+            let is_generated = |name: &String| name.starts_with(&self.unnamed_column_prefix);
+            // Columns are selected once renamed, and renamed by their generated name
+            let is_renamed = |name: &String| self.rename_columns.values().any(|new| new == name);
+            let names_after_renaming = referenced_columns
+                .iter()
+                .chain(&self.fill_forward_columns)
+                .filter_map(|idx_or_name| match idx_or_name {
+                    IdxOrName::Name(name) => Some(name),
+                    IdxOrName::Idx(_) => None,
+                });
+            let names_before_renaming =
+                self.rename_columns
+                    .keys()
+                    .filter_map(|idx_or_name| match idx_or_name {
+                        IdxOrName::Name(name) => Some(name),
+                        IdxOrName::Idx(_) => None,
+                    });
+            if let Some(name) = names_after_renaming
+                .filter(|name| !is_generated(name) && !is_renamed(name))
+                .chain(names_before_renaming.filter(|name| !is_generated(name)))
+                .next()
+            {
+                return Err(FastExcelErrorKind::InvalidParameters(format!(
+                    "column \"{name}\" cannot be referred to by name without a header row nor column_names, columns are named \"{prefix}<index>\"",
+                    prefix = self.unnamed_column_prefix
+                ))
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn schema_sample_row_ranges(
+        &self,
+        offset: usize,
+        limit: usize,
+    ) -> Vec<RowRange<usize>> {
+        get_schema_sample_row_ranges(
+            self.schema_sample_strategy,
+            self.schema_sample_rows,
+            offset,
+            limit,
+        )
+    }
+}
+
 impl TryFrom<&Bound<'_, PyList>> for SelectedColumns {
     type Error = FastExcelError;
 
@@ -105,6 +496,13 @@ impl TryFrom<&Bound<'_, PyList>> for SelectedColumns {
 pub(crate) enum SelectedColumns {
     All,
     Selection(Vec<IdxOrName>),
+    /// The columns whose name matches a regex, in the order of the sheet
+    Pattern(Regex),
+    /// All columns except the given ones, in the order of the sheet
+    Exclude(Vec<IdxOrName>),
+    /// A Python callable, called with the finalized `ColumnInfo` (including its dtype) of every
+    /// available column. Since all dtypes must be known beforehand, dtype inference runs on every
+    /// available column, whereas it only runs on the selected columns of a static selection.
     DynamicSelection(PyObject),
 }
 
@@ -113,6 +511,8 @@ impl std::fmt::Debug for SelectedColumns {
         match self {
             Self::All => write!(f, "All"),
             Self::Selection(selection) => write!(f, "Selection({selection:?})"),
+            Self::Pattern(regex) => write!(f, "Pattern({regex})"),
+            Self::Exclude(excluded) => write!(f, "Exclude({excluded:?})"),
             Self::DynamicSelection(func) => {
                 let addr = func as *const _ as usize;
                 write!(f, "DynamicSelection({addr})")
@@ -128,6 +528,10 @@ impl PartialEq for SelectedColumns {
             (Self::Selection(selection), Self::Selection(other_selection)) => {
                 selection == other_selection
             }
+            (Self::Pattern(regex), Self::Pattern(other_regex)) => {
+                regex.as_str() == other_regex.as_str()
+            }
+            (Self::Exclude(excluded), Self::Exclude(other_excluded)) => excluded == other_excluded,
             (Self::DynamicSelection(f1), Self::DynamicSelection(f2)) => std::ptr::eq(f1, f2),
             _ => false,
         }
@@ -141,24 +545,13 @@ impl SelectedColumns {
     ) -> FastExcelResult<Vec<ColumnInfo>> {
         match self {
             SelectedColumns::All => Ok(available_columns.to_vec()),
-            SelectedColumns::Selection(selection) => selection
-                .iter()
-                .map(|selected_column| {
-                    match selected_column {
-                        IdxOrName::Idx(index) => available_columns
-                            .iter()
-                            .find(|col_info| &col_info.index() == index),
-                        IdxOrName::Name(name) => available_columns
-                            .iter()
-                            .find(|col_info| col_info.name() == name.as_str()),
-                    }
-                    .ok_or_else(|| {
-                        FastExcelErrorKind::ColumnNotFound(selected_column.clone()).into()
-                    })
-                    .cloned()
-                    .with_context(|| format!("available columns are: {available_columns:?}"))
-                })
-                .collect(),
+            SelectedColumns::Selection(selection) => {
+                Self::select_from(selection, available_columns)
+            }
+            SelectedColumns::Pattern(regex) => Ok(Self::select_matching(regex, available_columns)),
+            SelectedColumns::Exclude(excluded) => {
+                Self::select_excluding(excluded, available_columns)
+            }
             SelectedColumns::DynamicSelection(use_col_func) => Python::with_gil(|py| {
                 Ok(available_columns
                     .iter()
@@ -181,11 +574,142 @@ impl SelectedColumns {
         }
     }
 
+    /// Selects the columns referred to by a static selection, in the order of the selection
+    pub(super) fn select_from<C: SelectableColumn>(
+        selection: &[IdxOrName],
+        available_columns: &[C],
+    ) -> FastExcelResult<Vec<C>> {
+        Ok(selection
+            .iter()
+            .map(|selected_column| {
+                match selected_column {
+                    IdxOrName::Idx(index) => available_columns
+                        .iter()
+                        .position(|col_info| &col_info.index() == index)
+                        .map(|pos| Ok(&available_columns[pos..=pos])),
+                    IdxOrName::Name(name) => match available_columns
+                        .iter()
+                        .position(|col_info| col_info.name() == name.as_str())
+                    {
+                        Some(pos) => Some(Ok(&available_columns[pos..=pos])),
+                        // Not a column name, but possibly a range of column names
+                        None => Self::columns_for_name_range(name, available_columns),
+                    },
+                }
+                .unwrap_or_else(|| {
+                    Err(FastExcelErrorKind::ColumnNotFound(selected_column.clone()).into())
+                })
+                .with_context(|| format!("available columns are: {available_columns:?}"))
+            })
+            .collect::<FastExcelResult<Vec<_>>>()?
+            .concat())
+    }
+
+    /// Selects the columns whose name matches a regex, in the order of the available columns
+    pub(super) fn select_matching<C: SelectableColumn>(
+        regex: &Regex,
+        available_columns: &[C],
+    ) -> Vec<C> {
+        available_columns
+            .iter()
+            .filter(|column| regex.is_match(column.name()))
+            .cloned()
+            .collect()
+    }
+
+    /// Selects the columns which are not referred to by `excluded`, in the order of the available
+    /// columns. Every excluded column must exist
+    pub(super) fn select_excluding<C: SelectableColumn>(
+        excluded: &[IdxOrName],
+        available_columns: &[C],
+    ) -> FastExcelResult<Vec<C>> {
+        let excluded_indices: Vec<usize> = Self::select_from(excluded, available_columns)
+            .with_context(|| "could not find a column to exclude")?
+            .iter()
+            .map(SelectableColumn::index)
+            .collect();
+        Ok(available_columns
+            .iter()
+            .filter(|column| !excluded_indices.contains(&column.index()))
+            .cloned()
+            .collect())
+    }
+
+    /// Builds a selection from a compiled Python regex (`re.Pattern`). Its pattern is compiled
+    /// again with the `regex` crate, whose syntax is close to Python's, and the flags which have an
+    /// inline equivalent are kept
+    fn from_py_pattern(py_pattern: &Bound<'_, PyAny>) -> FastExcelResult<Self> {
+        use FastExcelErrorKind::InvalidParameters;
+
+        let pattern = py_pattern
+            .getattr("pattern")
+            .and_then(|pattern| pattern.extract::<String>())
+            .map_err(|err| InvalidParameters(format!("expected a str pattern: {err}")))?;
+        let flags = py_pattern
+            .getattr("flags")
+            .and_then(|flags| flags.extract::<u32>())
+            .map_err(|err| InvalidParameters(format!("invalid pattern flags: {err}")))?;
+        // Values of re.IGNORECASE, re.MULTILINE, re.DOTALL and re.VERBOSE
+        let inline_flags: String = [(2, 'i'), (8, 'm'), (16, 's'), (64, 'x')]
+            .into_iter()
+            .filter(|(flag, _)| flags & flag != 0)
+            .map(|(_, inline_flag)| inline_flag)
+            .collect();
+        let pattern = if inline_flags.is_empty() {
+            pattern
+        } else {
+            format!("(?{inline_flags}){pattern}")
+        };
+        Regex::new(&pattern)
+            .map(Self::Pattern)
+            .map_err(|err| InvalidParameters(format!("invalid regex \"{pattern}\": {err}")).into())
+    }
+
+    /// Resolves a range of column names such as "Revenue:Profit" to the available columns
+    /// between both names, inclusively. `None` if it is not a range of available column names.
+    fn columns_for_name_range<'c, C: SelectableColumn>(
+        name_range: &str,
+        available_columns: &'c [C],
+    ) -> Option<FastExcelResult<&'c [C]>> {
+        use FastExcelErrorKind::InvalidParameters;
+
+        let position = |name: &str| {
+            available_columns
+                .iter()
+                .position(|col_info| col_info.name() == name)
+        };
+        // Column names can contain colons too, so the range is split at every colon
+        let bounds: Vec<_> = name_range
+            .match_indices(':')
+            .filter_map(|(colon_idx, _)| {
+                Some((
+                    position(&name_range[..colon_idx])?,
+                    position(&name_range[colon_idx + 1..])?,
+                ))
+            })
+            .collect();
+        match bounds.as_slice() {
+            [] => None,
+            [(start, end)] if start <= end => Some(Ok(&available_columns[*start..=*end])),
+            [_] => Some(Err(InvalidParameters(format!(
+                "end of range is before start: \"{name_range}\""
+            ))
+            .into())),
+            _ => Some(Err(InvalidParameters(format!(
+                "ambiguous range of column names: \"{name_range}\""
+            ))
+            .into())),
+        }
+    }
+
     const ALPHABET: [char; 26] = [
         'A', 'B', 'C', 'D', 'E', 'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R',
         'S', 'T', 'U', 'V', 'W', 'X', 'Y', 'Z',
     ];
 
+    /// Maximum number of columns of a sheet, the last one being XFD
+    const MAX_COLUMN_COUNT: usize = 16_384;
+
     fn col_idx_for_col_as_letter(col: &str) -> FastExcelResult<usize> {
         use FastExcelErrorKind::InvalidParameters;
 
@@ -197,12 +721,9 @@ impl SelectedColumns {
         }
 
         col.chars()
-            //  iterating over all chars reversed, to have a power based on their rank
-            .rev()
-            .enumerate()
-            //  Parses every char, checks its position and returns its numeric equivalent based on
-            //  its rank. For example, AB becomes 27 (26 + 1)
-            .map(|(idx, col_chr)| {
+            //  Parses every char and accumulates its numeric equivalent, each char being a digit
+            //  in base 26 (A being 1). For example, AB becomes 28 (1 * 26 + 2), i.e. index 27
+            .try_fold(0usize, |acc, col_chr| {
                 let pos_in_alphabet = Self::ALPHABET
                     .iter()
                     .position(|chr| chr == &col_chr)
@@ -212,21 +733,16 @@ impl SelectedColumns {
                         )))
                     })?;
 
-                Ok(match idx {
-                    // in case it's the last char, just return its position
-                    0 => pos_in_alphabet,
-                    // otherwise, 26^idx * (position + 1)
-                    // For example, CBA is 2081:
-                    // A -> 0
-                    // B -> 26 (53^1 * (1 + 1))
-                    // C -> 2028 (26^2 * (2 + 1))
-                    _ => 26usize.pow(idx as u32) * (pos_in_alphabet + 1),
-                })
-            })
-            // Sums all previously obtained ranks
-            .try_fold(0usize, |acc, elem_result| {
-                elem_result.map(|elem| acc + elem)
+                acc.checked_mul(26)
+                    .and_then(|acc| acc.checked_add(pos_in_alphabet + 1))
+                    .filter(|col_number| *col_number <= Self::MAX_COLUMN_COUNT)
+                    .ok_or_else(|| {
+                        FastExcelError::from(InvalidParameters(format!(
+                            "column reference too large: \"{col}\", the last column is XFD"
+                        )))
+                    })
             })
+            .map(|col_number| col_number - 1)
     }
 
     fn col_indices_for_letter_range(col_range: &str) -> FastExcelResult<Vec<usize>> {
@@ -285,6 +801,14 @@ impl FromStr for SelectedColumns {
     }
 }
 
+/// Whether a Python object is a compiled regex (`re.Pattern`)
+fn is_py_pattern(py_any: &Bound<'_, PyAny>) -> bool {
+    PyModule::import_bound(py_any.py(), "re")
+        .and_then(|re| re.getattr("Pattern"))
+        .and_then(|pattern_type| py_any.is_instance(&pattern_type))
+        .unwrap_or(false)
+}
+
 impl TryFrom<Option<&Bound<'_, PyAny>>> for SelectedColumns {
     type Error = FastExcelError;
 
@@ -298,6 +822,8 @@ impl TryFrom<Option<&Bound<'_, PyAny>>> for SelectedColumns {
                     py_str.parse()
                 } else if let Ok(py_list) = py_any.downcast::<PyList>() {
                     py_list.try_into()
+                } else if is_py_pattern(py_any) {
+                    Self::from_py_pattern(py_any)
                 } else if let Ok(py_function) = py_any.extract::<PyObject>() {
                     Ok(Self::DynamicSelection(py_function))
                 } else {
@@ -315,8 +841,10 @@ impl TryFrom<Option<&Bound<'_, PyAny>>> for SelectedColumns {
     }
 }
 
+// calamine reads the visibility of ODS sheets from the `table:display` property of their style,
+// so they are either visible or hidden: OpenDocument has no equivalent of "very hidden"
 #[derive(Clone, Debug)]
-struct SheetVisible(CalamineSheetVisible);
+pub(crate) struct SheetVisible(CalamineSheetVisible);
 
 impl ToPyObject for &SheetVisible {
     fn to_object(&self, py: Python<'_>) -> PyObject {
@@ -334,6 +862,21 @@ impl From<CalamineSheetVisible> for SheetVisible {
     }
 }
 
+/// Returns the absolute indices of the hidden columns of a sheet. Column visibility is only read
+/// from XLSX workbooks opened from a path or bytes, i.e. those with a source.
+pub(crate) fn read_hidden_columns(
+    xlsx_source: Option<&XlsxSource>,
+    sheet_name: &str,
+) -> FastExcelResult<Vec<usize>> {
+    match xlsx_source {
+        Some(source) => source
+            .open()
+            .and_then(|content| xlsx::hidden_columns(content, sheet_name)),
+        None => Ok(Vec::new()),
+    }
+    .with_context(|| format!("could not read the hidden columns of sheet \"{sheet_name}\""))
+}
+
 #[pyclass(name = "_ExcelSheet")]
 pub(crate) struct ExcelSheet {
     sheet_meta: CalamineSheet,
@@ -343,11 +886,20 @@ pub(crate) struct ExcelSheet {
     height: Option<usize>,
     total_height: Option<usize>,
     width: Option<usize>,
-    schema_sample_rows: Option<usize>,
-    dtype_coercion: DTypeCoercion,
+    options: LoadSheetOrTableOptions,
     selected_columns: Vec<ColumnInfo>,
-    available_columns: Vec<ColumnInfo>,
-    dtypes: Option<DTypes>,
+    available_columns: AvailableColumns,
+    /// Indices of the hidden columns, relative to the first column of the data. `None` until
+    /// read, which is only done upfront if they are skipped
+    hidden_columns: Option<Vec<usize>>,
+    /// Where the workbook can be opened again from, to read its hidden columns and tab color
+    xlsx_source: Option<XlsxSource>,
+    /// The first empty data row, at which the data stops if `stop_at_empty_row` is set
+    empty_row: Option<usize>,
+    /// Absolute indices of the first and last non-empty rows, `None` until computed
+    data_bounds: Option<Option<(usize, usize)>>,
+    /// ARGB hex color of the sheet's tab, if set as an RGB value. `None` until read
+    tab_color: Option<Option<String>>,
 }
 
 impl ExcelSheet {
@@ -355,57 +907,70 @@ impl ExcelSheet {
         &self.data
     }
 
-    #[allow(clippy::too_many_arguments)]
     pub(crate) fn try_new(
         sheet_meta: CalamineSheet,
         data: ExcelSheetData<'static>,
         header: Header,
         pagination: Pagination,
-        schema_sample_rows: Option<usize>,
-        dtype_coercion: DTypeCoercion,
         selected_columns: SelectedColumns,
-        dtypes: Option<DTypes>,
+        hidden_columns: Option<Vec<usize>>,
+        options: LoadSheetOrTableOptions,
     ) -> FastExcelResult<Self> {
-        let available_columns_info =
-            build_available_columns_info(&data, &selected_columns, &header)?;
         let mut sheet = ExcelSheet {
             sheet_meta,
             header,
             pagination,
             data,
-            schema_sample_rows,
-            dtype_coercion,
-            dtypes,
+            options,
+            hidden_columns,
+            xlsx_source: None,
+            empty_row: None,
+            data_bounds: None,
+            tab_color: None,
             height: None,
             total_height: None,
             width: None,
             // Empty vecs as they'll be replaced
-            available_columns: Vec::with_capacity(0),
+            available_columns: AvailableColumns::Finalized(Vec::with_capacity(0)),
             selected_columns: Vec::with_capacity(0),
         };
+        // Looked up once, since the limit is needed whenever data is read
+        if sheet.options.stop_at_empty_row {
+            sheet.empty_row = sheet.data.first_empty_row(sheet.offset());
+        }
 
-        let row_limit = sheet.schema_sample_rows();
+        let sample_rows = sheet
+            .options
+            .schema_sample_row_ranges(sheet.offset(), sheet.limit());
 
-        // Finalizing column info
-        let available_columns = build_available_columns(
-            available_columns_info,
+        let (available_columns, selected_columns) = finalize_column_info(
             &sheet.data,
-            sheet.offset(),
-            row_limit,
-            sheet.dtypes.as_ref(),
-            &sheet.dtype_coercion,
+            &sheet.header,
+            &selected_columns,
+            &sample_rows,
+            sheet.offset()..sheet.limit(),
+            sheet.hidden_columns.as_deref().unwrap_or_default(),
+            &sheet.options,
         )?;
-
-        // Figure out dtype for every column
-        let selected_columns = selected_columns.select_columns(&available_columns)?;
         sheet.available_columns = available_columns;
         sheet.selected_columns = selected_columns;
 
         Ok(sheet)
     }
 
+    pub(crate) fn with_xlsx_source(mut self, xlsx_source: Option<XlsxSource>) -> Self {
+        self.xlsx_source = xlsx_source;
+        self
+    }
+
+    /// The arrow schema of the selected columns, as produced by `to_arrow`, without loading any
+    /// data
+    pub(crate) fn arrow_schema(&self) -> Schema {
+        selected_columns_to_schema(&self.selected_columns, self.options.datetime_tz.as_deref())
+    }
+
     pub(crate) fn limit(&self) -> usize {
-        let upper_bound = self.data.height();
+        let upper_bound = self.empty_row.unwrap_or_else(|| self.data.height());
         if let Some(n_rows) = self.pagination.n_rows {
             let limit = self.offset() + n_rows;
             if limit < upper_bound {
@@ -416,8 +981,50 @@ impl ExcelSheet {
         upper_bound
     }
 
-    pub(crate) fn schema_sample_rows(&self) -> usize {
-        get_schema_sample_rows(self.schema_sample_rows, self.offset(), self.limit())
+    /// Writes the sheet's selected data to `writer` in the given format, building one
+    /// `RecordBatch` of at most `batch_size` rows at a time. Only one batch is held in memory.
+    pub(crate) fn stream_to<W: Write + Send>(
+        &self,
+        writer: W,
+        format: OutputFormat,
+        batch_size: usize,
+    ) -> FastExcelResult<()> {
+        if batch_size == 0 {
+            return Err(FastExcelErrorKind::InvalidParameters(
+                "batch_size cannot be 0".to_string(),
+            )
+            .into());
+        }
+        let offset = self.offset();
+        let limit = self.limit();
+        let schema = Arc::new(self.arrow_schema());
+
+        // An empty sheet still yields one empty batch, so that the output has a header
+        let batch_starts = (offset..limit.max(offset + 1)).step_by(batch_size);
+        let batches = batch_starts.map(|batch_start| {
+            let batch_end = cmp::min(batch_start + batch_size, limit).max(batch_start);
+            record_batch_from_data_and_columns(
+                &self.selected_columns,
+                self.data(),
+                offset,
+                batch_start,
+                batch_end,
+                &self.options,
+            )
+        });
+
+        write_batches(writer, format, schema, batches)
+            .with_context(|| format!("could not stream sheet \"{}\" as {format}", self.name()))
+    }
+
+    /// Writes the sheet's selected data as CSV to a string, which is handy to share what was
+    /// loaded without going through pyarrow
+    pub(crate) fn to_csv_string(&self) -> FastExcelResult<String> {
+        let mut buffer = Vec::new();
+        self.stream_to(&mut buffer, OutputFormat::Csv, 10_000)?;
+        String::from_utf8(buffer)
+            .map_err(|err| FastExcelErrorKind::Internal(err.to_string()).into())
+            .with_context(|| format!("could not write sheet \"{}\" as CSV", self.name()))
     }
 }
 
@@ -428,8 +1035,177 @@ impl TryFrom<&ExcelSheet> for RecordBatch {
         let offset = sheet.offset();
         let limit = sheet.limit();
 
-        record_batch_from_data_and_columns(&sheet.selected_columns, sheet.data(), offset, limit)
-            .with_context(|| format!("could not convert sheet {} to RecordBatch", sheet.name()))
+        record_batch_from_data_and_columns(
+            &sheet.selected_columns,
+            sheet.data(),
+            offset,
+            offset,
+            limit,
+            &sheet.options,
+        )
+        .with_context(|| format!("could not convert sheet {} to RecordBatch", sheet.name()))
+    }
+}
+
+/// Name of the column holding the name of the sheet each row comes from, see `concat_sheets`
+pub(crate) const SHEET_NAME_COLUMN: &str = "__sheet_name__";
+
+/// Describes the first difference between the columns of two schemas, if any
+fn schema_mismatch(expected: &Schema, actual: &Schema) -> Option<String> {
+    let (expected, actual) = (expected.fields(), actual.fields());
+    (0..cmp::max(expected.len(), actual.len())).find_map(|idx| {
+        match (expected.get(idx), actual.get(idx)) {
+            (Some(expected), Some(actual)) if expected.name() != actual.name() => Some(format!(
+                "column {idx} is named \"{}\" instead of \"{}\"",
+                actual.name(),
+                expected.name()
+            )),
+            (Some(expected), Some(actual)) if expected.data_type() != actual.data_type() => {
+                Some(format!(
+                    "column \"{}\" is of type {} instead of {}",
+                    actual.name(),
+                    actual.data_type(),
+                    expected.data_type()
+                ))
+            }
+            (Some(expected), None) => Some(format!("column \"{}\" is missing", expected.name())),
+            (None, Some(actual)) => Some(format!("column \"{}\" is unexpected", actual.name())),
+            _ => None,
+        }
+    })
+}
+
+fn with_sheet_name_column(batch: RecordBatch, sheet_name: &str) -> FastExcelResult<RecordBatch> {
+    if batch.schema().column_with_name(SHEET_NAME_COLUMN).is_some() {
+        return Err(FastExcelErrorKind::InvalidParameters(format!(
+            "sheet \"{sheet_name}\" already has a \"{SHEET_NAME_COLUMN}\" column"
+        ))
+        .into());
+    }
+    let mut fields = batch.schema().fields().to_vec();
+    fields.push(Arc::new(Field::new(
+        SHEET_NAME_COLUMN,
+        ArrowDataType::Utf8,
+        false,
+    )));
+    let mut columns = batch.columns().to_vec();
+    columns.push(Arc::new(StringArray::from(vec![
+        sheet_name;
+        batch.num_rows()
+    ])));
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+        .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+}
+
+/// Vertically concatenates the data of several sheets, which must have the same columns with the
+/// same dtypes. If `add_sheet_name_column` is set, a `__sheet_name__` column holding the name of
+/// the sheet of each row is appended
+pub(crate) fn concat_sheets(
+    sheets: &[ExcelSheet],
+    add_sheet_name_column: bool,
+) -> FastExcelResult<RecordBatch> {
+    let Some(first_sheet) = sheets.first() else {
+        return Err(FastExcelErrorKind::InvalidParameters(
+            "at least one sheet is needed to concatenate sheets".to_owned(),
+        )
+        .into());
+    };
+
+    let mut batches: Vec<RecordBatch> = Vec::with_capacity(sheets.len());
+    for sheet in sheets {
+        let batch = RecordBatch::try_from(sheet)?;
+        if let Some(first_batch) = batches.first().map(RecordBatch::schema) {
+            if let Some(mismatch) = schema_mismatch(&first_batch, &batch.schema()) {
+                return Err(FastExcelErrorKind::InvalidParameters(format!(
+                    "sheet \"{}\" cannot be concatenated with sheet \"{}\": {mismatch}",
+                    sheet.name(),
+                    first_sheet.name()
+                ))
+                .into());
+            }
+        }
+        batches.push(batch);
+    }
+
+    let batches = if add_sheet_name_column {
+        batches
+            .into_iter()
+            .zip(sheets)
+            .map(|(batch, sheet)| with_sheet_name_column(batch, sheet.name()))
+            .collect::<FastExcelResult<Vec<_>>>()?
+    } else {
+        batches
+    };
+    concat_batches(&batches[0].schema(), &batches)
+        .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+}
+
+impl ExcelSheet {
+    /// Builds a single selected column, with its guessed or provided dtype, without loading the
+    /// other columns
+    pub(crate) fn column(&self, idx_or_name: IdxOrName) -> FastExcelResult<Arc<dyn Array>> {
+        let column = self
+            .selected_columns
+            .iter()
+            .find(|column| column.matches(&idx_or_name))
+            .ok_or_else(|| FastExcelError::from(FastExcelErrorKind::ColumnNotFound(idx_or_name)))
+            .with_context(|| format!("selected columns are: {:?}", self.selected_columns))?;
+        let offset = self.offset();
+        let record_batch = record_batch_from_data_and_columns(
+            std::slice::from_ref(column),
+            self.data(),
+            offset,
+            offset,
+            self.limit(),
+            &self.options,
+        )?;
+        Ok(Arc::clone(record_batch.column(0)))
+    }
+
+    fn column_to_pyarrow(&self, py: Python<'_>, idx_or_name: IdxOrName) -> PyResult<PyObject> {
+        self.column(idx_or_name)
+            .and_then(|array| {
+                array
+                    .to_data()
+                    .to_pyarrow(py)
+                    .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+            })
+            .with_context(|| format!("could not load column from sheet \"{}\"", self.name()))
+            .into_pyresult()
+    }
+
+    /// Loads the columns of a schema, by name, with the dtypes of the schema's fields instead of
+    /// the guessed ones. The sheet's other columns are ignored.
+    pub(crate) fn record_batch_with_schema(&self, schema: Schema) -> FastExcelResult<RecordBatch> {
+        let columns = schema
+            .fields()
+            .iter()
+            .map(|field| {
+                let dtype = DType::try_from(field.data_type())
+                    .with_context(|| format!("invalid type for column \"{}\"", field.name()))?;
+                self.available_columns
+                    .column_with_dtype(field.name(), dtype)
+                    .ok_or_else(|| {
+                        FastExcelError::from(FastExcelErrorKind::ColumnNotFound(IdxOrName::Name(
+                            field.name().to_owned(),
+                        )))
+                    })
+            })
+            .collect::<FastExcelResult<Vec<_>>>()?;
+
+        let offset = self.offset();
+        let record_batch = record_batch_from_data_and_columns(
+            &columns,
+            self.data(),
+            offset,
+            offset,
+            self.limit(),
+            &self.options,
+        )?;
+        // Checks that the loaded columns match the fields, e.g. that non-nullable columns do not
+        // contain null values, and keeps the schema's metadata
+        RecordBatch::try_new(Arc::new(schema), record_batch.columns().to_vec())
+            .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
     }
 }
 
@@ -462,6 +1238,68 @@ impl ExcelSheet {
         })
     }
 
+    /// The number of data rows skipped by `skip_rows`. Unless `n_rows` or `stop_at_empty_row`
+    /// cut the data short, `total_height - skipped_row_count == height`
+    #[getter]
+    pub fn skipped_row_count(&mut self) -> usize {
+        cmp::min(self.pagination.offset(), self.total_height())
+    }
+
+    /// The indices of the first and last rows of the sheet containing a non-empty cell, `None` if
+    /// the sheet is empty. Like `header_row`, indices are relative to the sheet and not affected
+    /// by `skip_rows` or `n_rows`.
+    #[getter]
+    pub fn data_bounds(&mut self) -> Option<(usize, usize)> {
+        *self.data_bounds.get_or_insert_with(|| {
+            let start_row = self.data.start_row();
+            self.data
+                .non_empty_row_bounds()
+                .map(|(first, last)| (start_row + first, start_row + last))
+        })
+    }
+
+    /// Whether the sheet has no data rows, with `skip_rows` and `n_rows` applied
+    pub fn is_empty(&mut self) -> bool {
+        self.height() == 0
+    }
+
+    /// Whether the dtype of every selected column is `null`, i.e. no sampled cell has a value.
+    /// Also true if no column is selected.
+    pub fn has_only_null_columns(&self) -> bool {
+        self.selected_columns
+            .iter()
+            .all(|column| *column.dtype() == DType::Null)
+    }
+
+    #[getter]
+    pub fn header_row(&self) -> Option<usize> {
+        match self.header {
+            Header::At(row) => Some(self.data.start_row() + row),
+            // The first of the header rows
+            Header::AtMultiple(ref rows) => Some(self.data.start_row() + rows.start),
+            Header::None | Header::With(_) => None,
+        }
+    }
+
+    #[getter]
+    pub fn hidden_columns(&mut self) -> PyResult<Vec<usize>> {
+        if self.hidden_columns.is_none() {
+            let hidden_columns =
+                read_hidden_columns(self.xlsx_source.as_ref(), &self.sheet_meta.name)
+                    .into_pyresult()?;
+            let start_col = self.data.start_col();
+            let width = self.data.width();
+            self.hidden_columns = Some(
+                hidden_columns
+                    .into_iter()
+                    .filter_map(|col| col.checked_sub(start_col))
+                    .filter(|col| *col < width)
+                    .collect(),
+            );
+        }
+        Ok(self.hidden_columns.clone().unwrap_or_default())
+    }
+
     #[getter]
     pub fn offset(&self) -> usize {
         self.header.offset() + self.pagination.offset()
@@ -473,13 +1311,29 @@ impl ExcelSheet {
     }
 
     #[getter]
-    pub fn available_columns<'p>(&'p self, _py: Python<'p>) -> Vec<ColumnInfo> {
-        self.available_columns.clone()
+    pub fn available_columns<'p>(&'p mut self, _py: Python<'p>) -> PyResult<Vec<ColumnInfo>> {
+        let sample_rows = self
+            .options
+            .schema_sample_row_ranges(self.offset(), self.limit());
+        let data_rows = self.offset()..self.limit();
+        self.available_columns
+            .finalize(&self.data, &sample_rows, data_rows, &self.options)
+            .map(<[ColumnInfo]>::to_vec)
+            .with_context(|| {
+                format!(
+                    "could not determine the available columns of sheet \"{}\"",
+                    self.name()
+                )
+            })
+            .into_pyresult()
     }
 
     #[getter]
     pub fn specified_dtypes<'p>(&'p self, py: Python<'p>) -> Option<PyObject> {
-        self.dtypes.as_ref().map(|dtypes| dtypes.to_object(py))
+        self.options
+            .dtypes
+            .as_ref()
+            .map(|dtypes| dtypes.to_object(py))
     }
 
     #[getter]
@@ -493,6 +1347,27 @@ impl ExcelSheet {
         (&visible).to_object(py)
     }
 
+    #[getter]
+    pub fn tab_color(&mut self) -> PyResult<Option<String>> {
+        if self.tab_color.is_none() {
+            let tab_color = match &self.xlsx_source {
+                Some(source) => source
+                    .open()
+                    .and_then(|content| xlsx::tab_color(content, &self.sheet_meta.name)),
+                None => Ok(None),
+            }
+            .with_context(|| {
+                format!(
+                    "could not read the tab color of sheet \"{}\"",
+                    self.sheet_meta.name
+                )
+            })
+            .into_pyresult()?;
+            self.tab_color = Some(tab_color);
+        }
+        Ok(self.tab_color.clone().flatten())
+    }
+
     pub fn to_arrow(&self, py: Python<'_>) -> PyResult<PyObject> {
         RecordBatch::try_from(self)
             .with_context(|| {
@@ -514,6 +1389,82 @@ impl ExcelSheet {
             .into_pyresult()
     }
 
+    pub fn column_by_name(&self, py: Python<'_>, name: String) -> PyResult<PyObject> {
+        self.column_to_pyarrow(py, IdxOrName::Name(name))
+    }
+
+    pub fn column_by_index(&self, py: Python<'_>, index: usize) -> PyResult<PyObject> {
+        self.column_to_pyarrow(py, IdxOrName::Idx(index))
+    }
+
+    /// Returns the raw value of a cell as a string, without loading any column. `row` is relative
+    /// to the first data row, and `col` is the index of the column in the sheet
+    pub fn raw_cell_string(&self, row: usize, col: usize) -> Option<String> {
+        let row = self.offset().checked_add(row)?;
+        if row >= self.limit() {
+            return None;
+        }
+        self.data.get_as_string((row, col))
+    }
+
+    pub fn to_arrow_with_schema(
+        &self,
+        py: Python<'_>,
+        schema: PyArrowType<Schema>,
+    ) -> PyResult<PyObject> {
+        self.record_batch_with_schema(schema.0)
+            .with_context(|| {
+                format!(
+                    "could not create RecordBatch with schema from sheet \"{}\"",
+                    self.name()
+                )
+            })
+            .and_then(|rb| {
+                rb.to_pyarrow(py)
+                    .map_err(|err| FastExcelErrorKind::ArrowError(err.to_string()).into())
+            })
+            .into_pyresult()
+    }
+
+    /// Returns the name and the pyarrow type of every selected column, as they would be produced
+    /// by `to_arrow`, without loading any data
+    pub fn schema_hint(&self, py: Python<'_>) -> PyResult<Vec<(String, String)>> {
+        self.arrow_schema()
+            .fields()
+            .iter()
+            .map(|field| {
+                let dtype = field.data_type().to_pyarrow(py)?;
+                Ok((field.name().to_owned(), dtype.bind(py).str()?.to_string()))
+            })
+            .collect()
+    }
+
+    #[pyo3(name = "arrow_schema")]
+    pub fn py_arrow_schema(&self) -> PyArrowType<Schema> {
+        PyArrowType(self.arrow_schema())
+    }
+
+    #[pyo3(name = "stream_to", signature = (path, *, format, batch_size = 10_000))]
+    pub fn py_stream_to(
+        &self,
+        path: &str,
+        format: OutputFormat,
+        batch_size: usize,
+    ) -> PyResult<()> {
+        File::create(path)
+            .map_err(|err| {
+                FastExcelErrorKind::InvalidParameters(format!("could not create {path}: {err}"))
+                    .into()
+            })
+            .and_then(|file| self.stream_to(BufWriter::new(file), format, batch_size))
+            .into_pyresult()
+    }
+
+    #[pyo3(name = "to_csv_string")]
+    pub fn py_to_csv_string(&self) -> PyResult<String> {
+        self.to_csv_string().into_pyresult()
+    }
+
     pub fn __repr__(&self) -> String {
         format!("ExcelSheet<{}>", self.name())
     }
@@ -521,11 +1472,27 @@ impl ExcelSheet {
 
 #[cfg(test)]
 mod tests {
+    use super::column_info::{ColumnNameFrom, DTypeFrom};
     use super::*;
+    use calamine::{Cell, Data};
     use pretty_assertions::assert_eq;
     use pyo3::{prelude::PyListMethods, types::PyString};
     use rstest::rstest;
 
+    #[rstest]
+    #[case("A", 0)]
+    #[case("Z", 25)]
+    #[case("AA", 26)]
+    #[case("AB", 27)]
+    #[case("CBA", 2080)]
+    #[case("XFD", 16_383)]
+    fn col_idx_for_col_as_letter(#[case] col: &str, #[case] expected: usize) {
+        assert_eq!(
+            SelectedColumns::col_idx_for_col_as_letter(col).unwrap(),
+            expected
+        );
+    }
+
     #[test]
     fn selected_columns_from_none() {
         assert_eq!(
@@ -610,6 +1577,118 @@ mod tests {
         });
     }
 
+    #[rstest]
+    #[case(false, &["id"])]
+    #[case(true, &["id", "comment"])]
+    fn skip_empty_columns_with_expanded_sample(
+        #[case] expand_sample_on_null_columns: bool,
+        #[case] expected: &[&str],
+    ) {
+        // Only the first two data rows are sampled: "comment" is null within them, and "empty"
+        // is null within all the rows
+        let range = calamine::Range::from_sparse(vec![
+            Cell::new((0, 0), Data::String("id".to_string())),
+            Cell::new((0, 1), Data::String("comment".to_string())),
+            Cell::new((0, 2), Data::String("empty".to_string())),
+            Cell::new((1, 0), Data::Float(1.0)),
+            Cell::new((2, 0), Data::Float(2.0)),
+            Cell::new((3, 0), Data::Float(3.0)),
+            Cell::new((3, 1), Data::String("late".to_string())),
+        ]);
+        let options = LoadSheetOrTableOptions {
+            expand_sample_on_null_columns,
+            skip_empty_columns: true,
+            ..Default::default()
+        };
+
+        let (_, selected_columns) = finalize_column_info(
+            &range,
+            &Header::At(0),
+            &SelectedColumns::All,
+            std::slice::from_ref(&(1..3)),
+            1..4,
+            &[],
+            &options,
+        )
+        .unwrap();
+
+        assert_eq!(
+            selected_columns
+                .iter()
+                .map(ColumnInfo::name)
+                .collect::<Vec<_>>(),
+            expected
+        );
+        if expand_sample_on_null_columns {
+            assert_eq!(
+                selected_columns[1],
+                ColumnInfo::new(
+                    "comment".to_string(),
+                    1,
+                    ColumnNameFrom::LookedUp,
+                    DType::String,
+                    DTypeFrom::Guessed,
+                )
+            );
+        }
+    }
+
+    #[test]
+    fn load_options_from_kwargs() {
+        Python::with_gil(|py| {
+            let options = LoadSheetOrTableOptions::from_kwargs(None).unwrap();
+            assert_eq!(options.schema_sample_rows, Some(DEFAULT_SCHEMA_SAMPLE_ROWS));
+            assert!(!options.skip_hidden_columns);
+            assert_eq!(options.null_values, NullValues::Default);
+
+            let kwargs = PyDict::new_bound(py);
+            kwargs.set_item("skip_hidden_columns", true).unwrap();
+            kwargs.set_item("schema_sample_rows", py.None()).unwrap();
+            kwargs.set_item("null_values", vec!["-"]).unwrap();
+            kwargs.set_item("fill_forward_columns", py.None()).unwrap();
+            let options = LoadSheetOrTableOptions::from_kwargs(Some(&kwargs)).unwrap();
+            assert!(options.skip_hidden_columns);
+            // Other options keep their default value
+            assert!(!options.skip_empty_columns);
+            assert_eq!(options.schema_sample_rows, None);
+            assert_eq!(
+                options.null_values,
+                NullValues::Custom(vec!["-".to_string()])
+            );
+            assert!(options.fill_forward_columns.is_empty());
+        });
+    }
+
+    #[test]
+    fn load_options_from_invalid_kwargs() {
+        Python::with_gil(|py| {
+            let kwargs = PyDict::new_bound(py);
+            kwargs.set_item("skip_hiden_columns", true).unwrap();
+            let err = LoadSheetOrTableOptions::from_kwargs(Some(&kwargs))
+                .map(drop)
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+            assert!(err.to_string().contains("'skip_hiden_columns'"));
+
+            let kwargs = PyDict::new_bound(py);
+            kwargs.set_item("skip_hidden_columns", "yes").unwrap();
+            let err = LoadSheetOrTableOptions::from_kwargs(Some(&kwargs))
+                .map(drop)
+                .unwrap_err();
+            assert!(err.is_instance_of::<PyTypeError>(py));
+            assert!(err.to_string().contains("argument 'skip_hidden_columns'"));
+
+            let kwargs = PyDict::new_bound(py);
+            kwargs.set_item("datetime_tz", "").unwrap();
+            let err = LoadSheetOrTableOptions::from_kwargs(Some(&kwargs))
+                .map(drop)
+                .unwrap_err();
+            assert!(err
+                .to_string()
+                .contains("datetime_tz cannot be an empty string"));
+        });
+    }
+
     #[rstest]
     // Standard unique columns
     #[case("A,B,D", vec![0, 1, 3])]
@@ -647,6 +1726,13 @@ mod tests {
     #[case("a:", "at least one character, got none")]
     // too many elements
     #[case("a:b:e", "exactly 2 elements, got 3")]
+    // beyond the last column
+    #[case("XFE", "column reference too large")]
+    #[case("a:aaaaaaaaaaaaaaaaaaaa", "column reference too large")]
+    #[case(
+        "zzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzzz",
+        "column reference too large"
+    )]
     fn selected_columns_from_invalid_ranges(#[case] raw: &str, #[case] message: &str) {
         Python::with_gil(|py| {
             let input = PyString::new_bound(py, raw);
@@ -664,4 +1750,249 @@ mod tests {
             }
         })
     }
+
+    fn columns_named(names: &[&str]) -> Vec<ColumnInfo> {
+        names
+            .iter()
+            .enumerate()
+            .map(|(index, name)| {
+                ColumnInfo::new(
+                    name.to_string(),
+                    index,
+                    ColumnNameFrom::LookedUp,
+                    DType::Float,
+                    DTypeFrom::Guessed,
+                )
+            })
+            .collect()
+    }
+
+    #[rstest]
+    #[case(vec!["b:d"], vec!["b", "c", "d"])]
+    #[case(vec!["c:c", "a"], vec!["c", "a"])]
+    // Exact names take precedence over ranges
+    #[case(vec!["x:y"], vec!["x:y"])]
+    #[case(vec!["a:x:y"], vec!["a", "b", "c", "d", "x:y"])]
+    fn select_columns_by_name_range(
+        #[case] selection: Vec<&str>,
+        #[case] expected_names: Vec<&str>,
+    ) {
+        let available_columns = columns_named(&["a", "b", "c", "d", "x:y"]);
+        let selected_columns = SelectedColumns::Selection(
+            selection
+                .into_iter()
+                .map(|name| IdxOrName::Name(name.to_owned()))
+                .collect(),
+        )
+        .select_columns(&available_columns)
+        .unwrap();
+
+        assert_eq!(
+            selected_columns
+                .iter()
+                .map(ColumnInfo::name)
+                .collect::<Vec<_>>(),
+            expected_names
+        );
+    }
+
+    #[rstest]
+    #[case("d:b", "end of range is before start")]
+    #[case("a:b:c", "ambiguous range of column names")]
+    fn select_columns_by_invalid_name_range(#[case] name_range: &str, #[case] message: &str) {
+        let available_columns = columns_named(&["a", "a:b", "b", "b:c", "c", "d"]);
+        let err = SelectedColumns::Selection(vec![IdxOrName::Name(name_range.to_owned())])
+            .select_columns(&available_columns)
+            .expect_err("expected an error");
+
+        match err.kind {
+            FastExcelErrorKind::InvalidParameters(detail) => {
+                assert!(
+                    detail.contains(message),
+                    "expected \"{detail}\" to contain \"{message}\""
+                )
+            }
+            _ => panic!("Expected error to be InvalidParameters, got {err:?}"),
+        }
+    }
+
+    #[test]
+    fn select_columns_by_unknown_name_range() {
+        let available_columns = columns_named(&["a", "b"]);
+        let err = SelectedColumns::Selection(vec![IdxOrName::Name("a:z".to_owned())])
+            .select_columns(&available_columns)
+            .expect_err("expected an error");
+
+        assert!(matches!(err.kind, FastExcelErrorKind::ColumnNotFound(_)));
+    }
+
+    #[rstest]
+    #[case("^Q[1-4]_", vec!["Q1_sales", "Q2_sales", "Q3_sales", "Q4_sales"])]
+    #[case("sales$", vec!["Q1_sales", "Q2_sales", "Q3_sales", "Q4_sales", "total_sales"])]
+    #[case("^Q5", vec![])]
+    fn select_columns_by_pattern(#[case] pattern: &str, #[case] expected_names: Vec<&str>) {
+        let available_columns = columns_named(&[
+            "region",
+            "Q1_sales",
+            "Q2_sales",
+            "Q3_sales",
+            "Q4_sales",
+            "total_sales",
+        ]);
+        let selected_columns = SelectedColumns::Pattern(Regex::new(pattern).unwrap())
+            .select_columns(&available_columns)
+            .unwrap();
+
+        assert_eq!(
+            selected_columns
+                .iter()
+                .map(ColumnInfo::name)
+                .collect::<Vec<_>>(),
+            expected_names
+        );
+    }
+
+    #[rstest]
+    #[case(vec![IdxOrName::Name("b".to_owned())], vec!["a", "c", "d"])]
+    #[case(vec![IdxOrName::Idx(3), IdxOrName::Idx(0)], vec!["b", "c"])]
+    #[case(vec![IdxOrName::Name("b:c".to_owned()), IdxOrName::Idx(1)], vec!["a", "d"])]
+    #[case(vec![], vec!["a", "b", "c", "d"])]
+    fn select_columns_excluding(
+        #[case] excluded: Vec<IdxOrName>,
+        #[case] expected_names: Vec<&str>,
+    ) {
+        let available_columns = columns_named(&["a", "b", "c", "d"]);
+        let selected_columns = SelectedColumns::Exclude(excluded)
+            .select_columns(&available_columns)
+            .unwrap();
+
+        assert_eq!(
+            selected_columns
+                .iter()
+                .map(ColumnInfo::name)
+                .collect::<Vec<_>>(),
+            expected_names
+        );
+    }
+
+    #[test]
+    fn select_columns_excluding_missing_column() {
+        let available_columns = columns_named(&["a", "b"]);
+        let err = SelectedColumns::Exclude(vec![IdxOrName::Name("z".to_owned())])
+            .select_columns(&available_columns)
+            .expect_err("expected an error");
+
+        assert!(matches!(err.kind, FastExcelErrorKind::ColumnNotFound(_)));
+    }
+
+    fn range_from_rows(rows: Vec<Vec<Data>>) -> Range<Data> {
+        Range::from_sparse(
+            rows.into_iter()
+                .enumerate()
+                .flat_map(|(row_idx, row)| {
+                    row.into_iter().enumerate().map(move |(col_idx, value)| {
+                        Cell::new((row_idx as u32, col_idx as u32), value)
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    fn string(value: &str) -> Data {
+        Data::String(value.to_string())
+    }
+
+    #[rstest]
+    // header followed by data
+    #[case(vec![
+        vec![string("name"), string("age")],
+        vec![string("alice"), Data::Float(42.0)],
+    ], 0)]
+    // title and empty row before the header
+    #[case(vec![
+        vec![string("Report"), Data::Empty],
+        vec![Data::Empty, Data::Empty],
+        vec![string("name"), string("age")],
+        vec![string("alice"), Data::Float(42.0)],
+    ], 2)]
+    // partially filled header
+    #[case(vec![
+        vec![string("Report"), Data::Empty],
+        vec![string("name"), Data::Empty],
+        vec![string("alice"), Data::Float(42.0)],
+    ], 1)]
+    // only strings: nothing looks like data
+    #[case(vec![
+        vec![string("Report"), Data::Empty],
+        vec![string("name"), string("city")],
+        vec![string("alice"), string("Paris")],
+    ], 0)]
+    // numbers everywhere: no header
+    #[case(vec![
+        vec![Data::Float(1.0), Data::Float(2.0)],
+        vec![Data::Float(3.0), Data::Float(4.0)],
+    ], 0)]
+    fn header_detect_row(#[case] rows: Vec<Vec<Data>>, #[case] expected: usize) {
+        assert_eq!(Header::detect_row(&range_from_rows(rows)), expected);
+    }
+
+    #[test]
+    fn multi_row_header_names() {
+        let range = range_from_rows(vec![
+            vec![string("2024"), Data::Empty, string(""), Data::Float(2025.0)],
+            vec![string("Q1"), string("Q2"), Data::Empty, string("Q1")],
+            vec![
+                Data::Float(1.0),
+                Data::Float(2.0),
+                Data::Float(3.0),
+                Data::Float(4.0),
+            ],
+        ]);
+        assert_eq!(
+            column_info::available_column_names(
+                &range,
+                &Header::AtMultiple(0..2),
+                "__UNNAMED__",
+                DuplicateColumnStrategy::Suffix,
+            )
+            .unwrap(),
+            vec!["2024 / Q1", "Q2", "__UNNAMED__2", "2025 / Q1"]
+        );
+        assert_eq!(Header::AtMultiple(0..2).offset(), 2);
+    }
+
+    fn schema(fields: &[(&str, ArrowDataType)]) -> Schema {
+        Schema::new(
+            fields
+                .iter()
+                .map(|(name, dtype)| Field::new(*name, dtype.clone(), true))
+                .collect::<Vec<_>>(),
+        )
+    }
+
+    #[rstest]
+    #[case(&[("a", ArrowDataType::Float64), ("b", ArrowDataType::Utf8)], None)]
+    #[case(
+        &[("b", ArrowDataType::Utf8), ("a", ArrowDataType::Float64)],
+        Some("column 0 is named \"b\" instead of \"a\"")
+    )]
+    #[case(
+        &[("a", ArrowDataType::Int64), ("b", ArrowDataType::Utf8)],
+        Some("column \"a\" is of type Int64 instead of Float64")
+    )]
+    #[case(&[("a", ArrowDataType::Float64)], Some("column \"b\" is missing"))]
+    #[case(
+        &[("a", ArrowDataType::Float64), ("b", ArrowDataType::Utf8), ("c", ArrowDataType::Utf8)],
+        Some("column \"c\" is unexpected")
+    )]
+    fn schema_mismatch_between_sheets(
+        #[case] fields: &[(&str, ArrowDataType)],
+        #[case] expected: Option<&str>,
+    ) {
+        let reference = schema(&[("a", ArrowDataType::Float64), ("b", ArrowDataType::Utf8)]);
+        assert_eq!(
+            schema_mismatch(&reference, &schema(fields)).as_deref(),
+            expected
+        );
+    }
 }
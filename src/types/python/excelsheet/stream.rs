@@ -0,0 +1,136 @@
+use std::{fmt::Display, io::Write, str::FromStr, sync::Arc};
+
+use arrow::{csv::Writer as CsvWriter, ipc::writer::StreamWriter, record_batch::RecordBatch};
+use arrow::{datatypes::Schema, error::ArrowError};
+use parquet::{arrow::ArrowWriter, errors::ParquetError};
+use pyo3::{prelude::PyAnyMethods, Bound, FromPyObject, PyAny, PyResult};
+
+use crate::error::{
+    py_errors::IntoPyResult, ErrorContext, FastExcelError, FastExcelErrorKind, FastExcelResult,
+};
+
+/// Format in which a sheet's data can be streamed to a writer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum OutputFormat {
+    ArrowIpc,
+    Csv,
+    Parquet,
+}
+
+impl FromStr for OutputFormat {
+    type Err = FastExcelError;
+
+    fn from_str(raw_format: &str) -> FastExcelResult<Self> {
+        match raw_format {
+            "arrow_ipc" => Ok(Self::ArrowIpc),
+            "csv" => Ok(Self::Csv),
+            "parquet" => Ok(Self::Parquet),
+            _ => Err(FastExcelErrorKind::InvalidParameters(format!(
+                "unsupported output format: \"{raw_format}\""
+            ))
+            .into()),
+        }
+    }
+}
+
+impl Display for OutputFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            OutputFormat::ArrowIpc => "arrow_ipc",
+            OutputFormat::Csv => "csv",
+            OutputFormat::Parquet => "parquet",
+        })
+    }
+}
+
+impl FromPyObject<'_> for OutputFormat {
+    fn extract_bound(py_format: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(format_pystr) = py_format.extract::<String>() {
+            format_pystr.parse()
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "{py_format:?} cannot be converted to str"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
+fn arrow_error(err: ArrowError) -> FastExcelError {
+    FastExcelErrorKind::ArrowError(err.to_string()).into()
+}
+
+fn parquet_error(err: ParquetError) -> FastExcelError {
+    FastExcelErrorKind::ArrowError(err.to_string()).into()
+}
+
+/// The CSV and Parquet writers are boxed, as they are much larger than the IPC one
+enum BatchWriter<W: Write + Send> {
+    ArrowIpc(StreamWriter<W>),
+    Csv(Box<CsvWriter<W>>),
+    Parquet(Box<ArrowWriter<W>>),
+}
+
+impl<W: Write + Send> BatchWriter<W> {
+    fn try_new(writer: W, format: OutputFormat, schema: &Arc<Schema>) -> FastExcelResult<Self> {
+        Ok(match format {
+            OutputFormat::ArrowIpc => {
+                Self::ArrowIpc(StreamWriter::try_new(writer, schema).map_err(arrow_error)?)
+            }
+            OutputFormat::Csv => Self::Csv(Box::new(CsvWriter::new(writer))),
+            // Batches are buffered until a row group is full, so memory usage is bounded by the
+            // row group size rather than by the size of the sheet
+            OutputFormat::Parquet => Self::Parquet(Box::new(
+                ArrowWriter::try_new(writer, Arc::clone(schema), None).map_err(parquet_error)?,
+            )),
+        })
+    }
+
+    fn write(&mut self, batch: &RecordBatch) -> FastExcelResult<()> {
+        match self {
+            Self::ArrowIpc(writer) => writer.write(batch).map_err(arrow_error),
+            Self::Csv(writer) => writer.write(batch).map_err(arrow_error),
+            Self::Parquet(writer) => writer.write(batch).map_err(parquet_error),
+        }
+    }
+
+    fn finish(self) -> FastExcelResult<()> {
+        match self {
+            Self::ArrowIpc(mut writer) => writer.finish().map_err(arrow_error),
+            // The CSV writer has no footer, it only needs to be flushed
+            Self::Csv(writer) => writer
+                .into_inner()
+                .flush()
+                .map_err(|err| FastExcelErrorKind::Internal(err.to_string()).into()),
+            // Closing writes the footer, which holds the file's metadata
+            Self::Parquet(writer) => writer.close().map(|_| ()).map_err(parquet_error),
+        }
+    }
+}
+
+/// Writes the batches yielded by `batches` to `writer` in the given format, one at a time.
+///
+/// `batches` is expected to yield at least one batch (possibly empty), so that the header of the
+/// output (CSV column names, IPC or Parquet schema) is always written.
+pub(crate) fn write_batches<W, I>(
+    writer: W,
+    format: OutputFormat,
+    schema: Arc<Schema>,
+    batches: I,
+) -> FastExcelResult<()>
+where
+    W: Write + Send,
+    I: Iterator<Item = FastExcelResult<RecordBatch>>,
+{
+    let mut batch_writer = BatchWriter::try_new(writer, format, &schema)
+        .with_context(|| format!("could not create {format} writer"))?;
+    for batch in batches {
+        batch_writer
+            .write(&batch?)
+            .with_context(|| format!("could not write batch as {format}"))?;
+    }
+    batch_writer
+        .finish()
+        .with_context(|| format!("could not finish writing {format}"))
+}
@@ -0,0 +1,68 @@
+use std::io::{self, Read, Seek, SeekFrom};
+
+use pyo3::{prelude::PyAnyMethods, Bound, PyAny, PyObject, Python};
+
+/// Allows to read a workbook from a Python binary file-like object, i.e. an object with `read`
+/// and `seek` methods such as `io.BytesIO`, a file opened in binary mode or a stream from an
+/// object storage client. Data is read on demand rather than copied beforehand.
+pub(crate) struct PyFileLike(PyObject);
+
+impl PyFileLike {
+    /// Returns `None` if the object is not file-like
+    pub(crate) fn from_object(obj: &Bound<'_, PyAny>) -> Option<Self> {
+        let has_method = |name: &str| obj.hasattr(name).unwrap_or(false);
+        (has_method("read") && has_method("seek")).then(|| Self(obj.clone().unbind()))
+    }
+}
+
+// Clones share the position of the underlying object, which is fine since calamine only seeks to
+// absolute positions before reading
+impl Clone for PyFileLike {
+    fn clone(&self) -> Self {
+        Python::with_gil(|py| Self(self.0.clone_ref(py)))
+    }
+}
+
+impl Read for PyFileLike {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        Python::with_gil(|py| {
+            let py_data = self
+                .0
+                .bind(py)
+                .call_method1("read", (buf.len(),))
+                .map_err(io::Error::other)?;
+            let data: &[u8] = py_data.extract().map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "read() must return bytes, is the file opened in binary mode?",
+                )
+            })?;
+            if data.len() > buf.len() {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "read() returned more bytes than requested",
+                ));
+            }
+            buf[..data.len()].copy_from_slice(data);
+            Ok(data.len())
+        })
+    }
+}
+
+impl Seek for PyFileLike {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        // Same values as Python's os.SEEK_SET, os.SEEK_CUR and os.SEEK_END
+        let (offset, whence) = match pos {
+            SeekFrom::Start(offset) => (offset as i64, 0),
+            SeekFrom::Current(offset) => (offset, 1),
+            SeekFrom::End(offset) => (offset, 2),
+        };
+        Python::with_gil(|py| {
+            self.0
+                .bind(py)
+                .call_method1("seek", (offset, whence))
+                .and_then(|position| position.extract::<u64>())
+                .map_err(io::Error::other)
+        })
+    }
+}
@@ -1,5 +1,9 @@
+pub(crate) mod cell_style;
+pub(crate) mod defined_name;
 pub(crate) mod excelreader;
 pub(crate) mod excelsheet;
+pub(crate) mod file_like;
 pub(crate) mod table;
+pub(crate) mod workbook_metadata;
 pub(crate) use excelreader::ExcelReader;
 pub(crate) use excelsheet::ExcelSheet;
@@ -12,21 +12,19 @@ use crate::{
         create_boolean_array_from_range, create_date_array_from_range,
         create_datetime_array_from_range, create_duration_array_from_range,
         create_float_array_from_range, create_int_array_from_range, create_string_array_from_range,
+        create_time_array_from_range, fill_forward, first_empty_row, pad_numbers_with_zeros,
         record_batch_from_name_array_iterator, selected_columns_to_schema,
     },
     error::{
         py_errors::IntoPyResult, ErrorContext, FastExcelError, FastExcelErrorKind, FastExcelResult,
     },
-    types::{
-        dtype::{DType, DTypeCoercion, DTypes},
-        python::excelsheet::column_info::build_available_columns,
-    },
-    utils::schema::get_schema_sample_rows,
+    types::dtype::DType,
+    utils::xlsx::TableDefinition,
 };
 
 use super::excelsheet::{
-    column_info::{build_available_columns_info, ColumnInfo},
-    Header, Pagination, SelectedColumns,
+    column_info::{finalize_column_info, AvailableColumns, ColumnInfo},
+    Header, LoadSheetOrTableOptions, Pagination, SelectedColumns,
 };
 
 #[pyclass(name = "_ExcelTable")]
@@ -36,64 +34,73 @@ pub(crate) struct ExcelTable {
     #[pyo3(get)]
     sheet_name: String,
     selected_columns: Vec<ColumnInfo>,
-    available_columns: Vec<ColumnInfo>,
-    table: Table<Data>,
+    available_columns: AvailableColumns,
+    /// The table's data, numbers padded with leading zeros if `keep_leading_zeros` is set
+    data: Range<Data>,
     header: Header,
     pagination: Pagination,
-    dtypes: Option<DTypes>,
-    dtype_coercion: DTypeCoercion,
+    options: LoadSheetOrTableOptions,
+    /// Number of rows at the end of the table's data which are left out, i.e. its totals row
+    excluded_row_count: usize,
+    /// The first empty data row, at which the data stops if `stop_at_empty_row` is set
+    empty_row: Option<usize>,
     height: Option<usize>,
     total_height: Option<usize>,
     width: Option<usize>,
 }
 
 impl ExcelTable {
+    /// `hidden_columns` are relative to the table, whereas `zero_padded_cells` have absolute
+    /// positions within the sheet
+    #[allow(clippy::too_many_arguments)]
     pub(crate) fn try_new(
         table: Table<Data>,
         header: Header,
         pagination: Pagination,
-        schema_sample_rows: Option<usize>,
-        dtype_coercion: DTypeCoercion,
         selected_columns: SelectedColumns,
-        dtypes: Option<DTypes>,
+        hidden_columns: &[usize],
+        zero_padded_cells: &[(usize, usize, usize)],
+        options: LoadSheetOrTableOptions,
+        excluded_row_count: usize,
     ) -> FastExcelResult<Self> {
-        let available_columns_info =
-            build_available_columns_info(table.data(), &selected_columns, &header)?;
-
+        let name = table.name().to_owned();
+        let sheet_name = table.sheet_name().to_owned();
+        let mut data = Range::from(table);
+        pad_numbers_with_zeros(&mut data, zero_padded_cells, Data::String);
         let mut excel_table = ExcelTable {
-            name: table.name().to_owned(),
-            sheet_name: table.sheet_name().to_owned(),
+            name,
+            sheet_name,
             // Empty vecs as they'll be replaced
-            available_columns: Vec::with_capacity(0),
+            available_columns: AvailableColumns::Finalized(Vec::with_capacity(0)),
             selected_columns: Vec::with_capacity(0),
-            table,
+            data,
             header,
             pagination,
-            dtypes,
-            dtype_coercion,
+            options,
+            excluded_row_count,
+            empty_row: None,
             height: None,
             total_height: None,
             width: None,
         };
+        // Looked up once, since the limit is needed whenever data is read
+        if excel_table.options.stop_at_empty_row {
+            excel_table.empty_row = first_empty_row(excel_table.data(), excel_table.offset());
+        }
 
-        let row_limit = get_schema_sample_rows(
-            schema_sample_rows,
-            excel_table.offset(),
-            excel_table.limit(),
-        );
+        let sample_rows = excel_table
+            .options
+            .schema_sample_row_ranges(excel_table.offset(), excel_table.limit());
 
-        // Finalizing column info
-        let available_columns = build_available_columns(
-            available_columns_info,
+        let (available_columns, selected_columns) = finalize_column_info(
             excel_table.data(),
-            excel_table.offset(),
-            row_limit,
-            excel_table.dtypes.as_ref(),
-            &excel_table.dtype_coercion,
+            &excel_table.header,
+            &selected_columns,
+            &sample_rows,
+            excel_table.offset()..excel_table.limit(),
+            hidden_columns,
+            &excel_table.options,
         )?;
-
-        // Figure out dtype for every column
-        let selected_columns = selected_columns.select_columns(&available_columns)?;
         excel_table.available_columns = available_columns;
         excel_table.selected_columns = selected_columns;
 
@@ -101,7 +108,7 @@ impl ExcelTable {
     }
 
     pub(crate) fn data(&self) -> &Range<Data> {
-        self.table.data()
+        &self.data
     }
 }
 
@@ -112,22 +119,27 @@ impl TryFrom<&ExcelTable> for RecordBatch {
         let offset = table.offset();
         let limit = table.limit();
 
-        let iter = table.selected_columns.iter().map(|column_info| {
-            (
-                column_info.name(),
-                match column_info.dtype() {
+        let arrays = table
+            .selected_columns
+            .iter()
+            .map(|column_info| {
+                let array = match column_info.dtype() {
                     DType::Bool => create_boolean_array_from_range(
                         table.data(),
                         column_info.index(),
                         offset,
                         limit,
+                        table.options.parse_string_booleans,
                     ),
                     DType::Int => create_int_array_from_range(
                         table.data(),
                         column_info.index(),
                         offset,
                         limit,
-                    ),
+                    )
+                    .with_context(|| {
+                        format!("could not create int column \"{}\"", column_info.name())
+                    })?,
                     DType::Float => create_float_array_from_range(
                         table.data(),
                         column_info.index(),
@@ -139,12 +151,14 @@ impl TryFrom<&ExcelTable> for RecordBatch {
                         column_info.index(),
                         offset,
                         limit,
+                        &table.options,
                     ),
                     DType::DateTime => create_datetime_array_from_range(
                         table.data(),
                         column_info.index(),
                         offset,
                         limit,
+                        table.options.datetime_tz.as_deref(),
                     ),
                     DType::Date => create_date_array_from_range(
                         table.data(),
@@ -158,14 +172,29 @@ impl TryFrom<&ExcelTable> for RecordBatch {
                         offset,
                         limit,
                     ),
+                    DType::Time => create_time_array_from_range(
+                        table.data(),
+                        column_info.index(),
+                        offset,
+                        limit,
+                    ),
                     DType::Null => Arc::new(NullArray::new(limit - offset)),
-                },
-            )
-        });
+                };
+                let array = if table.options.fills_forward(column_info) {
+                    fill_forward(array.as_ref())?
+                } else {
+                    array
+                };
+                Ok((column_info.name(), array))
+            })
+            .collect::<FastExcelResult<Vec<_>>>()?;
 
-        let schema = selected_columns_to_schema(&table.selected_columns);
+        let schema = selected_columns_to_schema(
+            &table.selected_columns,
+            table.options.datetime_tz.as_deref(),
+        );
 
-        record_batch_from_name_array_iterator(iter, schema).with_context(|| {
+        record_batch_from_name_array_iterator(arrays.into_iter(), schema).with_context(|| {
             format!(
                 "could not convert table {table} in sheet {sheet} to RecordBatch",
                 table = &table.name,
@@ -184,7 +213,13 @@ impl ExcelTable {
 
     #[getter]
     pub(crate) fn limit(&self) -> usize {
-        let upper_bound = self.data().height();
+        // Skipping rows up to the excluded ones results in an empty table
+        let upper_bound = self
+            .data()
+            .height()
+            .saturating_sub(self.excluded_row_count)
+            .min(self.empty_row.unwrap_or(usize::MAX))
+            .max(self.offset());
         if let Some(n_rows) = self.pagination.n_rows() {
             let limit = self.offset() + n_rows;
             if limit < upper_bound {
@@ -201,13 +236,29 @@ impl ExcelTable {
     }
 
     #[getter]
-    pub fn available_columns(&self) -> Vec<ColumnInfo> {
-        self.available_columns.clone()
+    pub fn available_columns(&mut self) -> PyResult<Vec<ColumnInfo>> {
+        let sample_rows = self
+            .options
+            .schema_sample_row_ranges(self.offset(), self.limit());
+        let data_rows = self.offset()..self.limit();
+        self.available_columns
+            .finalize(&self.data, &sample_rows, data_rows, &self.options)
+            .map(<[ColumnInfo]>::to_vec)
+            .with_context(|| {
+                format!(
+                    "could not determine the available columns of table \"{}\"",
+                    self.name
+                )
+            })
+            .into_pyresult()
     }
 
     #[getter]
     pub fn specified_dtypes<'p>(&'p self, py: Python<'p>) -> Option<PyObject> {
-        self.dtypes.as_ref().map(|dtypes| dtypes.to_object(py))
+        self.options
+            .dtypes
+            .as_ref()
+            .map(|dtypes| dtypes.to_object(py))
     }
 
     #[getter]
@@ -231,7 +282,10 @@ impl ExcelTable {
     #[getter]
     pub fn total_height(&mut self) -> usize {
         self.total_height.unwrap_or_else(|| {
-            let total_height = self.data().height() - self.header.offset();
+            let total_height = self
+                .data()
+                .height()
+                .saturating_sub(self.excluded_row_count + self.header.offset());
             self.total_height = Some(total_height);
             total_height
         })
@@ -266,3 +320,54 @@ impl ExcelTable {
         )
     }
 }
+
+/// This class provides metadata about a table, which is read without loading the table's data
+#[derive(Debug, Clone)]
+#[pyclass(name = "TableInfo")]
+pub(crate) struct TableInfo {
+    /// `str`. The name of the table
+    #[pyo3(get)]
+    name: String,
+    /// `str`. The name of the sheet containing the table
+    #[pyo3(get)]
+    sheet_name: String,
+    /// `str`. The A1-style range of the table, header and totals rows included
+    #[pyo3(get)]
+    range: String,
+    /// `list[str]`. The names of the table's columns
+    #[pyo3(get)]
+    column_names: Vec<String>,
+    /// `bool`. Whether the first row of the range is a header row
+    #[pyo3(get)]
+    has_header_row: bool,
+    /// `bool`. Whether the last row of the range is a totals row
+    #[pyo3(get)]
+    has_totals_row: bool,
+}
+
+impl From<TableDefinition> for TableInfo {
+    fn from(definition: TableDefinition) -> Self {
+        Self {
+            name: definition.name,
+            sheet_name: definition.sheet_name,
+            range: definition.reference,
+            column_names: definition.column_names,
+            has_header_row: definition.header_row_count > 0,
+            has_totals_row: definition.totals_row_count > 0,
+        }
+    }
+}
+
+#[pymethods]
+impl TableInfo {
+    pub fn __repr__(&self) -> String {
+        format!(
+            "TableInfo(name=\"{name}\", sheet_name=\"{sheet_name}\", range=\"{range}\", has_header_row={has_header_row}, has_totals_row={has_totals_row})",
+            name = self.name,
+            sheet_name = self.sheet_name,
+            range = self.range,
+            has_header_row = self.has_header_row,
+            has_totals_row = self.has_totals_row,
+        )
+    }
+}
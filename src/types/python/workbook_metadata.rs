@@ -0,0 +1,52 @@
+use pyo3::{pyclass, pymethods};
+
+use crate::utils::xlsx::CoreProperties;
+
+/// This class provides the document properties of a workbook, as returned by
+/// `ExcelReader.metadata`
+#[derive(Debug, Clone)]
+#[pyclass(name = "WorkbookMetadata")]
+pub(crate) struct WorkbookMetadata {
+    /// `str | None`. The title of the workbook
+    #[pyo3(get)]
+    title: Option<String>,
+    /// `str | None`. The author of the workbook
+    #[pyo3(get)]
+    creator: Option<String>,
+    /// `str | None`. When the workbook was created, as written in the workbook (usually an
+    /// ISO 8601 datetime such as `"2024-01-31T12:00:00Z"`)
+    #[pyo3(get)]
+    created: Option<String>,
+    /// `str | None`. When the workbook was last modified, in the same format as `created`
+    #[pyo3(get)]
+    modified: Option<String>,
+}
+
+impl From<CoreProperties> for WorkbookMetadata {
+    fn from(properties: CoreProperties) -> Self {
+        Self {
+            title: properties.title,
+            creator: properties.creator,
+            created: properties.created,
+            modified: properties.modified,
+        }
+    }
+}
+
+#[pymethods]
+impl WorkbookMetadata {
+    pub fn __repr__(&self) -> String {
+        let repr = |value: &Option<String>| {
+            value
+                .as_ref()
+                .map_or_else(|| "None".to_owned(), |value| format!("\"{value}\""))
+        };
+        format!(
+            "WorkbookMetadata(title={title}, creator={creator}, created={created}, modified={modified})",
+            title = repr(&self.title),
+            creator = repr(&self.creator),
+            created = repr(&self.created),
+            modified = repr(&self.modified),
+        )
+    }
+}
@@ -0,0 +1,57 @@
+//! Detection of encrypted workbooks, which calamine cannot read and fails on with an opaque error
+
+use std::{fs::File, io::Read};
+
+/// Signature of OLE compound files, the container in which encrypted OOXML workbooks are stored
+const CFB_SIGNATURE: [u8; 8] = [0xD0, 0xCF, 0x11, 0xE0, 0xA1, 0xB1, 0x1A, 0xE1];
+
+/// Name of the stream of the compound file holding the encrypted workbook
+const ENCRYPTED_PACKAGE_STREAM: &str = "EncryptedPackage";
+
+/// Whether `content` is an encrypted OOXML workbook, including workbooks encrypted with Excel's
+/// default password, i.e. "encrypted without a password"
+pub(crate) fn is_encrypted_workbook(content: &[u8]) -> bool {
+    if !content.starts_with(&CFB_SIGNATURE) {
+        return false;
+    }
+    // The names of the compound file's directory entries are stored in UTF-16LE
+    let stream_name: Vec<u8> = ENCRYPTED_PACKAGE_STREAM
+        .encode_utf16()
+        .flat_map(u16::to_le_bytes)
+        .collect();
+    content
+        .windows(stream_name.len())
+        .any(|window| window == stream_name)
+}
+
+/// Same as `is_encrypted_workbook`, for the workbook at `path`. Only files starting with the
+/// compound file signature are read entirely
+pub(crate) fn is_encrypted_workbook_at(path: &str) -> bool {
+    let mut signature = [0; CFB_SIGNATURE.len()];
+    let is_compound_file = File::open(path)
+        .and_then(|mut file| file.read_exact(&mut signature))
+        .is_ok_and(|()| signature == CFB_SIGNATURE);
+    is_compound_file && std::fs::read(path).is_ok_and(|content| is_encrypted_workbook(&content))
+}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn utf16(value: &str) -> Vec<u8> {
+        value.encode_utf16().flat_map(u16::to_le_bytes).collect()
+    }
+
+    #[rstest]
+    #[case(CFB_SIGNATURE.iter().copied().chain(utf16("EncryptedPackage")).collect(), true)]
+    // Legacy xls workbooks are compound files too
+    #[case(CFB_SIGNATURE.iter().copied().chain(utf16("Workbook")).collect(), false)]
+    #[case(b"PK\x03\x04EncryptedPackage".to_vec(), false)]
+    #[case(utf16("EncryptedPackage"), false)]
+    #[case(vec![], false)]
+    fn detect_encrypted_workbook(#[case] content: Vec<u8>, #[case] expected: bool) {
+        assert_eq!(is_encrypted_workbook(&content), expected);
+    }
+}
@@ -1 +1,3 @@
+pub(crate) mod encryption;
 pub(crate) mod schema;
+pub(crate) mod xlsx;
@@ -1,7 +1,63 @@
-use std::cmp::min;
+use std::{cmp::min, fmt::Display, ops::Range, str::FromStr};
+
+use pyo3::{prelude::PyAnyMethods, Bound, FromPyObject, PyAny, PyResult};
+
+use crate::error::{py_errors::IntoPyResult, FastExcelError, FastExcelErrorKind, FastExcelResult};
+
+/// How the rows used for dtype inference are picked
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub(crate) enum SchemaSampleStrategy {
+    /// Sample the first `schema_sample_rows` rows
+    #[default]
+    Head,
+    /// Sample the first `schema_sample_rows / 2` rows and the last `schema_sample_rows / 2` rows.
+    /// Useful to catch type changes at the bottom of a sheet (summary rows for example)
+    HeadTail,
+}
+
+impl FromStr for SchemaSampleStrategy {
+    type Err = FastExcelError;
+
+    fn from_str(raw_strategy: &str) -> FastExcelResult<Self> {
+        match raw_strategy {
+            "head" => Ok(Self::Head),
+            "head_tail" => Ok(Self::HeadTail),
+            _ => Err(FastExcelErrorKind::InvalidParameters(format!(
+                "unsupported schema_sample_strategy: \"{raw_strategy}\""
+            ))
+            .into()),
+        }
+    }
+}
+
+impl Display for SchemaSampleStrategy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            SchemaSampleStrategy::Head => "head",
+            SchemaSampleStrategy::HeadTail => "head_tail",
+        })
+    }
+}
+
+impl FromPyObject<'_> for SchemaSampleStrategy {
+    fn extract_bound(py_strategy: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(strategy_pystr) = py_strategy.extract::<String>() {
+            strategy_pystr.parse()
+        } else {
+            Err(FastExcelErrorKind::InvalidParameters(format!(
+                "{py_strategy:?} cannot be converted to str"
+            ))
+            .into())
+        }
+        .into_pyresult()
+    }
+}
+
+/// Default number of rows sampled to determine the dtypes of columns
+pub(crate) const DEFAULT_SCHEMA_SAMPLE_ROWS: usize = 1_000;
 
 /// Determines how many rows should be used for schema sampling, based on the provided parameter,
-/// and the sheet's offset and limit.
+/// and the sheet's offset and limit. Both `None` and `Some(0)` mean that all rows are sampled.
 ///
 /// Note that here, the limit should be retrieved from the sheet's `limit()` method, and must not
 /// be out of the sheet's bounds
@@ -11,15 +67,41 @@ pub(crate) fn get_schema_sample_rows(
     limit: usize,
 ) -> usize {
     // Checking how many rows we want to use to determine the dtype for a column. If sample_rows is
-    // not provided, we sample limit rows, i.e on the entire column
-    let sample_rows = offset + sample_rows.unwrap_or(limit);
+    // not provided or 0, we sample limit rows, i.e on the entire column
+    let sample_rows = offset
+        + match sample_rows {
+            None | Some(0) => limit,
+            Some(sample_rows) => sample_rows,
+        };
     // If sample_rows is higher than the sheet's limit, use the limit instead
     min(sample_rows, limit)
 }
 
+/// Returns the ranges of rows that should be used for schema sampling, depending on the strategy.
+///
+/// The returned ranges are sorted and never overlap.
+pub(crate) fn get_schema_sample_row_ranges(
+    strategy: SchemaSampleStrategy,
+    sample_rows: Option<usize>,
+    offset: usize,
+    limit: usize,
+) -> Vec<Range<usize>> {
+    match (strategy, sample_rows) {
+        (SchemaSampleStrategy::HeadTail, Some(sample_rows))
+            if sample_rows > 0 && offset + sample_rows < limit =>
+        {
+            // Rounding the head up, so that a single sampled row is taken from the head
+            let tail_rows = sample_rows / 2;
+            let head_rows = sample_rows - tail_rows;
+            vec![offset..offset + head_rows, limit - tail_rows..limit]
+        }
+        _ => std::iter::once(offset..get_schema_sample_rows(sample_rows, offset, limit)).collect(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::get_schema_sample_rows;
+    use super::*;
     use rstest::rstest;
 
     #[rstest]
@@ -37,6 +119,10 @@ mod tests {
     #[case(None, 0, 5000, 5000)]
     // no value, 1500 rows sheet, offset of 1000, row limit should be 1500
     #[case(None, 1000, 1500, 1500)]
+    // 0 is the same as no value, 5000 rows sheet, row limit should be 5000
+    #[case(Some(0), 0, 5000, 5000)]
+    // 0 is the same as no value, 1500 rows sheet, offset of 1000, row limit should be 1500
+    #[case(Some(0), 1000, 1500, 1500)]
     fn test_get_schema_sample_rows_return_values(
         #[case] sample_rows: Option<usize>,
         #[case] offset: usize,
@@ -45,4 +131,30 @@ mod tests {
     ) {
         assert_eq!(get_schema_sample_rows(sample_rows, offset, limit), expected);
     }
+
+    #[rstest]
+    // head: same as get_schema_sample_rows
+    #[case(SchemaSampleStrategy::Head, Some(100), 10, 5000, vec![10..110])]
+    // head_tail: half of the rows at the top, half at the bottom
+    #[case(SchemaSampleStrategy::HeadTail, Some(100), 10, 5000, vec![10..60, 4950..5000])]
+    // head_tail with an odd number: the head gets the extra row
+    #[case(SchemaSampleStrategy::HeadTail, Some(5), 0, 50, vec![0..3, 48..50])]
+    // head_tail on a sheet smaller than the sample: the entire sheet is sampled once
+    #[case(SchemaSampleStrategy::HeadTail, Some(100), 10, 50, vec![10..50])]
+    // head_tail without a sample size: the entire sheet is sampled
+    #[case(SchemaSampleStrategy::HeadTail, None, 0, 50, vec![0..50])]
+    // head_tail with a sample size of 0: the entire sheet is sampled
+    #[case(SchemaSampleStrategy::HeadTail, Some(0), 0, 50, vec![0..50])]
+    fn test_get_schema_sample_row_ranges(
+        #[case] strategy: SchemaSampleStrategy,
+        #[case] sample_rows: Option<usize>,
+        #[case] offset: usize,
+        #[case] limit: usize,
+        #[case] expected: Vec<Range<usize>>,
+    ) {
+        assert_eq!(
+            get_schema_sample_row_ranges(strategy, sample_rows, offset, limit),
+            expected
+        );
+    }
 }
@@ -0,0 +1,845 @@
+//! Worksheet metadata which calamine does not expose, read straight from the XLSX archive
+
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{self, BufReader, Cursor, Read, Seek, SeekFrom},
+    ops::RangeInclusive,
+    sync::Arc,
+};
+
+use calamine::XlsxError;
+use quick_xml::{
+    escape::unescape,
+    events::{BytesStart, Event},
+    Reader as XmlReader,
+};
+use zip::ZipArchive;
+
+use crate::error::{FastExcelError, FastExcelErrorKind, FastExcelResult};
+
+/// The content of an XLSX workbook, opened a second time to read what calamine does not expose
+pub(crate) enum XlsxContent {
+    File(BufReader<File>),
+    Bytes(Cursor<Arc<[u8]>>),
+}
+
+impl Read for XlsxContent {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Self::File(file) => file.read(buf),
+            Self::Bytes(bytes) => bytes.read(buf),
+        }
+    }
+}
+
+impl Seek for XlsxContent {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        match self {
+            Self::File(file) => file.seek(pos),
+            Self::Bytes(bytes) => bytes.seek(pos),
+        }
+    }
+}
+
+/// Where an XLSX workbook can be opened again from, cheap to clone so that what calamine does not
+/// expose can be read lazily, only once it is needed
+#[derive(Debug, Clone)]
+pub(crate) enum XlsxSource {
+    Path(String),
+    Bytes(Arc<[u8]>),
+}
+
+impl XlsxSource {
+    pub(crate) fn open(&self) -> FastExcelResult<XlsxContent> {
+        match self {
+            Self::Path(path) => File::open(path)
+                .map(|file| XlsxContent::File(BufReader::new(file)))
+                .map_err(|err| FastExcelErrorKind::CalamineError(calamine::Error::Io(err)).into()),
+            Self::Bytes(bytes) => Ok(XlsxContent::Bytes(Cursor::new(Arc::clone(bytes)))),
+        }
+    }
+}
+
+fn xlsx_error(err: impl Into<XlsxError>) -> FastExcelError {
+    FastExcelErrorKind::CalamineError(calamine::Error::Xlsx(err.into())).into()
+}
+
+/// Calls `on_element` for every start or empty element of a file of the archive, until it
+/// returns `false` or the end of the file is reached
+fn visit_elements<RS: Read + Seek>(
+    archive: &mut ZipArchive<RS>,
+    path: &str,
+    mut on_element: impl FnMut(&BytesStart<'_>) -> FastExcelResult<bool>,
+) -> FastExcelResult<()> {
+    let file = archive.by_name(path).map_err(xlsx_error)?;
+    let mut reader = XmlReader::from_reader(BufReader::new(file));
+    let mut buf = Vec::new();
+    loop {
+        match reader.read_event_into(&mut buf).map_err(xlsx_error)? {
+            Event::Start(element) | Event::Empty(element) if !on_element(&element)? => {
+                return Ok(())
+            }
+            Event::Eof => return Ok(()),
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+/// Returns the unescaped value of an element's attribute, looked up by its local name
+fn attribute(element: &BytesStart<'_>, local_name: &[u8]) -> FastExcelResult<Option<String>> {
+    for attr in element.attributes() {
+        let attr = attr.map_err(|err| xlsx_error(quick_xml::Error::from(err)))?;
+        if attr.key.local_name().as_ref() == local_name {
+            // calamine enables quick-xml's `encoding` feature, which requires a reader to decode
+            // values: archives' XML parts are UTF-8 anyway
+            return unescape(&String::from_utf8_lossy(&attr.value))
+                .map(|value| Some(value.into_owned()))
+                .map_err(|err| xlsx_error(quick_xml::Error::from(err)));
+        }
+    }
+    Ok(None)
+}
+
+/// Resolves the target of a relationship, which is either relative to the directory of the part
+/// the relationship belongs to, or absolute within the archive
+fn resolve_target(base_dir: &str, target: &str) -> String {
+    if let Some(absolute_target) = target.strip_prefix('/') {
+        return absolute_target.to_owned();
+    }
+    let mut parts: Vec<_> = base_dir
+        .split('/')
+        .filter(|part| !part.is_empty())
+        .collect();
+    for part in target.split('/') {
+        match part {
+            ".." => {
+                parts.pop();
+            }
+            "." | "" => {}
+            _ => parts.push(part),
+        }
+    }
+    parts.join("/")
+}
+
+/// Returns the (id, type, resolved target) of the relationships of a part of the archive. Parts
+/// without relationships have none.
+fn relationships<RS: Read + Seek>(
+    archive: &mut ZipArchive<RS>,
+    part_path: &str,
+) -> FastExcelResult<Vec<(String, String, String)>> {
+    let (base_dir, file_name) = part_path.rsplit_once('/').unwrap_or(("", part_path));
+    let rels_path = format!("{base_dir}/_rels/{file_name}.rels");
+    if archive.index_for_name(&rels_path).is_none() {
+        return Ok(Vec::new());
+    }
+
+    let mut relationships = Vec::new();
+    visit_elements(archive, &rels_path, |element| {
+        if element.local_name().as_ref() == b"Relationship" {
+            if let (Some(id), Some(kind), Some(target)) = (
+                attribute(element, b"Id")?,
+                attribute(element, b"Type")?,
+                attribute(element, b"Target")?,
+            ) {
+                relationships.push((id, kind, resolve_target(base_dir, &target)));
+            }
+        }
+        Ok(true)
+    })?;
+    Ok(relationships)
+}
+
+/// Returns the name and the path of the XML file of every worksheet of the archive
+fn worksheet_paths<RS: Read + Seek>(
+    archive: &mut ZipArchive<RS>,
+) -> FastExcelResult<Vec<(String, String)>> {
+    let mut sheets = Vec::new();
+    visit_elements(archive, "xl/workbook.xml", |element| {
+        if element.local_name().as_ref() == b"sheet" {
+            if let (Some(name), Some(relationship_id)) =
+                (attribute(element, b"name")?, attribute(element, b"id")?)
+            {
+                sheets.push((name, relationship_id));
+            }
+        }
+        Ok(true)
+    })?;
+
+    let relationships = relationships(archive, "xl/workbook.xml")?;
+    Ok(sheets
+        .into_iter()
+        .filter_map(|(name, relationship_id)| {
+            relationships
+                .iter()
+                .find(|(id, _, _)| id == &relationship_id)
+                .map(|(_, _, target)| (name, target.clone()))
+        })
+        .collect())
+}
+
+/// Looks up the path of a worksheet's XML file in the archive
+fn worksheet_path<RS: Read + Seek>(
+    archive: &mut ZipArchive<RS>,
+    sheet_name: &str,
+) -> FastExcelResult<Option<String>> {
+    Ok(worksheet_paths(archive)?
+        .into_iter()
+        .find(|(name, _)| name == sheet_name)
+        .map(|(_, path)| path))
+}
+
+/// Maximum number of columns of a worksheet, the last one being XFD
+const MAX_COLUMN_COUNT: usize = 16_384;
+
+fn column_bound(element: &BytesStart<'_>, local_name: &[u8]) -> FastExcelResult<usize> {
+    attribute(element, local_name)?
+        .and_then(|bound| bound.parse::<usize>().ok())
+        // Column bounds are 1-based
+        .and_then(|bound| bound.checked_sub(1))
+        .ok_or_else(|| xlsx_error(XlsxError::Unexpected("invalid column definition")))
+}
+
+/// Returns the 0-based indices of the columns defined by a `<col>` element if it hides them. The
+/// bounds are written by the workbook's producer, so they are clamped to the last column of a sheet
+fn hidden_column_range(element: &BytesStart<'_>) -> FastExcelResult<Option<RangeInclusive<usize>>> {
+    if !matches!(
+        attribute(element, b"hidden")?.as_deref(),
+        Some("1" | "true")
+    ) {
+        return Ok(None);
+    }
+    let min = column_bound(element, b"min")?;
+    let max = column_bound(element, b"max")?.min(MAX_COLUMN_COUNT - 1);
+    Ok(Some(min..=max).filter(|columns| !columns.is_empty()))
+}
+
+/// Returns the 0-based indices of the columns of a worksheet which are hidden, as defined by the
+/// `<col>` elements of the worksheet. Unknown sheets have no hidden columns.
+pub(crate) fn hidden_columns<RS: Read + Seek>(
+    reader: RS,
+    sheet_name: &str,
+) -> FastExcelResult<Vec<usize>> {
+    let mut archive = ZipArchive::new(reader).map_err(xlsx_error)?;
+    let Some(path) = worksheet_path(&mut archive, sheet_name)? else {
+        return Ok(Vec::new());
+    };
+
+    let mut hidden_columns = Vec::new();
+    visit_elements(&mut archive, &path, |element| {
+        match element.local_name().as_ref() {
+            b"col" => {
+                if let Some(columns) = hidden_column_range(element)? {
+                    hidden_columns.extend(columns);
+                }
+                Ok(true)
+            }
+            // Column definitions come before the cells, which do not need to be read
+            b"sheetData" => Ok(false),
+            _ => Ok(true),
+        }
+    })?;
+    Ok(hidden_columns)
+}
+
+/// Returns the A1-style reference of the used range of a worksheet (e.g. "A1:D10"), as recorded
+/// by its `<dimension>` element. `None` for unknown sheets or if the element is missing.
+pub(crate) fn dimension<RS: Read + Seek>(
+    reader: RS,
+    sheet_name: &str,
+) -> FastExcelResult<Option<String>> {
+    let mut archive = ZipArchive::new(reader).map_err(xlsx_error)?;
+    let Some(path) = worksheet_path(&mut archive, sheet_name)? else {
+        return Ok(None);
+    };
+
+    let mut dimension = None;
+    visit_elements(&mut archive, &path, |element| {
+        match element.local_name().as_ref() {
+            b"dimension" => {
+                dimension = attribute(element, b"ref")?;
+                Ok(false)
+            }
+            // The dimension comes before the cells, which do not need to be read
+            b"sheetData" => Ok(false),
+            _ => Ok(true),
+        }
+    })?;
+    Ok(dimension)
+}
+
+/// Returns the (name, code name) of every worksheet which has a code name, i.e. a name VBA refers
+/// to it by. Code names are recorded by the `<sheetPr>` element of worksheets, and are kept when
+/// sheets are renamed.
+pub(crate) fn sheet_code_names<RS: Read + Seek>(
+    reader: RS,
+) -> FastExcelResult<Vec<(String, String)>> {
+    let mut archive = ZipArchive::new(reader).map_err(xlsx_error)?;
+    let mut code_names = Vec::new();
+    for (sheet_name, sheet_path) in worksheet_paths(&mut archive)? {
+        let mut sheet_code_name = None;
+        visit_elements(&mut archive, &sheet_path, |element| {
+            match element.local_name().as_ref() {
+                b"sheetPr" => {
+                    sheet_code_name = attribute(element, b"codeName")?;
+                    Ok(false)
+                }
+                // Sheet properties come first, the rest of the sheet does not need to be read
+                b"dimension" | b"sheetViews" | b"sheetData" => Ok(false),
+                _ => Ok(true),
+            }
+        })?;
+        if let Some(code_name) = sheet_code_name {
+            code_names.push((sheet_name, code_name));
+        }
+    }
+    Ok(code_names)
+}
+
+/// Reads the ARGB hex color of the tab of the worksheet at `path`, if it is defined as an RGB
+/// value. Tab colors referring to the theme or to the indexed palette are ignored.
+fn read_tab_color<RS: Read + Seek>(
+    archive: &mut ZipArchive<RS>,
+    path: &str,
+) -> FastExcelResult<Option<String>> {
+    let mut tab_color = None;
+    visit_elements(archive, path, |element| {
+        match element.local_name().as_ref() {
+            b"tabColor" => {
+                tab_color = attribute(element, b"rgb")?;
+                Ok(false)
+            }
+            // Sheet properties come first, the rest of the sheet does not need to be read
+            b"dimension" | b"sheetViews" | b"sheetData" => Ok(false),
+            _ => Ok(true),
+        }
+    })?;
+    Ok(tab_color)
+}
+
+/// Returns the ARGB hex color of a worksheet's tab (e.g. `"FFFF0000"` for red), as defined by
+/// its `<sheetPr>` element. `None` for unknown sheets or if no RGB color is set.
+pub(crate) fn tab_color<RS: Read + Seek>(
+    reader: RS,
+    sheet_name: &str,
+) -> FastExcelResult<Option<String>> {
+    let mut archive = ZipArchive::new(reader).map_err(xlsx_error)?;
+    match worksheet_path(&mut archive, sheet_name)? {
+        Some(path) => read_tab_color(&mut archive, &path),
+        None => Ok(None),
+    }
+}
+
+/// Returns the name and the tab color of every worksheet, in the workbook's order
+pub(crate) fn tab_colors<RS: Read + Seek>(
+    reader: RS,
+) -> FastExcelResult<Vec<(String, Option<String>)>> {
+    let mut archive = ZipArchive::new(reader).map_err(xlsx_error)?;
+    worksheet_paths(&mut archive)?
+        .into_iter()
+        .map(|(sheet_name, path)| {
+            read_tab_color(&mut archive, &path).map(|tab_color| (sheet_name, tab_color))
+        })
+        .collect()
+}
+
+/// Returns the scope of every defined name of the workbook, in the order they are defined in,
+/// which is the order calamine lists them in: the name of the sheet the name is local to, or
+/// `None` for names belonging to the whole workbook.
+pub(crate) fn defined_name_scopes<RS: Read + Seek>(
+    reader: RS,
+) -> FastExcelResult<Vec<Option<String>>> {
+    let mut archive = ZipArchive::new(reader).map_err(xlsx_error)?;
+    let mut sheet_names = Vec::new();
+    let mut scopes = Vec::new();
+    visit_elements(&mut archive, "xl/workbook.xml", |element| {
+        match element.local_name().as_ref() {
+            b"sheet" => sheet_names.extend(attribute(element, b"name")?),
+            // Sheets come before defined names, whose scope is the index of a sheet
+            b"definedName" => scopes.push(
+                attribute(element, b"localSheetId")?
+                    .and_then(|sheet_idx| sheet_idx.parse::<usize>().ok())
+                    .and_then(|sheet_idx| sheet_names.get(sheet_idx).cloned()),
+            ),
+            _ => {}
+        }
+        Ok(true)
+    })?;
+    Ok(scopes)
+}
+
+/// Returns the index of the sheet which is active when the workbook is opened, as recorded by the
+/// first view of the workbook. Workbooks without any view record no active sheet.
+pub(crate) fn active_sheet<RS: Read + Seek>(reader: RS) -> FastExcelResult<Option<usize>> {
+    let mut archive = ZipArchive::new(reader).map_err(xlsx_error)?;
+    let mut active_sheet = None;
+    visit_elements(&mut archive, "xl/workbook.xml", |element| {
+        if element.local_name().as_ref() != b"workbookView" {
+            return Ok(true);
+        }
+        // The first sheet is active unless stated otherwise
+        active_sheet = Some(
+            attribute(element, b"activeTab")?
+                .and_then(|sheet_idx| sheet_idx.parse::<usize>().ok())
+                .unwrap_or(0),
+        );
+        Ok(false)
+    })?;
+    Ok(active_sheet)
+}
+
+/// The definition of a table, as found in the `xl/tables` directory of the archive
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TableDefinition {
+    pub(crate) name: String,
+    pub(crate) sheet_name: String,
+    /// A1-style reference of the whole table, header and totals rows included
+    pub(crate) reference: String,
+    pub(crate) column_names: Vec<String>,
+    pub(crate) header_row_count: usize,
+    pub(crate) totals_row_count: usize,
+}
+
+/// Reads the definition of a table, if the XML file at `path` defines the table `table_name`
+fn read_table_definition<RS: Read + Seek>(
+    archive: &mut ZipArchive<RS>,
+    path: &str,
+    table_name: &str,
+    sheet_name: &str,
+) -> FastExcelResult<Option<TableDefinition>> {
+    let count = |value: Option<String>, default: usize| {
+        value.map_or(Ok(default), |value| {
+            value
+                .parse::<usize>()
+                .map_err(|_| xlsx_error(XlsxError::Unexpected("invalid table row count")))
+        })
+    };
+
+    let mut definition: Option<TableDefinition> = None;
+    visit_elements(archive, path, |element| {
+        match element.local_name().as_ref() {
+            b"table" => {
+                // Tables are referred to by their display name, which usually is their name too
+                let name = match attribute(element, b"displayName")? {
+                    Some(display_name) => display_name,
+                    None => attribute(element, b"name")?.unwrap_or_default(),
+                };
+                if name != table_name {
+                    return Ok(false);
+                }
+                definition = Some(TableDefinition {
+                    name,
+                    sheet_name: sheet_name.to_owned(),
+                    reference: attribute(element, b"ref")?.unwrap_or_default(),
+                    column_names: Vec::new(),
+                    header_row_count: count(attribute(element, b"headerRowCount")?, 1)?,
+                    totals_row_count: count(attribute(element, b"totalsRowCount")?, 0)?,
+                });
+                Ok(true)
+            }
+            b"tableColumn" => {
+                if let Some(definition) = definition.as_mut() {
+                    definition
+                        .column_names
+                        .push(attribute(element, b"name")?.unwrap_or_default());
+                }
+                Ok(true)
+            }
+            _ => Ok(true),
+        }
+    })?;
+    Ok(definition)
+}
+
+/// Returns the definition of a table, without reading the cells of its sheet. Unknown tables
+/// have none.
+pub(crate) fn table_definition<RS: Read + Seek>(
+    reader: RS,
+    table_name: &str,
+) -> FastExcelResult<Option<TableDefinition>> {
+    let mut archive = ZipArchive::new(reader).map_err(xlsx_error)?;
+    for (sheet_name, sheet_path) in worksheet_paths(&mut archive)? {
+        for (_, kind, target) in relationships(&mut archive, &sheet_path)? {
+            if !kind.ends_with("/table") {
+                continue;
+            }
+            if let Some(definition) =
+                read_table_definition(&mut archive, &target, table_name, &sheet_name)?
+            {
+                return Ok(Some(definition));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// The formatting of a cell which is commonly used to convey meaning, e.g. red cells for errors
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct CellStyle {
+    /// ARGB hex color of the cell's fill (e.g. "FFFF0000"). Only colors defined as RGB values
+    /// are known: theme and indexed colors are not resolved.
+    pub(crate) fill_color: Option<String>,
+    pub(crate) bold: bool,
+}
+
+/// Returns the number of digits of a number format which pads numbers with leading zeros,
+/// e.g. 5 for "00000". Only formats made of at least two zeros are considered, other formats
+/// (e.g. "0" or "0.00") do not add leading zeros.
+fn zero_padding_width(format_code: &str) -> Option<usize> {
+    // Only the first section of the format applies to positive numbers
+    let positive_section = format_code.split(';').next().unwrap_or_default();
+    (positive_section.len() >= 2 && positive_section.bytes().all(|byte| byte == b'0'))
+        .then_some(positive_section.len())
+}
+
+/// What is known of a cell format of the workbook, i.e. of an entry of its `cellXfs`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+struct CellFormat {
+    style: CellStyle,
+    /// The number of digits numbers are padded to with leading zeros, if the number format of
+    /// the cell format pads them. Built-in number formats do not pad numbers.
+    zero_padding: Option<usize>,
+}
+
+/// Returns every cell format of the workbook, indexed like the `s` attribute of cells
+fn cell_formats<RS: Read + Seek>(archive: &mut ZipArchive<RS>) -> FastExcelResult<Vec<CellFormat>> {
+    if archive.index_for_name("xl/styles.xml").is_none() {
+        return Ok(Vec::new());
+    }
+
+    let mut section = Vec::new();
+    let mut zero_paddings = HashMap::new();
+    let mut bold_fonts: Vec<bool> = Vec::new();
+    let mut fill_colors: Vec<Option<String>> = Vec::new();
+    let mut pattern_type = None;
+    let mut formats = Vec::new();
+    visit_elements(archive, "xl/styles.xml", |element| {
+        let local_name = element.local_name();
+        match local_name.as_ref() {
+            // Only starts are visited, so the section an element belongs to is the last one met.
+            // Differential formats (dxfs) also contain fonts and fills, which must be ignored.
+            section_name @ (b"numFmts" | b"fonts" | b"fills" | b"borders" | b"cellStyleXfs"
+            | b"cellXfs" | b"cellStyles" | b"dxfs" | b"tableStyles" | b"colors"
+            | b"extLst") => {
+                section = section_name.to_vec();
+            }
+            b"numFmt" if section == b"numFmts" => {
+                if let (Some(id), Some(width)) = (
+                    attribute(element, b"numFmtId")?,
+                    attribute(element, b"formatCode")?
+                        .as_deref()
+                        .and_then(zero_padding_width),
+                ) {
+                    zero_paddings.insert(id, width);
+                }
+            }
+            b"font" if section == b"fonts" => bold_fonts.push(false),
+            b"b" if section == b"fonts" => {
+                if let Some(bold) = bold_fonts.last_mut() {
+                    *bold = !matches!(attribute(element, b"val")?.as_deref(), Some("0" | "false"));
+                }
+            }
+            b"fill" if section == b"fills" => {
+                fill_colors.push(None);
+                pattern_type = None;
+            }
+            b"patternFill" if section == b"fills" => {
+                pattern_type = attribute(element, b"patternType")?;
+            }
+            b"fgColor"
+                if section == b"fills"
+                    && pattern_type
+                        .as_deref()
+                        .is_some_and(|pattern| pattern != "none") =>
+            {
+                if let Some(fill_color) = fill_colors.last_mut() {
+                    *fill_color = attribute(element, b"rgb")?;
+                }
+            }
+            b"xf" if section == b"cellXfs" => {
+                let id = |value: Option<String>| {
+                    value
+                        .and_then(|value| value.parse::<usize>().ok())
+                        .unwrap_or(0)
+                };
+                formats.push(CellFormat {
+                    style: CellStyle {
+                        fill_color: fill_colors
+                            .get(id(attribute(element, b"fillId")?))
+                            .cloned()
+                            .flatten(),
+                        bold: bold_fonts
+                            .get(id(attribute(element, b"fontId")?))
+                            .copied()
+                            .unwrap_or(false),
+                    },
+                    zero_padding: attribute(element, b"numFmtId")?
+                        .and_then(|id| zero_paddings.get(&id).copied()),
+                });
+            }
+            _ => {}
+        }
+        Ok(true)
+    })?;
+    Ok(formats)
+}
+
+/// Parses an A1-style cell reference (e.g. "B2") into a 0-based (row, column) position
+fn cell_position(reference: &str) -> Option<(usize, usize)> {
+    let (col, row) = reference.split_at(reference.find(|chr: char| chr.is_ascii_digit())?);
+    if col.is_empty() || !col.chars().all(|chr| chr.is_ascii_uppercase()) {
+        return None;
+    }
+    // Same as `SelectedColumns::col_idx_for_col_as_letter`, A being 1
+    let col_idx = col.bytes().try_fold(0usize, |col_idx, letter| {
+        col_idx
+            .checked_mul(26)
+            .and_then(|col_idx| col_idx.checked_add(usize::from(letter - b'A') + 1))
+            .filter(|col_number| *col_number <= MAX_COLUMN_COUNT)
+    })?;
+    Some((row.parse::<usize>().ok()?.checked_sub(1)?, col_idx - 1))
+}
+
+/// Calls `on_cell` with the 0-based (row, column) position of every cell of a worksheet
+fn visit_cells<RS: Read + Seek>(
+    archive: &mut ZipArchive<RS>,
+    path: &str,
+    mut on_cell: impl FnMut(usize, usize, &BytesStart<'_>) -> FastExcelResult<()>,
+) -> FastExcelResult<()> {
+    // Row and column references are optional, in which case cells follow each other
+    let mut row_idx: Option<usize> = None;
+    let mut next_col_idx = 0;
+    visit_elements(archive, path, |element| {
+        match element.local_name().as_ref() {
+            b"row" => {
+                row_idx = Some(match attribute(element, b"r")? {
+                    Some(row) => row
+                        .parse::<usize>()
+                        .ok()
+                        .and_then(|row| row.checked_sub(1))
+                        .ok_or_else(|| xlsx_error(XlsxError::Unexpected("invalid row number")))?,
+                    None => row_idx.map_or(0, |row_idx| row_idx + 1),
+                });
+                next_col_idx = 0;
+            }
+            b"c" => {
+                let (cell_row_idx, col_idx) = match attribute(element, b"r")? {
+                    Some(reference) => cell_position(&reference).ok_or_else(|| {
+                        xlsx_error(XlsxError::Unexpected("invalid cell reference"))
+                    })?,
+                    None => (row_idx.unwrap_or(0), next_col_idx),
+                };
+                next_col_idx = col_idx + 1;
+                on_cell(cell_row_idx, col_idx, element)?;
+            }
+            _ => {}
+        }
+        Ok(true)
+    })
+}
+
+/// Returns the index of a cell's format, i.e. its `s` attribute
+fn cell_format_idx(element: &BytesStart<'_>) -> FastExcelResult<Option<usize>> {
+    Ok(attribute(element, b"s")?.and_then(|style| style.parse::<usize>().ok()))
+}
+
+/// Returns the (row, column, style) of every cell of a worksheet with a fill color or a bold
+/// font. Positions are 0-based and absolute within the sheet. Unknown sheets have no styles.
+pub(crate) fn cell_styles<RS: Read + Seek>(
+    reader: RS,
+    sheet_name: &str,
+) -> FastExcelResult<Vec<(usize, usize, CellStyle)>> {
+    let mut archive = ZipArchive::new(reader).map_err(xlsx_error)?;
+    let Some(path) = worksheet_path(&mut archive, sheet_name)? else {
+        return Ok(Vec::new());
+    };
+    let formats = cell_formats(&mut archive)?;
+    let default_style = CellStyle::default();
+
+    let mut styles = Vec::new();
+    visit_cells(&mut archive, &path, |row_idx, col_idx, element| {
+        let format = cell_format_idx(element)?.and_then(|format_idx| formats.get(format_idx));
+        if let Some(format) = format.filter(|format| format.style != default_style) {
+            styles.push((row_idx, col_idx, format.style.clone()));
+        }
+        Ok(())
+    })?;
+    Ok(styles)
+}
+
+/// Returns the (row, column, width) of every cell of a worksheet whose number format pads
+/// numbers with leading zeros to `width` digits (e.g. "00000" for zip codes). Positions are
+/// 0-based and absolute within the sheet. Unknown sheets have no such cells.
+pub(crate) fn zero_padded_cells<RS: Read + Seek>(
+    reader: RS,
+    sheet_name: &str,
+) -> FastExcelResult<Vec<(usize, usize, usize)>> {
+    let mut archive = ZipArchive::new(reader).map_err(xlsx_error)?;
+    let Some(path) = worksheet_path(&mut archive, sheet_name)? else {
+        return Ok(Vec::new());
+    };
+    let formats = cell_formats(&mut archive)?;
+    if formats.iter().all(|format| format.zero_padding.is_none()) {
+        return Ok(Vec::new());
+    }
+
+    let mut cells = Vec::new();
+    visit_cells(&mut archive, &path, |row_idx, col_idx, element| {
+        if let Some(width) = cell_format_idx(element)?
+            .and_then(|format_idx| formats.get(format_idx))
+            .and_then(|format| format.zero_padding)
+        {
+            cells.push((row_idx, col_idx, width));
+        }
+        Ok(())
+    })?;
+    Ok(cells)
+}
+
+/// The core properties of a workbook. Dates are kept as written, usually in the W3CDTF format
+/// (e.g. "2024-01-31T12:00:00Z")
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct CoreProperties {
+    pub(crate) title: Option<String>,
+    pub(crate) creator: Option<String>,
+    pub(crate) created: Option<String>,
+    pub(crate) modified: Option<String>,
+}
+
+/// Conventional location of the core properties part. Workbooks could refer to another location
+/// in their package relationships, but spreadsheet applications all use this one.
+const CORE_PROPERTIES_PATH: &str = "docProps/core.xml";
+
+/// Returns the core properties of a workbook. Missing properties, or a missing part, are `None`.
+pub(crate) fn core_properties<RS: Read + Seek>(reader: RS) -> FastExcelResult<CoreProperties> {
+    let mut archive = ZipArchive::new(reader).map_err(xlsx_error)?;
+    let mut properties = CoreProperties::default();
+    if archive.index_for_name(CORE_PROPERTIES_PATH).is_none() {
+        return Ok(properties);
+    }
+
+    let file = archive.by_name(CORE_PROPERTIES_PATH).map_err(xlsx_error)?;
+    let mut reader = XmlReader::from_reader(BufReader::new(file));
+    let mut buf = Vec::new();
+    // Local name of the element whose text is being read
+    let mut current_element = None;
+    loop {
+        match reader.read_event_into(&mut buf).map_err(xlsx_error)? {
+            Event::Start(element) => current_element = Some(element.local_name().as_ref().to_vec()),
+            Event::Text(text) => {
+                let property = match current_element.as_deref() {
+                    Some(b"title") => Some(&mut properties.title),
+                    Some(b"creator") => Some(&mut properties.creator),
+                    Some(b"created") => Some(&mut properties.created),
+                    Some(b"modified") => Some(&mut properties.modified),
+                    _ => None,
+                };
+                if let Some(property) = property {
+                    let value = text.unescape().map_err(xlsx_error)?;
+                    if !value.trim().is_empty() {
+                        *property = Some(value.trim().to_owned());
+                    }
+                }
+            }
+            Event::End(_) => current_element = None,
+            Event::Eof => return Ok(properties),
+            _ => {}
+        }
+        buf.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use pretty_assertions::assert_eq;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("xl", "worksheets/sheet1.xml", "xl/worksheets/sheet1.xml")]
+    #[case("xl", "/xl/worksheets/sheet1.xml", "xl/worksheets/sheet1.xml")]
+    #[case("xl/worksheets", "../tables/table1.xml", "xl/tables/table1.xml")]
+    #[case("xl/worksheets", "./table1.xml", "xl/worksheets/table1.xml")]
+    fn resolve_relationship_target(
+        #[case] base_dir: &str,
+        #[case] target: &str,
+        #[case] expected: &str,
+    ) {
+        assert_eq!(resolve_target(base_dir, target), expected);
+    }
+
+    #[rstest]
+    #[case("A1", Some((0, 0)))]
+    #[case("B12", Some((11, 1)))]
+    #[case("AA3", Some((2, 26)))]
+    #[case("A0", None)]
+    #[case("12", None)]
+    #[case("b2", None)]
+    #[case("XFD1", Some((0, 16_383)))]
+    #[case("XFE1", None)]
+    #[case("ZZZZZZZZZZZZZZZZ1", None)]
+    fn parse_cell_position(#[case] reference: &str, #[case] expected: Option<(usize, usize)>) {
+        assert_eq!(cell_position(reference), expected);
+    }
+
+    #[rstest]
+    #[case(r#"col min="2" max="3" hidden="1""#, Some(1..=2))]
+    #[case(r#"col min="2" max="3" hidden="true""#, Some(1..=2))]
+    #[case(r#"col min="2" max="3""#, None)]
+    #[case(r#"col min="2" max="3" hidden="0""#, None)]
+    #[case(r#"col min="16380" max="99999999999" hidden="1""#, Some(16_379..=16_383))]
+    #[case(r#"col min="20000" max="99999999999" hidden="1""#, None)]
+    fn hidden_column_definition(
+        #[case] content: &str,
+        #[case] expected: Option<RangeInclusive<usize>>,
+    ) {
+        let element = BytesStart::from_content(content, 3);
+        assert_eq!(hidden_column_range(&element).unwrap(), expected);
+    }
+
+    #[rstest]
+    #[case("00000", Some(5))]
+    #[case("00;-00", Some(2))]
+    #[case("0", None)]
+    #[case("0.00", None)]
+    #[case("000-00", None)]
+    #[case("@", None)]
+    #[case("General", None)]
+    fn number_format_zero_padding(#[case] format_code: &str, #[case] expected: Option<usize>) {
+        assert_eq!(zero_padding_width(format_code), expected);
+    }
+
+    #[test]
+    fn cell_styles_and_zero_paddings_from_the_same_formats() {
+        let bytes = std::fs::read(
+            std::path::Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("python/tests/fixtures/sheet-with-cell-styles-and-zero-padded-numbers.xlsx"),
+        )
+        .unwrap();
+        let red = CellStyle {
+            fill_color: Some("FFFF0000".to_owned()),
+            bold: false,
+        };
+        let bold = CellStyle {
+            fill_color: None,
+            bold: true,
+        };
+
+        assert_eq!(
+            cell_styles(Cursor::new(&bytes), "Orders").unwrap(),
+            vec![
+                (0, 0, bold.clone()),
+                (0, 1, bold),
+                (1, 1, red.clone()),
+                (3, 1, red)
+            ]
+        );
+        assert_eq!(
+            zero_padded_cells(Cursor::new(&bytes), "Orders").unwrap(),
+            vec![(1, 0, 5), (3, 0, 5)]
+        );
+    }
+}